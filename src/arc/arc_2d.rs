@@ -0,0 +1,323 @@
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+use std::f64::consts::PI;
+
+use crate::arc::circle::{
+    check_tolerance, circumcenter, line_parameters, polyline_intersections, segment_count,
+    ARC_TOLERANCE,
+};
+use crate::io::json::{json_methods, JsonData};
+use crate::line::LinearArgs2D;
+use crate::polyline::PolyLine2D;
+use crate::vector::_vector::*;
+
+/// Circular arc from start_angle to end_angle, counter-clockwise if end_angle > start_angle
+#[pyclass(module = "euklid_rs.arc")]
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ArcData", into = "ArcData")
+)]
+pub struct Arc2D {
+    #[pyo3(get)]
+    pub center: Vector2D,
+
+    #[pyo3(get)]
+    pub radius: f64,
+
+    #[pyo3(get)]
+    pub start_angle: f64,
+
+    #[pyo3(get)]
+    pub end_angle: f64,
+}
+
+impl Arc2D {
+    pub fn try_new(
+        center: Vector2D,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) -> Result<Self, &'static str> {
+        if radius <= 0. || !radius.is_finite() {
+            return Err("radius must be positive");
+        }
+        if !start_angle.is_finite() || !end_angle.is_finite() {
+            return Err("angles must be finite");
+        }
+        if (end_angle - start_angle).abs() > 2. * PI + ARC_TOLERANCE {
+            return Err("the sweep of an arc must not exceed a full turn");
+        }
+
+        Ok(Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        })
+    }
+
+    pub fn sweep(&self) -> f64 {
+        self.end_angle - self.start_angle
+    }
+
+    /// check if a point on the circle lies within the angular range of the arc
+    pub fn covers(&self, point: &Vector2D) -> bool {
+        let diff = *point - self.center;
+        let angle = diff.v[1].atan2(diff.v[0]);
+        let offset = ((angle - self.start_angle) * self.sweep().signum()).rem_euclid(2. * PI);
+        let tolerance = ARC_TOLERANCE / self.radius;
+
+        offset <= self.sweep().abs() + tolerance || offset >= 2. * PI - tolerance
+    }
+
+    /// discretize with a maximum chord deviation (tolerance is expected to be validated)
+    pub fn discretize(&self, tolerance: f64) -> PolyLine2D {
+        let count = segment_count(self.radius, self.sweep(), tolerance);
+
+        let nodes = (0..=count)
+            .map(|i| self.get(i as f64 / count as f64))
+            .collect();
+
+        PolyLine2D { nodes }
+    }
+}
+
+#[pymethods]
+impl Arc2D {
+    #[new]
+    pub fn new(center: Vector2D, radius: f64, start_angle: f64, end_angle: f64) -> PyResult<Self> {
+        Self::try_new(center, radius, start_angle, end_angle)
+            .map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// from_points(p1, p2, p3)
+    /// --
+    ///
+    /// from_points(p1: euklid_rs.vector.Vector2D, p2: euklid_rs.vector.Vector2D, p3: euklid_rs.vector.Vector2D) -> euklid_rs.arc.Arc2D
+    /// create the arc starting at p1, passing through p2 and ending at p3
+    #[staticmethod]
+    pub fn from_points(p1: Vector2D, p2: Vector2D, p3: Vector2D) -> PyResult<Self> {
+        let center = match circumcenter(&p1, &p2, &p3) {
+            Some(center) => center,
+            None => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "points are collinear",
+                ))
+            }
+        };
+
+        let d1 = p1 - center;
+        let d3 = p3 - center;
+        let start_angle = d1.v[1].atan2(d1.v[0]);
+        let end_angle = d3.v[1].atan2(d3.v[0]);
+
+        let sweep = if (p2 - p1).cross(&(p3 - p2)) > 0. {
+            (end_angle - start_angle).rem_euclid(2. * PI)
+        } else {
+            -(start_angle - end_angle).rem_euclid(2. * PI)
+        };
+
+        Self::new(center, d1.length(), start_angle, start_angle + sweep)
+    }
+
+    /// from_bulge(p1, p2, bulge)
+    /// --
+    ///
+    /// from_bulge(p1: euklid_rs.vector.Vector2D, p2: euklid_rs.vector.Vector2D, bulge: float) -> euklid_rs.arc.Arc2D
+    /// create an arc from start, end and bulge (tan(sweep / 4), positive for counter-clockwise arcs)
+    #[staticmethod]
+    pub fn from_bulge(p1: Vector2D, p2: Vector2D, bulge: f64) -> PyResult<Self> {
+        let chord = p2 - p1;
+        let length = chord.length();
+
+        if bulge.abs() < ARC_TOLERANCE || length < ARC_TOLERANCE {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "bulge and chord must not be zero",
+            ));
+        }
+
+        let sweep = 4. * bulge.atan();
+        let normal = Vector2D::__new__([-chord.v[1], chord.v[0]]) / length;
+        let center = (p1 + p2) / 2. + normal * (length / 2. / (sweep / 2.).tan());
+
+        let d1 = p1 - center;
+        let start_angle = d1.v[1].atan2(d1.v[0]);
+
+        Self::new(center, d1.length(), start_angle, start_angle + sweep)
+    }
+
+    /// signed angle from start to end
+    #[getter]
+    pub fn get_sweep(&self) -> f64 {
+        self.sweep()
+    }
+
+    pub fn bulge(&self) -> f64 {
+        (self.sweep() / 4.).tan()
+    }
+
+    pub fn get_length(&self) -> f64 {
+        self.radius * self.sweep().abs()
+    }
+
+    /// get($self, t)
+    /// --
+    ///
+    /// get(self: euklid_rs.arc.Arc2D, t: float) -> euklid_rs.vector.Vector2D
+    /// get the point at t (0: start, 1: end)
+    pub fn get(&self, t: f64) -> Vector2D {
+        let angle = self.start_angle + self.sweep() * t;
+
+        self.center + Vector2D::__new__([angle.cos(), angle.sin()]) * self.radius
+    }
+
+    pub fn start(&self) -> Vector2D {
+        self.get(0.)
+    }
+
+    pub fn end(&self) -> Vector2D {
+        self.get(1.)
+    }
+
+    /// tangent($self, t)
+    /// --
+    ///
+    /// tangent(self: euklid_rs.arc.Arc2D, t: float) -> euklid_rs.vector.Vector2D
+    /// get the unit tangent in the direction of the arc at t
+    pub fn tangent(&self, t: f64) -> Vector2D {
+        let angle = self.start_angle + self.sweep() * t;
+
+        Vector2D::__new__([-angle.sin(), angle.cos()]) * self.sweep().signum()
+    }
+
+    pub fn reverse(&self) -> Self {
+        Self {
+            start_angle: self.end_angle,
+            end_angle: self.start_angle,
+            ..*self
+        }
+    }
+
+    /// distance($self, point)
+    /// --
+    ///
+    /// distance(self: euklid_rs.arc.Arc2D, point: euklid_rs.vector.Vector2D) -> float
+    /// get the distance to the arc
+    pub fn distance(&self, point: &Vector2D) -> f64 {
+        let diff = *point - self.center;
+
+        if diff.length() > ARC_TOLERANCE {
+            let projected = self.center + diff * (self.radius / diff.length());
+            if self.covers(&projected) {
+                return (diff.length() - self.radius).abs();
+            }
+        }
+
+        (*point - self.start())
+            .length()
+            .min((*point - self.end()).length())
+    }
+
+    /// get_polyline($self, tolerance=1e-3)
+    /// --
+    ///
+    /// get_polyline(self: euklid_rs.arc.Arc2D, tolerance: float = 1e-3) -> euklid_rs.polyline.PolyLine2D
+    /// discretize with a maximum chord deviation
+    #[pyo3(signature = (tolerance=1e-3))]
+    pub fn get_polyline(&self, tolerance: f64) -> PyResult<PolyLine2D> {
+        check_tolerance(tolerance)?;
+
+        Ok(self.discretize(tolerance))
+    }
+
+    /// intersect_line($self, line)
+    /// --
+    ///
+    /// intersect_line(self: euklid_rs.arc.Arc2D, line: Union[euklid_rs.line.Line2D, euklid_rs.line.Ray2D, euklid_rs.line.Segment2D]) -> List[Tuple[float, euklid_rs.vector.Vector2D]]
+    /// get the intersections as (line parameter, point), ordered along the line
+    pub fn intersect_line(&self, line: LinearArgs2D) -> Vec<(f64, Vector2D)> {
+        let linear = line.linear();
+
+        line_parameters(&self.center, self.radius, &linear.origin, &linear.direction)
+            .into_iter()
+            .filter(|t| {
+                linear.range.0 - ARC_TOLERANCE <= *t && *t <= linear.range.1 + ARC_TOLERANCE
+            })
+            .map(|t| (t, linear.get(t)))
+            .filter(|(_, point)| self.covers(point))
+            .collect()
+    }
+
+    /// intersect_polyline($self, line)
+    /// --
+    ///
+    /// intersect_polyline(self: euklid_rs.arc.Arc2D, line: euklid_rs.polyline.PolyLine2D) -> List[Tuple[float, euklid_rs.vector.Vector2D]]
+    /// get all intersections as (ik, point), ordered along the polyline
+    pub fn intersect_polyline(&self, line: &PolyLine2D) -> Vec<(f64, Vector2D)> {
+        polyline_intersections(&self.center, self.radius, line, |point| self.covers(point))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Arc2D(({:.4}, {:.4}), r={:.4}, {:.4} -> {:.4})",
+            self.center.v[0], self.center.v[1], self.radius, self.start_angle, self.end_angle
+        )
+    }
+}
+
+// json data: {"center": [...], "radius": radius, "start_angle": angle, "end_angle": angle}
+impl JsonData for Arc2D {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("center", self.center.json_data(py)),
+            ("radius", self.radius.into_py(py)),
+            ("start_angle", self.start_angle.into_py(py)),
+            ("end_angle", self.end_angle.into_py(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Self::new(
+            JsonData::from_json_data(data.get_item("center")?)?,
+            data.get_item("radius")?.extract()?,
+            data.get_item("start_angle")?.extract()?,
+            data.get_item("end_angle")?.extract()?,
+        )
+    }
+}
+
+json_methods!(Arc2D);
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ArcData {
+    center: Vector2D,
+    radius: f64,
+    start_angle: f64,
+    end_angle: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<Arc2D> for ArcData {
+    fn from(arc: Arc2D) -> Self {
+        Self {
+            center: arc.center,
+            radius: arc.radius,
+            start_angle: arc.start_angle,
+            end_angle: arc.end_angle,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ArcData> for Arc2D {
+    type Error = &'static str;
+
+    fn try_from(data: ArcData) -> Result<Self, Self::Error> {
+        Self::try_new(data.center, data.radius, data.start_angle, data.end_angle)
+    }
+}
@@ -0,0 +1,395 @@
+use nalgebra as na;
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+
+use crate::io::json::{json_methods, JsonData};
+use crate::line::LinearArgs2D;
+use crate::polyline::PolyLine2D;
+use crate::vector::_vector::*;
+
+pub const ARC_TOLERANCE: f64 = 1e-8;
+
+/// smallest angle per segment, caps the segment count for tiny tolerances
+pub const MIN_SEGMENT_ANGLE: f64 = 1e-4;
+
+#[derive(FromPyObject)]
+pub enum PointsArgs2D {
+    PolyLine(PolyLine2D),
+    List(Vec<Vector2D>),
+}
+
+impl PointsArgs2D {
+    pub fn nodes(&self) -> &[Vector2D] {
+        match self {
+            PointsArgs2D::PolyLine(line) => &line.nodes,
+            PointsArgs2D::List(nodes) => nodes,
+        }
+    }
+}
+
+/// center of the circle through three points, None for collinear points
+pub fn circumcenter(p1: &Vector2D, p2: &Vector2D, p3: &Vector2D) -> Option<Vector2D> {
+    let a = *p2 - *p1;
+    let b = *p3 - *p1;
+    let determinant = 2. * a.cross(&b);
+    let scale = a.length().max(b.length());
+
+    if determinant.abs() <= ARC_TOLERANCE * scale * scale {
+        return None;
+    }
+
+    let a2 = a.dot(&a);
+    let b2 = b.dot(&b);
+    let offset = Vector2D::__new__([
+        (b.v[1] * a2 - a.v[1] * b2) / determinant,
+        (a.v[0] * b2 - b.v[0] * a2) / determinant,
+    ]);
+
+    Some(*p1 + offset)
+}
+
+/// parameters t of the intersections of origin + t * direction with a circle
+pub fn line_parameters(
+    center: &Vector2D,
+    radius: f64,
+    origin: &Vector2D,
+    direction: &Vector2D,
+) -> Vec<f64> {
+    let diff = *origin - *center;
+    let a = direction.dot(direction);
+    let b = 2. * direction.dot(&diff);
+    let c = diff.dot(&diff) - radius * radius;
+    let discriminant = b * b - 4. * a * c;
+    let tolerance = ARC_TOLERANCE * radius * radius * a;
+
+    if discriminant < -tolerance {
+        Vec::new()
+    } else if discriminant <= tolerance {
+        vec![-b / (2. * a)]
+    } else {
+        let root = discriminant.sqrt();
+        vec![(-b - root) / (2. * a), (-b + root) / (2. * a)]
+    }
+}
+
+/// intersections of a polyline with a circle as (ik, point), filtered by accept(point)
+pub fn polyline_intersections(
+    center: &Vector2D,
+    radius: f64,
+    line: &PolyLine2D,
+    accept: impl Fn(&Vector2D) -> bool,
+) -> Vec<(f64, Vector2D)> {
+    let mut result = Vec::new();
+    let segments = line.nodes.len().saturating_sub(1);
+
+    for i in 0..segments {
+        let p1 = line.nodes[i];
+        let direction = line.nodes[i + 1] - p1;
+
+        for t in line_parameters(center, radius, &p1, &direction) {
+            // shared nodes belong to the following segment
+            let in_range = if i + 1 == segments {
+                t <= 1. + ARC_TOLERANCE
+            } else {
+                t < 1. - ARC_TOLERANCE
+            };
+
+            if -ARC_TOLERANCE <= t && in_range {
+                let point = p1 + direction * t;
+                if accept(&point) {
+                    result.push((i as f64 + t.max(0.), point));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// raise a ValueError for non-positive or non-finite discretization tolerances
+pub fn check_tolerance(tolerance: f64) -> PyResult<()> {
+    if tolerance <= 0. || !tolerance.is_finite() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "tolerance must be positive",
+        ));
+    }
+
+    Ok(())
+}
+
+/// number of segments to discretize an angle with a maximum chord deviation
+pub fn segment_count(radius: f64, angle: f64, tolerance: f64) -> usize {
+    let max_angle = if tolerance >= radius {
+        std::f64::consts::PI
+    } else {
+        (2. * (1. - tolerance / radius).acos()).max(MIN_SEGMENT_ANGLE)
+    };
+
+    (angle.abs() / max_angle).ceil().max(1.) as usize
+}
+
+/// Full circle in the plane
+#[pyclass(module = "euklid_rs.arc")]
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "CircleData", into = "CircleData")
+)]
+pub struct Circle2D {
+    #[pyo3(get)]
+    pub center: Vector2D,
+
+    #[pyo3(get)]
+    pub radius: f64,
+}
+
+impl Circle2D {
+    pub fn try_new(center: Vector2D, radius: f64) -> Result<Self, &'static str> {
+        if radius <= 0. || !radius.is_finite() {
+            return Err("radius must be positive");
+        }
+
+        Ok(Self { center, radius })
+    }
+
+    pub fn point_at(&self, angle: f64) -> Vector2D {
+        self.center + Vector2D::__new__([angle.cos(), angle.sin()]) * self.radius
+    }
+}
+
+#[pymethods]
+impl Circle2D {
+    #[new]
+    pub fn new(center: Vector2D, radius: f64) -> PyResult<Self> {
+        Self::try_new(center, radius).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// from_points(p1, p2, p3)
+    /// --
+    ///
+    /// from_points(p1: euklid_rs.vector.Vector2D, p2: euklid_rs.vector.Vector2D, p3: euklid_rs.vector.Vector2D) -> euklid_rs.arc.Circle2D
+    /// create the circle through three points
+    #[staticmethod]
+    pub fn from_points(p1: Vector2D, p2: Vector2D, p3: Vector2D) -> PyResult<Self> {
+        match circumcenter(&p1, &p2, &p3) {
+            Some(center) => Self::new(center, (p1 - center).length()),
+            None => Err(pyo3::exceptions::PyValueError::new_err(
+                "points are collinear",
+            )),
+        }
+    }
+
+    /// fit(points)
+    /// --
+    ///
+    /// fit(points: Union[euklid_rs.polyline.PolyLine2D, List[euklid_rs.vector.Vector2D]]) -> Tuple[euklid_rs.arc.Circle2D, float]
+    /// least-squares circle through the points and the rms distance of the points to the circle
+    #[staticmethod]
+    pub fn fit(points: PointsArgs2D) -> PyResult<(Self, f64)> {
+        let nodes = points.nodes();
+
+        if nodes.len() < 3 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "at least three points are needed",
+            ));
+        }
+
+        // algebraic fit (kasa) relative to the centroid as initial guess
+        let centroid =
+            nodes.iter().fold(na::Vector2::zeros(), |sum, p| sum + p.v) / nodes.len() as f64;
+        let covariance = nodes.iter().fold(na::Matrix2::zeros(), |sum, p| {
+            sum + (p.v - centroid) * (p.v - centroid).transpose()
+        });
+        let eigenvalues = covariance.symmetric_eigenvalues();
+        if eigenvalues.min() <= ARC_TOLERANCE * eigenvalues.max() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "points are collinear",
+            ));
+        }
+
+        let a = na::DMatrix::from_fn(nodes.len(), 3, |i, j| match j {
+            0 => nodes[i].v[0] - centroid[0],
+            1 => nodes[i].v[1] - centroid[1],
+            _ => 1.,
+        });
+        let b = na::DVector::from_fn(nodes.len(), |i, _| -(nodes[i].v - centroid).norm_squared());
+
+        let solution = a
+            .svd(true, true)
+            .solve(&b, ARC_TOLERANCE)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        let mut center = centroid - na::Vector2::new(solution[0], solution[1]) / 2.;
+        let mut radius = (center - centroid).norm_squared() - solution[2];
+
+        if !radius.is_finite() || radius <= 0. {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "points are collinear",
+            ));
+        }
+        radius = radius.sqrt();
+
+        // geometric refinement (gauss-newton on the radial distances)
+        for _ in 0..20 {
+            let mut jacobian = na::DMatrix::zeros(nodes.len(), 3);
+            let mut residuals = na::DVector::zeros(nodes.len());
+
+            for (i, node) in nodes.iter().enumerate() {
+                let diff = node.v - center;
+                let distance = diff.norm().max(ARC_TOLERANCE);
+                jacobian[(i, 0)] = -diff[0] / distance;
+                jacobian[(i, 1)] = -diff[1] / distance;
+                jacobian[(i, 2)] = -1.;
+                residuals[i] = distance - radius;
+            }
+
+            let step = match jacobian.svd(true, true).solve(&(-residuals), ARC_TOLERANCE) {
+                Ok(step) => step,
+                Err(_) => break,
+            };
+
+            center += na::Vector2::new(step[0], step[1]);
+            radius += step[2];
+
+            if step.norm() <= ARC_TOLERANCE * radius {
+                break;
+            }
+        }
+
+        let squared_sum: f64 = nodes
+            .iter()
+            .map(|node| ((node.v - center).norm() - radius).powi(2))
+            .sum();
+
+        Ok((
+            Self::new(Vector2D { v: center }, radius.abs())?,
+            (squared_sum / nodes.len() as f64).sqrt(),
+        ))
+    }
+
+    /// get($self, angle)
+    /// --
+    ///
+    /// get(self: euklid_rs.arc.Circle2D, angle: float) -> euklid_rs.vector.Vector2D
+    /// get the point at an angle (counter-clockwise from the x-axis)
+    pub fn get(&self, angle: f64) -> Vector2D {
+        self.point_at(angle)
+    }
+
+    pub fn get_area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    pub fn get_length(&self) -> f64 {
+        2. * std::f64::consts::PI * self.radius
+    }
+
+    /// distance($self, point)
+    /// --
+    ///
+    /// distance(self: euklid_rs.arc.Circle2D, point: euklid_rs.vector.Vector2D) -> float
+    /// signed distance to the circle line (negative inside)
+    pub fn distance(&self, point: &Vector2D) -> f64 {
+        (*point - self.center).length() - self.radius
+    }
+
+    /// get_polyline($self, tolerance=1e-3)
+    /// --
+    ///
+    /// get_polyline(self: euklid_rs.arc.Circle2D, tolerance: float = 1e-3) -> euklid_rs.polyline.PolyLine2D
+    /// discretize counter-clockwise with a maximum chord deviation, starting and ending at angle 0
+    #[pyo3(signature = (tolerance=1e-3))]
+    pub fn get_polyline(&self, tolerance: f64) -> PyResult<PolyLine2D> {
+        check_tolerance(tolerance)?;
+
+        let full = 2. * std::f64::consts::PI;
+        let count = segment_count(self.radius, full, tolerance).max(4);
+
+        let nodes = (0..=count)
+            .map(|i| self.point_at(full * i as f64 / count as f64))
+            .collect();
+
+        Ok(PolyLine2D { nodes })
+    }
+
+    /// intersect_line($self, line)
+    /// --
+    ///
+    /// intersect_line(self: euklid_rs.arc.Circle2D, line: Union[euklid_rs.line.Line2D, euklid_rs.line.Ray2D, euklid_rs.line.Segment2D]) -> List[Tuple[float, euklid_rs.vector.Vector2D]]
+    /// get the intersections as (line parameter, point), ordered along the line
+    pub fn intersect_line(&self, line: LinearArgs2D) -> Vec<(f64, Vector2D)> {
+        let linear = line.linear();
+
+        line_parameters(&self.center, self.radius, &linear.origin, &linear.direction)
+            .into_iter()
+            .filter(|t| {
+                linear.range.0 - ARC_TOLERANCE <= *t && *t <= linear.range.1 + ARC_TOLERANCE
+            })
+            .map(|t| (t, linear.get(t)))
+            .collect()
+    }
+
+    /// intersect_polyline($self, line)
+    /// --
+    ///
+    /// intersect_polyline(self: euklid_rs.arc.Circle2D, line: euklid_rs.polyline.PolyLine2D) -> List[Tuple[float, euklid_rs.vector.Vector2D]]
+    /// get all intersections as (ik, point), ordered along the polyline
+    pub fn intersect_polyline(&self, line: &PolyLine2D) -> Vec<(f64, Vector2D)> {
+        polyline_intersections(&self.center, self.radius, line, |_| true)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Circle2D(({:.4}, {:.4}), r={:.4})",
+            self.center.v[0], self.center.v[1], self.radius
+        )
+    }
+}
+
+// json data: {"center": [...], "radius": radius}
+impl JsonData for Circle2D {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("center", self.center.json_data(py)),
+            ("radius", self.radius.into_py(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Self::new(
+            JsonData::from_json_data(data.get_item("center")?)?,
+            data.get_item("radius")?.extract()?,
+        )
+    }
+}
+
+json_methods!(Circle2D);
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CircleData {
+    center: Vector2D,
+    radius: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<Circle2D> for CircleData {
+    fn from(circle: Circle2D) -> Self {
+        Self {
+            center: circle.center,
+            radius: circle.radius,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CircleData> for Circle2D {
+    type Error = &'static str;
+
+    fn try_from(data: CircleData) -> Result<Self, Self::Error> {
+        Self::try_new(data.center, data.radius)
+    }
+}
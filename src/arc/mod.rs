@@ -0,0 +1,28 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+mod arc_2d;
+mod circle;
+mod path;
+
+pub use arc_2d::Arc2D;
+pub use circle::{check_tolerance, Circle2D};
+pub use path::{Path2D, PathElement};
+
+pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    #[pymodule]
+    fn arc(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<Circle2D>()?;
+        m.add_class::<Arc2D>()?;
+        m.add_class::<Path2D>()?;
+        Ok(())
+    }
+
+    m.add_wrapped(wrap_pymodule!(arc))?;
+
+    let sys = PyModule::import(_py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item("euklid_rs.arc", m.getattr("arc")?)?;
+    Ok(())
+}
@@ -0,0 +1,604 @@
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+
+use crate::arc::circle::{check_tolerance, circumcenter, ARC_TOLERANCE};
+use crate::arc::Arc2D;
+use crate::io::json::{json_methods, tagged, unknown_variant, variant, JsonData};
+use crate::line::Segment2D;
+use crate::polyline::PolyLine2D;
+use crate::spline::{BSplineCurve3, BezierCurve};
+use crate::vector::_vector::*;
+
+#[derive(FromPyObject, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathElement {
+    Segment(Segment2D),
+    Arc(Arc2D),
+}
+
+impl IntoPy<PyObject> for PathElement {
+    fn into_py(self, py: Python) -> PyObject {
+        match self {
+            PathElement::Segment(segment) => segment.into_py(py),
+            PathElement::Arc(arc) => arc.into_py(py),
+        }
+    }
+}
+
+impl PathElement {
+    pub fn start(&self) -> Vector2D {
+        match self {
+            PathElement::Segment(segment) => segment.p1,
+            PathElement::Arc(arc) => arc.start(),
+        }
+    }
+
+    pub fn end(&self) -> Vector2D {
+        match self {
+            PathElement::Segment(segment) => segment.p2,
+            PathElement::Arc(arc) => arc.end(),
+        }
+    }
+
+    /// unit tangent at t (0: start, 1: end)
+    pub fn tangent(&self, t: f64) -> Vector2D {
+        match self {
+            PathElement::Segment(segment) => segment.direction(),
+            PathElement::Arc(arc) => arc.tangent(t),
+        }
+    }
+
+    pub fn distance(&self, point: &Vector2D) -> f64 {
+        match self {
+            PathElement::Segment(segment) => segment.distance(point),
+            PathElement::Arc(arc) => arc.distance(point),
+        }
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            PathElement::Segment(segment) => PathElement::Segment(Segment2D {
+                p1: segment.p2,
+                p2: segment.p1,
+            }),
+            PathElement::Arc(arc) => PathElement::Arc(arc.reverse()),
+        }
+    }
+}
+
+#[derive(FromPyObject)]
+pub enum CurveArgs<'a> {
+    BezierCurve(PyRefMut<'a, BezierCurve>),
+    BSplineCurve3(PyRefMut<'a, BSplineCurve3>),
+}
+
+/// distance of a point to the edge p1 -> p2
+fn edge_distance(point: &Vector2D, p1: &Vector2D, p2: &Vector2D) -> f64 {
+    let edge = *p2 - *p1;
+    let length = edge.dot(&edge);
+
+    if length <= f64::EPSILON {
+        return (*point - *p1).length();
+    }
+
+    let t = ((*point - *p1).dot(&edge) / length).clamp(0., 1.);
+    (*point - (*p1 + edge * t)).length()
+}
+
+/// unit tangent at p of the circle through p1, p2, p3 (p is one of them), oriented along
+/// reference. the reference is used for collinear points
+fn circle_tangent(p: &Vector2D, points: [&Vector2D; 3], reference: Vector2D) -> Vector2D {
+    let tangent = match circumcenter(points[0], points[1], points[2]) {
+        Some(center) => {
+            let radial = *p - center;
+            Vector2D::__new__([-radial.v[1], radial.v[0]])
+        }
+        None => reference,
+    };
+
+    if tangent.dot(&reference) < 0. {
+        tangent.normalized() * -1.
+    } else {
+        tangent.normalized()
+    }
+}
+
+/// arc (or segment) starting at p with the unit tangent t and ending at q
+fn tangent_arc(p: Vector2D, t: Vector2D, q: Vector2D) -> Option<PathElement> {
+    let chord = q - p;
+    let length = chord.length();
+
+    if length <= ARC_TOLERANCE {
+        return None;
+    }
+
+    // the sweep of a tangent arc is twice the angle between tangent and chord
+    let angle = t.cross(&chord).atan2(t.dot(&chord));
+
+    if angle.abs() <= ARC_TOLERANCE {
+        return Some(PathElement::Segment(Segment2D { p1: p, p2: q }));
+    }
+
+    let radius = length / (2. * angle.sin().abs());
+    let center = p + Vector2D::__new__([-t.v[1], t.v[0]]) * (radius * angle.signum());
+    let radial = p - center;
+    let start_angle = radial.v[1].atan2(radial.v[0]);
+
+    Arc2D::new(center, radius, start_angle, start_angle + 2. * angle)
+        .ok()
+        .map(PathElement::Arc)
+}
+
+/// biarc from p1 to p2 matching the unit tangents t1 and t2 (equal tangent lengths)
+fn biarc(p1: Vector2D, t1: Vector2D, p2: Vector2D, t2: Vector2D) -> Vec<PathElement> {
+    let v = p2 - p1;
+    let t = t1 + t2;
+    let denominator = 2. * (1. - t1.dot(&t2));
+
+    let junction = if denominator.abs() <= ARC_TOLERANCE {
+        if v.dot(&t2).abs() <= ARC_TOLERANCE * v.length() {
+            // parallel tangents normal to the chord: two half circles
+            p1 + v / 2.
+        } else {
+            let d = v.dot(&v) / (4. * v.dot(&t2));
+            (p1 + t1 * d + p2 - t2 * d) / 2.
+        }
+    } else {
+        let vt = v.dot(&t);
+        let d = (-vt + (vt * vt + denominator * v.dot(&v)).sqrt()) / denominator;
+        (p1 + t1 * d + p2 - t2 * d) / 2.
+    };
+
+    let mut elements = Vec::new();
+    let first = tangent_arc(p1, t1, junction);
+    let tangent = match first {
+        Some(element) => element.tangent(1.),
+        None => t1,
+    };
+
+    for element in [first, tangent_arc(junction, tangent, p2)]
+        .into_iter()
+        .flatten()
+    {
+        push_element(&mut elements, element);
+    }
+
+    elements
+}
+
+/// append an element, merging it with the last one if both lie on the same line or circle
+fn push_element(elements: &mut Vec<PathElement>, element: PathElement) {
+    let merged = match (elements.last(), element) {
+        (Some(PathElement::Segment(last)), PathElement::Segment(next)) => {
+            let d1 = last.p2 - last.p1;
+            let d2 = next.p2 - next.p1;
+
+            if d1.cross(&d2).abs() <= ARC_TOLERANCE * d1.length() * d2.length() && d1.dot(&d2) > 0.
+            {
+                Some(PathElement::Segment(Segment2D {
+                    p1: last.p1,
+                    p2: next.p2,
+                }))
+            } else {
+                None
+            }
+        }
+        (Some(PathElement::Arc(last)), PathElement::Arc(next)) => {
+            let sweep = last.sweep() + next.sweep();
+
+            if (last.center - next.center).length() <= ARC_TOLERANCE * last.radius
+                && (last.radius - next.radius).abs() <= ARC_TOLERANCE * last.radius
+                && last.sweep().signum() == next.sweep().signum()
+                && sweep.abs() <= 2. * std::f64::consts::PI
+            {
+                Some(PathElement::Arc(Arc2D {
+                    end_angle: last.start_angle + sweep,
+                    ..*last
+                }))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    match merged {
+        Some(merged) => *elements.last_mut().unwrap() = merged,
+        None => elements.push(element),
+    }
+}
+
+/// nodes and tangents of a polyline for biarc fitting.
+/// corners have separate incoming and outgoing tangents, all other nodes share one tangent
+struct TangentNodes {
+    nodes: Vec<Vector2D>,
+    closed: bool,
+    corners: Vec<bool>,
+    incoming: Vec<Vector2D>,
+    outgoing: Vec<Vector2D>,
+}
+
+impl TangentNodes {
+    fn new(mut nodes: Vec<Vector2D>) -> Self {
+        nodes.dedup_by(|p1, p2| (*p1 - *p2).length() <= f64::EPSILON);
+
+        let count = nodes.len();
+        let closed = count > 3 && (nodes[0] - nodes[count - 1]).length() <= f64::EPSILON;
+        let mut corners = vec![false; count];
+
+        if !closed && count > 0 {
+            corners[0] = true;
+            corners[count - 1] = true;
+        }
+
+        let mut result = Self {
+            nodes,
+            closed,
+            corners,
+            incoming: vec![Vector2D::__new__([0., 0.]); count],
+            outgoing: vec![Vector2D::__new__([0., 0.]); count],
+        };
+        result.update_tangents();
+
+        result
+    }
+
+    fn previous(&self, i: usize) -> Option<usize> {
+        match i {
+            0 if self.closed => Some(self.nodes.len() - 2),
+            0 => None,
+            _ => Some(i - 1),
+        }
+    }
+
+    fn next(&self, i: usize) -> Option<usize> {
+        if i + 1 < self.nodes.len() {
+            Some(i + 1)
+        } else if self.closed {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    fn set_corner(&mut self, i: usize) {
+        self.corners[i] = true;
+
+        if self.closed && (i == 0 || i + 1 == self.nodes.len()) {
+            let last = self.nodes.len() - 1;
+            self.corners[0] = true;
+            self.corners[last] = true;
+        }
+    }
+
+    fn update_tangents(&mut self) {
+        for i in 0..self.nodes.len() {
+            let node = &self.nodes[i];
+            let previous = self.previous(i);
+            let next = self.next(i);
+
+            // one-sided tangents use the circle through the neighbours on that side
+            let incoming = previous.map(|j| {
+                let edge = *node - self.nodes[j];
+                match self.previous(j) {
+                    Some(k) if !self.corners[j] => {
+                        circle_tangent(node, [&self.nodes[k], &self.nodes[j], node], edge)
+                    }
+                    _ => edge.normalized(),
+                }
+            });
+            let outgoing = next.map(|j| {
+                let edge = self.nodes[j] - *node;
+                match self.next(j) {
+                    Some(k) if !self.corners[j] => {
+                        circle_tangent(node, [node, &self.nodes[j], &self.nodes[k]], edge)
+                    }
+                    _ => edge.normalized(),
+                }
+            });
+
+            let (incoming, outgoing) = match (previous, next) {
+                (Some(j), Some(k)) if !self.corners[i] => {
+                    let tangent = circle_tangent(
+                        node,
+                        [&self.nodes[j], node, &self.nodes[k]],
+                        self.nodes[k] - self.nodes[j],
+                    );
+                    (tangent, tangent)
+                }
+                _ => (
+                    incoming.or(outgoing).unwrap_or(Vector2D::__new__([1., 0.])),
+                    outgoing.or(incoming).unwrap_or(Vector2D::__new__([1., 0.])),
+                ),
+            };
+
+            self.incoming[i] = incoming;
+            self.outgoing[i] = outgoing;
+        }
+    }
+
+    /// biarc from node first to node last, None if the polyline is not within tolerance
+    fn fit(&self, first: usize, last: usize, tolerance: f64) -> Option<Vec<PathElement>> {
+        let section = &self.nodes[first..=last];
+        let elements = biarc(
+            self.nodes[first],
+            self.outgoing[first],
+            self.nodes[last],
+            self.incoming[last],
+        );
+
+        let path_distance = |point: &Vector2D| {
+            elements
+                .iter()
+                .map(|element| element.distance(point))
+                .fold(f64::INFINITY, f64::min)
+        };
+        let line_distance = |point: &Vector2D| {
+            section
+                .windows(2)
+                .map(|edge| edge_distance(point, &edge[0], &edge[1]))
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        // the polyline (nodes and edge midpoints) has to be close to the path
+        let line_close = section.windows(2).all(|edge| {
+            path_distance(&edge[0]) <= tolerance
+                && path_distance(&((edge[0] + edge[1]) / 2.)) <= tolerance
+        });
+
+        // and the path has to be close to the polyline
+        let path_close = elements.iter().all(|element| {
+            [0.25, 0.5, 0.75].iter().all(|t| {
+                let point = match element {
+                    PathElement::Segment(segment) => segment.p1 + (segment.p2 - segment.p1) * *t,
+                    PathElement::Arc(arc) => arc.get(*t),
+                };
+                line_distance(&point) <= tolerance && line_distance(&element.end()) <= tolerance
+            })
+        });
+
+        if line_close && path_close {
+            Some(elements)
+        } else {
+            None
+        }
+    }
+
+    /// mark the nodes of edges that can not be fitted with smooth tangents as corners
+    fn find_corners(&mut self, tolerance: f64) {
+        loop {
+            let mut changed = false;
+
+            for i in 0..self.nodes.len().saturating_sub(1) {
+                if (!self.corners[i] || !self.corners[i + 1])
+                    && self.fit(i, i + 1, tolerance).is_none()
+                {
+                    self.set_corner(i);
+                    self.set_corner(i + 1);
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+            self.update_tangents();
+        }
+    }
+}
+
+/// Sequence of segments and arcs
+#[pyclass(module = "euklid_rs.arc")]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Path2D {
+    pub elements: Vec<PathElement>,
+}
+
+#[pymethods]
+impl Path2D {
+    #[new]
+    pub fn new(elements: Vec<PathElement>) -> Self {
+        Self { elements }
+    }
+
+    /// from_polyline(line, tolerance=1e-3)
+    /// --
+    ///
+    /// from_polyline(line: euklid_rs.polyline.PolyLine2D, tolerance: float = 1e-3) -> euklid_rs.arc.Path2D
+    /// approximate a polyline with biarcs. the nodes and edge midpoints of the polyline are
+    /// within tolerance of the path and vice versa. consecutive elements share their tangent,
+    /// except at corners of the polyline that can not be approximated smoothly. each biarc is
+    /// extended as far as possible, arcs on the same line or circle are merged
+    #[staticmethod]
+    #[pyo3(signature = (line, tolerance=1e-3))]
+    pub fn from_polyline(line: &PolyLine2D, tolerance: f64) -> PyResult<Self> {
+        check_tolerance(tolerance)?;
+
+        let mut nodes = TangentNodes::new(line.nodes.clone());
+        nodes.find_corners(tolerance);
+
+        let count = nodes.nodes.len();
+        let mut elements = Vec::new();
+        let mut start = 0;
+
+        while start + 1 < count {
+            // biarcs end at the next corner at the latest
+            let corner = (start + 1..count)
+                .find(|&i| nodes.corners[i])
+                .unwrap_or(count - 1);
+
+            // gallop to find a section that can not be fitted, then bisect
+            let mut good = start + 1;
+            let mut fitted = nodes.fit(start, good, tolerance);
+            let mut bad = corner + 1;
+            let mut step = 1;
+
+            while good + step < bad {
+                match nodes.fit(start, good + step, tolerance) {
+                    Some(next) => {
+                        fitted = Some(next);
+                        good += step;
+                        step *= 2;
+                    }
+                    None => {
+                        bad = good + step;
+                        break;
+                    }
+                }
+            }
+
+            while good + 1 < bad {
+                let end = (good + bad) / 2;
+                match nodes.fit(start, end, tolerance) {
+                    Some(next) => {
+                        fitted = Some(next);
+                        good = end;
+                    }
+                    None => bad = end,
+                }
+            }
+
+            for element in fitted.into_iter().flatten() {
+                push_element(&mut elements, element);
+            }
+            start = good;
+        }
+
+        Ok(Self { elements })
+    }
+
+    /// from_curve(curve, tolerance=1e-3, num=200)
+    /// --
+    ///
+    /// from_curve(curve: Union[euklid_rs.spline.BezierCurve, euklid_rs.spline.BSplineCurve3], tolerance: float = 1e-3, num: int = 200) -> euklid_rs.arc.Path2D
+    /// approximate a curve (evaluated at num points, including both ends) with segments and arcs
+    #[staticmethod]
+    #[pyo3(signature = (curve, tolerance=1e-3, num=200))]
+    pub fn from_curve(curve: CurveArgs, tolerance: f64, num: usize) -> PyResult<Self> {
+        if num < 2 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "num must be at least 2",
+            ));
+        }
+
+        let xs = (0..num).map(|i| i as f64 / (num - 1) as f64);
+        let nodes = match curve {
+            CurveArgs::BezierCurve(mut curve) => xs.map(|x| curve.get(x)).collect(),
+            CurveArgs::BSplineCurve3(mut curve) => xs.map(|x| curve.get(x)).collect(),
+        };
+
+        Self::from_polyline(&PolyLine2D { nodes }, tolerance)
+    }
+
+    #[getter]
+    pub fn elements(&self, py: Python) -> Vec<PyObject> {
+        self.elements
+            .iter()
+            .map(|element| element.into_py(py))
+            .collect()
+    }
+
+    /// get_polyline($self, tolerance=1e-3)
+    /// --
+    ///
+    /// get_polyline(self: euklid_rs.arc.Path2D, tolerance: float = 1e-3) -> euklid_rs.polyline.PolyLine2D
+    /// discretize the path, arcs with a maximum chord deviation
+    #[pyo3(signature = (tolerance=1e-3))]
+    pub fn get_polyline(&self, tolerance: f64) -> PyResult<PolyLine2D> {
+        check_tolerance(tolerance)?;
+
+        let mut nodes = Vec::new();
+
+        for element in &self.elements {
+            if nodes.is_empty() {
+                nodes.push(element.start());
+            }
+
+            match element {
+                PathElement::Segment(segment) => nodes.push(segment.p2),
+                PathElement::Arc(arc) => {
+                    nodes.extend(arc.discretize(tolerance).nodes.into_iter().skip(1))
+                }
+            }
+        }
+
+        Ok(PolyLine2D { nodes })
+    }
+
+    pub fn get_length(&self) -> f64 {
+        self.elements
+            .iter()
+            .map(|element| match element {
+                PathElement::Segment(segment) => segment.length(),
+                PathElement::Arc(arc) => arc.get_length(),
+            })
+            .sum()
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn __getitem__(&self, py: Python, idx: isize) -> PyResult<PyObject> {
+        let length = self.elements.len() as isize;
+        let index = if idx < 0 { length + idx } else { idx };
+
+        if index < 0 || index >= length {
+            return Err(pyo3::exceptions::PyIndexError::new_err("out of bounds"));
+        }
+
+        Ok(self.elements[index as usize].into_py(py))
+    }
+
+    fn __repr__(&self) -> String {
+        let arcs = self
+            .elements
+            .iter()
+            .filter(|element| matches!(element, PathElement::Arc(_)))
+            .count();
+
+        format!(
+            "Path2D({} segments, {} arcs)",
+            self.elements.len() - arcs,
+            arcs
+        )
+    }
+}
+
+// json data: {"Segment": {...}} or {"Arc": {...}}
+impl JsonData for PathElement {
+    fn json_data(&self, py: Python) -> PyObject {
+        match self {
+            PathElement::Segment(segment) => variant(py, "Segment", segment.json_data(py)),
+            PathElement::Arc(arc) => variant(py, "Arc", arc.json_data(py)),
+        }
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        let (name, data) = tagged(data)?;
+
+        match name.as_str() {
+            "Segment" => Ok(PathElement::Segment(JsonData::from_json_data(data)?)),
+            "Arc" => Ok(PathElement::Arc(JsonData::from_json_data(data)?)),
+            _ => Err(unknown_variant(&name)),
+        }
+    }
+}
+
+// json data: {"elements": [...]}
+impl JsonData for Path2D {
+    fn json_data(&self, py: Python) -> PyObject {
+        [("elements", self.elements.json_data(py))]
+            .into_py_dict(py)
+            .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self::new(JsonData::from_json_data(
+            data.get_item("elements")?,
+        )?))
+    }
+}
+
+json_methods!(Path2D);
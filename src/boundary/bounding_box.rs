@@ -0,0 +1,162 @@
+use crate::io::json::{json_methods, JsonData};
+use crate::vector::_vector::*;
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+
+macro_rules! define_bounding_box {
+    ($dst: ident, $vecClass: ident) => {
+        /// Axis aligned bounding box
+        #[pyclass(module = "euklid_rs.boundary")]
+        #[derive(Clone, Copy)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $dst {
+            #[pyo3(get)]
+            pub min: $vecClass,
+
+            #[pyo3(get)]
+            pub max: $vecClass,
+        }
+
+        impl $dst {
+            pub fn from_nodes(nodes: &[$vecClass]) -> Self {
+                let mut min = $vecClass::scalar(f64::INFINITY);
+                let mut max = $vecClass::scalar(-f64::INFINITY);
+
+                for node in nodes {
+                    min.v = min.v.inf(&node.v);
+                    max.v = max.v.sup(&node.v);
+                }
+
+                Self { min, max }
+            }
+        }
+
+        // json data: {"min": [...], "max": [...]}
+        impl JsonData for $dst {
+            fn json_data(&self, py: Python) -> PyObject {
+                [("min", self.min.json_data(py)), ("max", self.max.json_data(py))]
+                    .into_py_dict(py)
+                    .into()
+            }
+
+            fn from_json_data(data: &PyAny) -> PyResult<Self> {
+                Ok(Self::new(
+                    JsonData::from_json_data(data.get_item("min")?)?,
+                    JsonData::from_json_data(data.get_item("max")?)?,
+                ))
+            }
+        }
+
+        json_methods!($dst);
+
+        #[pymethods]
+        impl $dst {
+            #[new]
+            pub fn new(min: $vecClass, max: $vecClass) -> Self {
+                Self { min, max }
+            }
+
+            pub fn size(&self) -> $vecClass {
+                self.max - self.min
+            }
+
+            pub fn center(&self) -> $vecClass {
+                (self.min + self.max) * 0.5
+            }
+
+            /// contains($self, point, tolerance=0.)
+            /// --
+            ///
+            #[doc = concat!("contains(self: ", stringify!($dst), ", point: ", stringify!($vecClass), ", tolerance: float = 0.) -> bool")]
+            /// check if a point is inside the box
+            #[pyo3(signature = (point, tolerance=0.))]
+            pub fn contains(&self, point: &$vecClass, tolerance: f64) -> bool {
+                (0..$vecClass::DIMENSIONS).all(|i| {
+                    self.min.v[i] - tolerance <= point.v[i] && point.v[i] <= self.max.v[i] + tolerance
+                })
+            }
+
+            pub fn intersects(&self, other: &Self) -> bool {
+                (0..$vecClass::DIMENSIONS)
+                    .all(|i| self.min.v[i] <= other.max.v[i] && other.min.v[i] <= self.max.v[i])
+            }
+
+            /// union($self, other)
+            /// --
+            ///
+            #[doc = concat!("union(self: ", stringify!($dst), ", other: ", stringify!($dst), ") -> ", stringify!($dst))]
+            /// get the smallest box containing both boxes
+            pub fn union(&self, other: &Self) -> Self {
+                Self {
+                    min: $vecClass { v: self.min.v.inf(&other.min.v) },
+                    max: $vecClass { v: self.max.v.sup(&other.max.v) },
+                }
+            }
+
+            fn __repr__(&self) -> String {
+                format!(
+                    "{}({:?} - {:?})",
+                    stringify!($dst),
+                    self.min.tolist(),
+                    self.max.tolist()
+                )
+            }
+        }
+    };
+}
+
+define_bounding_box!(BoundingBox2D, Vector2D);
+define_bounding_box!(BoundingBox3D, Vector3D);
+
+#[pymethods]
+impl BoundingBox2D {
+    pub fn get_area(&self) -> f64 {
+        let size = self.size();
+        size.v[0] * size.v[1]
+    }
+
+    /// corners($self)
+    /// --
+    ///
+    /// corners(self: BoundingBox2D) -> List[Vector2D]
+    /// get the corners in counter-clockwise order starting at min
+    pub fn corners(&self) -> Vec<Vector2D> {
+        let [x0, y0] = self.min.tolist();
+        let [x1, y1] = self.max.tolist();
+
+        vec![
+            Vector2D::__new__([x0, y0]),
+            Vector2D::__new__([x1, y0]),
+            Vector2D::__new__([x1, y1]),
+            Vector2D::__new__([x0, y1]),
+        ]
+    }
+}
+
+#[pymethods]
+impl BoundingBox3D {
+    pub fn get_volume(&self) -> f64 {
+        let size = self.size();
+        size.v[0] * size.v[1] * size.v[2]
+    }
+
+    /// corners($self)
+    /// --
+    ///
+    /// corners(self: BoundingBox3D) -> List[Vector3D]
+    /// get the bottom (min-z) corners counter-clockwise, followed by the top corners
+    pub fn corners(&self) -> Vec<Vector3D> {
+        let [x0, y0, z0] = self.min.tolist();
+        let [x1, y1, z1] = self.max.tolist();
+
+        let mut corners = Vec::new();
+        for z in [z0, z1] {
+            corners.push(Vector3D::__new__([x0, y0, z]));
+            corners.push(Vector3D::__new__([x1, y0, z]));
+            corners.push(Vector3D::__new__([x1, y1, z]));
+            corners.push(Vector3D::__new__([x0, y1, z]));
+        }
+
+        corners
+    }
+}
@@ -0,0 +1,27 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+mod bounding_box;
+mod oriented_box;
+
+pub use bounding_box::{BoundingBox2D, BoundingBox3D};
+pub use oriented_box::{OrientedBox2D, OrientedBox3D};
+
+pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    #[pymodule]
+    fn boundary(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<BoundingBox2D>()?;
+        m.add_class::<BoundingBox3D>()?;
+        m.add_class::<OrientedBox2D>()?;
+        m.add_class::<OrientedBox3D>()?;
+        Ok(())
+    }
+
+    m.add_wrapped(wrap_pymodule!(boundary))?;
+
+    let sys = PyModule::import(_py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item("euklid_rs.boundary", m.getattr("boundary")?)?;
+    Ok(())
+}
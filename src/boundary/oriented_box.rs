@@ -0,0 +1,313 @@
+use nalgebra as na;
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+
+use crate::io::json::{json_methods, JsonData};
+use crate::polyline::hull;
+use crate::vector::_vector::*;
+use crate::vector::{Transformation, Transformation2D};
+
+/// Oriented (rotated) bounding rectangle
+#[pyclass(module = "euklid_rs.boundary")]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrientedBox2D {
+    /// corners in counter-clockwise order, starting at the local origin
+    #[pyo3(get)]
+    pub corners: Vec<Vector2D>,
+
+    #[pyo3(get)]
+    pub size: Vector2D,
+
+    /// angle of the local x-axis relative to the global x-axis
+    #[pyo3(get)]
+    pub angle: f64,
+
+    /// transformation from global into box-local coordinates
+    #[pyo3(get)]
+    pub transformation: Transformation2D,
+}
+
+/// Oriented (rotated) bounding box
+#[pyclass(module = "euklid_rs.boundary")]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrientedBox3D {
+    /// bottom corners counter-clockwise, followed by the top corners
+    #[pyo3(get)]
+    pub corners: Vec<Vector3D>,
+
+    #[pyo3(get)]
+    pub size: Vector3D,
+
+    /// transformation from global into box-local coordinates
+    #[pyo3(get)]
+    pub transformation: Transformation,
+}
+
+fn local_transformation(axes: [Vector3D; 3], origin: Vector3D) -> Transformation {
+    let mut matrix = na::Matrix4::<f64>::identity();
+
+    for (row, axis) in axes.iter().enumerate() {
+        for column in 0..3 {
+            matrix[(row, column)] = axis.v[column];
+        }
+        matrix[(row, 3)] = -axis.dot(&origin);
+    }
+
+    Transformation::new(matrix)
+}
+
+impl OrientedBox2D {
+    /// box aligned to the (normalized) x_axis
+    pub fn from_axis(nodes: &[Vector2D], x_axis: Vector2D) -> Self {
+        let y_axis = Vector2D::__new__([-x_axis.v[1], x_axis.v[0]]);
+
+        let mut min = Vector2D::scalar(f64::INFINITY);
+        let mut max = Vector2D::scalar(-f64::INFINITY);
+
+        for node in nodes {
+            let local = na::Vector2::new(node.dot(&x_axis), node.dot(&y_axis));
+            min.v = min.v.inf(&local);
+            max.v = max.v.sup(&local);
+        }
+
+        let size = max - min;
+        let origin = x_axis * min.v[0] + y_axis * min.v[1];
+
+        let corners = vec![
+            origin,
+            origin + x_axis * size.v[0],
+            origin + x_axis * size.v[0] + y_axis * size.v[1],
+            origin + y_axis * size.v[1],
+        ];
+
+        let transformation = Transformation2D::new(na::Matrix3::new(
+            x_axis.v[0],
+            x_axis.v[1],
+            -x_axis.dot(&origin),
+            y_axis.v[0],
+            y_axis.v[1],
+            -y_axis.dot(&origin),
+            0.,
+            0.,
+            1.,
+        ));
+
+        Self {
+            corners,
+            size,
+            angle: x_axis.angle(),
+            transformation,
+        }
+    }
+
+    /// Minimum-area rectangle: one side of the optimal rectangle is collinear with
+    /// an edge of the convex hull, so it is sufficient to check all hull edges.
+    pub fn min_area(nodes: &[Vector2D]) -> Result<Self, &'static str> {
+        let hull_nodes = hull::monotone_chain(nodes)?;
+
+        if hull_nodes.len() < 2 {
+            return Err("not enough nodes");
+        }
+
+        let mut best: Option<Self> = None;
+
+        for i in 0..hull_nodes.len() {
+            let edge = hull_nodes[(i + 1) % hull_nodes.len()] - hull_nodes[i];
+            let candidate = Self::from_axis(&hull_nodes, edge.normalized());
+
+            if best
+                .as_ref()
+                .is_none_or(|b| candidate.get_area() < b.get_area() - Vector2D::SMALL_N)
+            {
+                best = Some(candidate);
+            }
+        }
+
+        best.ok_or("not enough nodes")
+    }
+}
+
+#[pymethods]
+impl OrientedBox2D {
+    pub fn get_area(&self) -> f64 {
+        self.size.v[0] * self.size.v[1]
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "OrientedBox2D(size: {:?}, angle: {:.4})",
+            self.size.tolist(),
+            self.angle
+        )
+    }
+}
+
+impl OrientedBox3D {
+    /// box aligned to three orthonormal axes
+    pub fn from_axes(nodes: &[Vector3D], axes: [Vector3D; 3]) -> Self {
+        let mut min = Vector3D::scalar(f64::INFINITY);
+        let mut max = Vector3D::scalar(-f64::INFINITY);
+
+        for node in nodes {
+            let local =
+                na::Vector3::new(node.dot(&axes[0]), node.dot(&axes[1]), node.dot(&axes[2]));
+            min.v = min.v.inf(&local);
+            max.v = max.v.sup(&local);
+        }
+
+        let size = max - min;
+        let origin = axes[0] * min.v[0] + axes[1] * min.v[1] + axes[2] * min.v[2];
+
+        let mut corners = Vec::new();
+        for z in [0., size.v[2]] {
+            for (x, y) in [(0., 0.), (1., 0.), (1., 1.), (0., 1.)] {
+                corners.push(
+                    origin + axes[0] * (x * size.v[0]) + axes[1] * (y * size.v[1]) + axes[2] * z,
+                );
+            }
+        }
+
+        Self {
+            corners,
+            size,
+            transformation: local_transformation(axes, origin),
+        }
+    }
+
+    /// box aligned to the principal axes of the nodes
+    pub fn pca(nodes: &[Vector3D]) -> Result<Self, &'static str> {
+        if nodes.is_empty() {
+            return Err("not enough nodes");
+        }
+        if nodes.iter().any(|p| !p.v.iter().all(|x| x.is_finite())) {
+            return Err("points must be finite");
+        }
+
+        let center =
+            nodes.iter().fold(Vector3D::zero(), |sum, node| sum + *node) / nodes.len() as f64;
+        let mut covariance = na::Matrix3::<f64>::zeros();
+
+        for node in nodes {
+            let diff = (*node - center).v;
+            covariance += diff * diff.transpose();
+        }
+
+        let eigen = covariance.symmetric_eigen();
+        let mut order = [0, 1, 2];
+        order.sort_by(|a, b| eigen.eigenvalues[*b].total_cmp(&eigen.eigenvalues[*a]));
+
+        let x_axis = Vector3D {
+            v: eigen.eigenvectors.column(order[0]).into(),
+        }
+        .normalized();
+        let y_axis = Vector3D {
+            v: eigen.eigenvectors.column(order[1]).into(),
+        }
+        .normalized();
+
+        Ok(Self::from_axes(
+            nodes,
+            [x_axis, y_axis, x_axis.cross(&y_axis)],
+        ))
+    }
+
+    /// Approximated minimum-volume box: every face normal of the convex hull is
+    /// tried as box axis, the remaining axes are found with a 2D minimum-area
+    /// rectangle in the face plane.
+    pub fn min_volume(nodes: &[Vector3D]) -> Result<Self, &'static str> {
+        let mesh = hull::quickhull(nodes)?;
+        let vertices = &mesh.vertices;
+
+        let mut best = Self::pca(vertices).ok();
+
+        for [a, b, c] in &mesh.triangles {
+            let z_axis = (vertices[*b] - vertices[*a])
+                .cross(&(vertices[*c] - vertices[*a]))
+                .normalized();
+            let x_axis = (vertices[*b] - vertices[*a]).normalized();
+            let y_axis = z_axis.cross(&x_axis);
+
+            let projected: Vec<Vector2D> = vertices
+                .iter()
+                .map(|p| Vector2D::__new__([p.dot(&x_axis), p.dot(&y_axis)]))
+                .collect();
+
+            if let Ok(rectangle) = OrientedBox2D::min_area(&projected) {
+                let [u, v] = [rectangle.angle.cos(), rectangle.angle.sin()];
+                let axis_1 = x_axis * u + y_axis * v;
+                let axis_2 = z_axis.cross(&axis_1);
+                let candidate = Self::from_axes(vertices, [axis_1, axis_2, z_axis]);
+
+                if best
+                    .as_ref()
+                    .is_none_or(|b| candidate.get_volume() < b.get_volume() - Vector3D::SMALL_N)
+                {
+                    best = Some(candidate);
+                }
+            }
+        }
+
+        best.ok_or("no points given")
+    }
+}
+
+#[pymethods]
+impl OrientedBox3D {
+    pub fn get_volume(&self) -> f64 {
+        self.size.v[0] * self.size.v[1] * self.size.v[2]
+    }
+
+    fn __repr__(&self) -> String {
+        format!("OrientedBox3D(size: {:?})", self.size.tolist())
+    }
+}
+
+// json data: {"corners": [...], "size": [...], "angle": angle, "transformation": {...}}
+impl JsonData for OrientedBox2D {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("corners", self.corners.json_data(py)),
+            ("size", self.size.json_data(py)),
+            ("angle", self.angle.into_py(py)),
+            ("transformation", self.transformation.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            corners: JsonData::from_json_data(data.get_item("corners")?)?,
+            size: JsonData::from_json_data(data.get_item("size")?)?,
+            angle: data.get_item("angle")?.extract()?,
+            transformation: JsonData::from_json_data(data.get_item("transformation")?)?,
+        })
+    }
+}
+
+json_methods!(OrientedBox2D);
+
+// json data: {"corners": [...], "size": [...], "transformation": {...}}
+impl JsonData for OrientedBox3D {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("corners", self.corners.json_data(py)),
+            ("size", self.size.json_data(py)),
+            ("transformation", self.transformation.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            corners: JsonData::from_json_data(data.get_item("corners")?)?,
+            size: JsonData::from_json_data(data.get_item("size")?)?,
+            transformation: JsonData::from_json_data(data.get_item("transformation")?)?,
+        })
+    }
+}
+
+json_methods!(OrientedBox3D);
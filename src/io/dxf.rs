@@ -0,0 +1,893 @@
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+use crate::arc::{Arc2D, Circle2D, Path2D, PathElement};
+use crate::io::json::{pickle_methods, tagged, unknown_variant, variant, JsonData};
+use crate::line::Segment2D;
+use crate::polyline::{PolyLine2D, PolyLine3D};
+use crate::spline::{BSplineCurve3, BSplineCurve3_3D};
+use crate::vector::_vector::*;
+
+const DXF_TOLERANCE: f64 = 1e-9;
+
+#[derive(FromPyObject)]
+pub enum EntityArgs<'a> {
+    PolyLine2D(PolyLine2D),
+    PolyLine3D(PolyLine3D),
+    Path(Path2D),
+    Segment(Segment2D),
+    Arc(Arc2D),
+    Circle(Circle2D),
+    BSplineCurve3(PyRef<'a, BSplineCurve3>),
+    BSplineCurve3_3D(PyRef<'a, BSplineCurve3_3D>),
+}
+
+#[derive(Clone)]
+enum Entity {
+    PolyLine2D(PolyLine2D),
+    PolyLine3D(PolyLine3D),
+    Path(Path2D),
+    Arc(Arc2D),
+    Circle(Circle2D),
+    // cubic b-splines with the crate's uniform clamped knots
+    Spline2D(PolyLine2D),
+    Spline3D(PolyLine3D),
+}
+
+/// group code / value pairs of one entity
+type Group = Vec<(i32, String)>;
+
+fn value<T: std::str::FromStr>(group: &Group, code: i32) -> Option<T> {
+    group
+        .iter()
+        .find(|(c, _)| *c == code)
+        .and_then(|(_, value)| value.trim().parse().ok())
+}
+
+fn values(group: &Group, code: i32) -> Vec<f64> {
+    group
+        .iter()
+        .filter(|(c, _)| *c == code)
+        .filter_map(|(_, value)| value.trim().parse().ok())
+        .collect()
+}
+
+/// entities with an extrusion of (0, 0, -1) are mirrored at the yz-plane
+fn mirrored(group: &Group) -> bool {
+    value::<f64>(group, 230).is_some_and(|z| z < 0.)
+}
+
+fn closed(nodes: &[Vector2D]) -> bool {
+    nodes.len() > 2 && (nodes[0] - nodes[nodes.len() - 1]).length() < DXF_TOLERANCE
+}
+
+/// segments and arcs between vertices with bulges
+fn bulge_path(vertices: &[(Vector2D, f64)], closed: bool) -> PyResult<Path2D> {
+    let count = if closed {
+        vertices.len()
+    } else {
+        vertices.len().saturating_sub(1)
+    };
+    let mut elements = Vec::new();
+
+    for i in 0..count {
+        let (p1, bulge) = vertices[i];
+        let p2 = vertices[(i + 1) % vertices.len()].0;
+
+        if (p2 - p1).length() < DXF_TOLERANCE {
+            continue;
+        }
+
+        elements.push(if bulge.abs() < DXF_TOLERANCE {
+            PathElement::Segment(Segment2D { p1, p2 })
+        } else {
+            PathElement::Arc(Arc2D::from_bulge(p1, p2, bulge)?)
+        });
+    }
+
+    Ok(Path2D { elements })
+}
+
+fn polyline_2d(vertices: &[(Vector2D, f64)], closed: bool) -> PyResult<Entity> {
+    if vertices
+        .iter()
+        .any(|(_, bulge)| bulge.abs() > DXF_TOLERANCE)
+    {
+        return Ok(Entity::Path(bulge_path(vertices, closed)?));
+    }
+
+    let mut nodes: Vec<Vector2D> = vertices.iter().map(|(node, _)| *node).collect();
+    if closed && !nodes.is_empty() {
+        nodes.push(nodes[0]);
+    }
+
+    Ok(Entity::PolyLine2D(PolyLine2D { nodes }))
+}
+
+/// de boor evaluation in homogeneous coordinates
+fn de_boor(degree: usize, knots: &[f64], points: &[[f64; 4]], t: f64) -> [f64; 4] {
+    let n = points.len();
+    let mut k = degree;
+    while k + 1 < n && knots[k + 1] <= t {
+        k += 1;
+    }
+
+    let mut d: Vec<[f64; 4]> = (0..=degree).map(|j| points[j + k - degree]).collect();
+
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let i = j + k - degree;
+            let denominator = knots[i + degree + 1 - r] - knots[i];
+            let alpha = if denominator.abs() < DXF_TOLERANCE {
+                0.
+            } else {
+                (t - knots[i]) / denominator
+            };
+
+            let previous = d[j - 1];
+            for (c, value) in d[j].iter_mut().enumerate() {
+                *value = (1. - alpha) * previous[c] + alpha * *value;
+            }
+        }
+    }
+
+    d[degree]
+}
+
+/// degree and clamped knots of the crate's b-spline curves with count controlpoints.
+/// curves with less than four controlpoints are written with degree count - 1 (a single
+/// bezier span) and are sampled when they are read back
+fn spline_knots(count: usize) -> (usize, Vec<f64>) {
+    if count >= 4 {
+        let reference = BSplineCurve3::new(PolyLine2D {
+            nodes: vec![Vector2D::__new__([0., 0.]); count],
+        });
+        (3, reference.knots().to_vec())
+    } else {
+        let degree = count.saturating_sub(1);
+        let mut knots = vec![0.; degree + 1];
+        knots.extend(vec![1.; degree + 1]);
+        (degree, knots)
+    }
+}
+
+fn read_spline(group: &Group, samples: usize) -> PyResult<Entity> {
+    let degree = value::<usize>(group, 71).unwrap_or(3);
+    let knots = values(group, 40);
+    let xs = values(group, 10);
+    let ys = values(group, 20);
+    let zs = values(group, 30);
+    let weights = values(group, 41);
+
+    let count = xs.len().min(ys.len());
+    if count <= degree || knots.len() != count + degree + 1 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "invalid spline: controlpoints and knots do not match",
+        ));
+    }
+
+    let planar = zs.iter().all(|z| z.abs() < DXF_TOLERANCE);
+    let rational = weights.len() == count && weights.iter().any(|w| (w - 1.).abs() > DXF_TOLERANCE);
+    let point = |i: usize| [xs[i], ys[i], zs.get(i).copied().unwrap_or(0.)];
+
+    // uniform clamped cubic splines map onto the crate's b-spline curves
+    if degree == 3 && count >= 4 && !rational {
+        let start = knots[0];
+        let length = knots[knots.len() - 1] - start;
+        let (_, reference_knots) = spline_knots(count);
+
+        if length > DXF_TOLERANCE
+            && reference_knots
+                .iter()
+                .zip(&knots)
+                .all(|(a, b)| (a - (b - start) / length).abs() < 1e-6)
+        {
+            return Ok(if planar {
+                Entity::Spline2D(PolyLine2D {
+                    nodes: (0..count)
+                        .map(|i| Vector2D::__new__([xs[i], ys[i]]))
+                        .collect(),
+                })
+            } else {
+                Entity::Spline3D(PolyLine3D {
+                    nodes: (0..count).map(|i| Vector3D::__new__(point(i))).collect(),
+                })
+            });
+        }
+    }
+
+    // other splines are flattened
+    let points: Vec<[f64; 4]> = (0..count)
+        .map(|i| {
+            let w = if rational { weights[i] } else { 1. };
+            let p = point(i);
+            [p[0] * w, p[1] * w, p[2] * w, w]
+        })
+        .collect();
+
+    let mut nodes = Vec::new();
+    for span in degree..count {
+        let (t0, t1) = (knots[span], knots[span + 1]);
+        if t1 - t0 < DXF_TOLERANCE {
+            continue;
+        }
+        for i in 0..samples {
+            let t = t0 + (t1 - t0) * i as f64 / samples as f64;
+            nodes.push(de_boor(degree, &knots, &points, t));
+        }
+    }
+    nodes.push(de_boor(degree, &knots, &points, knots[count]));
+    let nodes = nodes
+        .into_iter()
+        .map(|p| [p[0] / p[3], p[1] / p[3], p[2] / p[3]]);
+
+    Ok(if planar {
+        Entity::PolyLine2D(PolyLine2D {
+            nodes: nodes.map(|p| Vector2D::__new__([p[0], p[1]])).collect(),
+        })
+    } else {
+        Entity::PolyLine3D(PolyLine3D {
+            nodes: nodes.map(Vector3D::__new__).collect(),
+        })
+    })
+}
+
+fn read_entity(
+    name: &str,
+    group: &Group,
+    vertices: &[Group],
+    samples: usize,
+) -> PyResult<Option<Entity>> {
+    let mirror = if mirrored(group) { -1. } else { 1. };
+    let get = |code: i32| value::<f64>(group, code).unwrap_or(0.);
+
+    Ok(Some(match name {
+        "LINE" => {
+            if get(30).abs() < DXF_TOLERANCE && get(31).abs() < DXF_TOLERANCE {
+                Entity::PolyLine2D(PolyLine2D {
+                    nodes: vec![
+                        Vector2D::__new__([get(10), get(20)]),
+                        Vector2D::__new__([get(11), get(21)]),
+                    ],
+                })
+            } else {
+                Entity::PolyLine3D(PolyLine3D {
+                    nodes: vec![
+                        Vector3D::__new__([get(10), get(20), get(30)]),
+                        Vector3D::__new__([get(11), get(21), get(31)]),
+                    ],
+                })
+            }
+        }
+        "CIRCLE" => Entity::Circle(Circle2D::new(
+            Vector2D::__new__([get(10) * mirror, get(20)]),
+            get(40),
+        )?),
+        "ARC" => {
+            let (mut start, mut end) = (get(50).to_radians(), get(51).to_radians());
+            if mirror < 0. {
+                (start, end) = (PI - end, PI - start);
+            }
+            if end < start {
+                end += 2. * PI;
+            }
+            Entity::Arc(Arc2D::new(
+                Vector2D::__new__([get(10) * mirror, get(20)]),
+                get(40),
+                start,
+                end,
+            )?)
+        }
+        "LWPOLYLINE" => {
+            let mut points: Vec<(Vector2D, f64)> = Vec::new();
+            let mut x = 0.;
+
+            for (code, value) in group {
+                let number: f64 = value.trim().parse().unwrap_or(0.);
+                match code {
+                    10 => x = number,
+                    20 => points.push((Vector2D::__new__([x * mirror, number]), 0.)),
+                    42 => {
+                        if let Some(last) = points.last_mut() {
+                            last.1 = number * mirror;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            polyline_2d(&points, value::<i32>(group, 70).unwrap_or(0) & 1 == 1)?
+        }
+        "POLYLINE" => {
+            let flags = value::<i32>(group, 70).unwrap_or(0);
+            // polygon and polyface meshes are not supported
+            if flags & (16 | 64) != 0 {
+                return Ok(None);
+            }
+            let closed = flags & 1 == 1;
+            let vertices: Vec<&Group> = vertices
+                .iter()
+                .filter(|vertex| value::<i32>(vertex, 70).unwrap_or(0) & 16 == 0)
+                .collect();
+            let coordinate = |vertex: &Group, code: i32| value::<f64>(vertex, code).unwrap_or(0.);
+
+            if flags & 8 == 8 {
+                let mut nodes: Vec<Vector3D> = vertices
+                    .iter()
+                    .map(|v| {
+                        Vector3D::__new__([coordinate(v, 10), coordinate(v, 20), coordinate(v, 30)])
+                    })
+                    .collect();
+                if closed && !nodes.is_empty() {
+                    nodes.push(nodes[0]);
+                }
+                Entity::PolyLine3D(PolyLine3D { nodes })
+            } else {
+                let points: Vec<(Vector2D, f64)> = vertices
+                    .iter()
+                    .map(|v| {
+                        (
+                            Vector2D::__new__([coordinate(v, 10) * mirror, coordinate(v, 20)]),
+                            coordinate(v, 42) * mirror,
+                        )
+                    })
+                    .collect();
+                polyline_2d(&points, closed)?
+            }
+        }
+        "SPLINE" => read_spline(group, samples)?,
+        _ => return Ok(None),
+    }))
+}
+
+/// symbol table record: name, subclass marker and extra group codes
+type Record<'a> = (&'a str, &'a str, &'a [(i32, &'a str)]);
+
+/// dxf text with consecutive handles for all objects
+struct Writer {
+    text: String,
+    handles: usize,
+}
+
+impl Writer {
+    fn new() -> Self {
+        Self {
+            text: String::new(),
+            handles: 0,
+        }
+    }
+
+    fn pair(&mut self, code: i32, value: impl std::fmt::Display) {
+        write!(self.text, "{:>3}\n{}\n", code, value).unwrap();
+    }
+
+    fn coordinates(&mut self, code: i32, values: &[f64]) {
+        for (i, value) in values.iter().enumerate() {
+            self.pair(code + 10 * i as i32, value);
+        }
+    }
+
+    fn next_handle(&mut self) -> String {
+        self.handles += 1;
+
+        format!("{:X}", self.handles)
+    }
+
+    fn start(&mut self, name: &str, handle: &str, owner: &str) {
+        self.pair(0, name);
+        self.pair(if name == "DIMSTYLE" { 105 } else { 5 }, handle);
+        self.pair(330, owner);
+    }
+
+    /// start an object with a new handle and its owner, returns the handle
+    fn object(&mut self, name: &str, owner: &str) -> String {
+        let handle = self.next_handle();
+        self.start(name, &handle, owner);
+
+        handle
+    }
+
+    /// start an entity: handle, owner, layer and subclass markers
+    fn entity(&mut self, name: &str, owner: &str, layer: &str, subclasses: &[&str]) -> String {
+        let handle = self.object(name, owner);
+
+        self.pair(100, "AcDbEntity");
+        self.pair(8, layer);
+        for subclass in subclasses {
+            self.pair(100, subclass);
+        }
+
+        handle
+    }
+
+    /// symbol table with its entries (name, extra subclass, group codes)
+    fn table(&mut self, name: &str, entries: &[Record]) -> Vec<String> {
+        let table = self.next_handle();
+
+        self.pair(0, "TABLE");
+        self.pair(2, name);
+        self.pair(5, &table);
+        self.pair(330, 0);
+        self.pair(100, "AcDbSymbolTable");
+        self.pair(70, entries.len());
+        if name == "DIMSTYLE" {
+            self.pair(100, "AcDbDimStyleTable");
+        }
+
+        let handles = entries
+            .iter()
+            .map(|(entry, subclass, codes)| {
+                let handle = self.object(name, &table);
+                self.pair(100, "AcDbSymbolTableRecord");
+                self.pair(100, subclass);
+                self.pair(2, entry);
+                self.pair(70, 0);
+                for (code, value) in codes.iter() {
+                    self.pair(*code, value);
+                }
+                handle
+            })
+            .collect();
+
+        self.pair(0, "ENDTAB");
+        handles
+    }
+}
+
+/// DXF drawing with named layers of 2D and 3D geometry
+#[pyclass(module = "euklid_rs.io")]
+#[derive(Clone)]
+pub struct DXFDocument {
+    layers: Vec<(String, Vec<Entity>)>,
+}
+
+impl DXFDocument {
+    fn layer(&mut self, name: &str) -> &mut Vec<Entity> {
+        let index = match self.layers.iter().position(|layer| layer.0 == name) {
+            Some(index) => index,
+            None => {
+                self.layers.push((name.to_string(), Vec::new()));
+                self.layers.len() - 1
+            }
+        };
+
+        &mut self.layers[index].1
+    }
+
+    fn write_entity(writer: &mut Writer, owner: &str, layer: &str, entity: &Entity) {
+        match entity {
+            Entity::PolyLine2D(line) => {
+                let closed = closed(&line.nodes);
+                let nodes = &line.nodes[..line.nodes.len() - closed as usize];
+
+                writer.entity("LWPOLYLINE", owner, layer, &["AcDbPolyline"]);
+                writer.pair(90, nodes.len());
+                writer.pair(70, closed as i32);
+                for node in nodes {
+                    writer.coordinates(10, node.v.as_slice());
+                }
+            }
+            Entity::Path(path) => {
+                // one lwpolyline per connected part
+                let mut parts: Vec<Vec<PathElement>> = Vec::new();
+                for element in &path.elements {
+                    match parts.last_mut() {
+                        Some(part)
+                            if (part[part.len() - 1].end() - element.start()).length()
+                                < DXF_TOLERANCE =>
+                        {
+                            part.push(*element)
+                        }
+                        _ => parts.push(vec![*element]),
+                    }
+                }
+
+                for part in parts {
+                    let first = part[0].start();
+                    let last = part[part.len() - 1].end();
+                    let closed = part.len() > 1 && (first - last).length() < DXF_TOLERANCE;
+
+                    writer.entity("LWPOLYLINE", owner, layer, &["AcDbPolyline"]);
+                    writer.pair(90, part.len() + !closed as usize);
+                    writer.pair(70, closed as i32);
+                    for element in &part {
+                        writer.coordinates(10, element.start().v.as_slice());
+                        if let PathElement::Arc(arc) = element {
+                            writer.pair(42, arc.bulge());
+                        }
+                    }
+                    if !closed {
+                        writer.coordinates(10, last.v.as_slice());
+                    }
+                }
+            }
+            Entity::PolyLine3D(line) => {
+                let closed = line.nodes.len() > 2
+                    && (line.nodes[0] - line.nodes[line.nodes.len() - 1]).length() < DXF_TOLERANCE;
+                let nodes = &line.nodes[..line.nodes.len() - closed as usize];
+
+                let polyline = writer.entity("POLYLINE", owner, layer, &["AcDb3dPolyline"]);
+                writer.pair(66, 1);
+                writer.coordinates(10, &[0., 0., 0.]);
+                writer.pair(70, 8 | closed as i32);
+                for node in nodes {
+                    let subclasses = ["AcDbVertex", "AcDb3dPolylineVertex"];
+                    writer.entity("VERTEX", &polyline, layer, &subclasses);
+                    writer.coordinates(10, node.v.as_slice());
+                    writer.pair(70, 32);
+                }
+                writer.entity("SEQEND", &polyline, layer, &[]);
+            }
+            Entity::Arc(arc) => {
+                let (start, end) = if arc.sweep() >= 0. {
+                    (arc.start_angle, arc.end_angle)
+                } else {
+                    (arc.end_angle, arc.start_angle)
+                };
+
+                writer.entity("ARC", owner, layer, &["AcDbCircle"]);
+                writer.coordinates(10, arc.center.v.as_slice());
+                writer.pair(40, arc.radius);
+                writer.pair(100, "AcDbArc");
+                writer.pair(50, start.to_degrees().rem_euclid(360.));
+                writer.pair(51, end.to_degrees().rem_euclid(360.));
+            }
+            Entity::Circle(circle) => {
+                writer.entity("CIRCLE", owner, layer, &["AcDbCircle"]);
+                writer.coordinates(10, circle.center.v.as_slice());
+                writer.pair(40, circle.radius);
+            }
+            Entity::Spline2D(_) | Entity::Spline3D(_) => {
+                let points: Vec<[f64; 3]> = match entity {
+                    Entity::Spline2D(controlpoints) => controlpoints
+                        .nodes
+                        .iter()
+                        .map(|p| [p.v[0], p.v[1], 0.])
+                        .collect(),
+                    Entity::Spline3D(controlpoints) => controlpoints
+                        .nodes
+                        .iter()
+                        .map(|p| [p.v[0], p.v[1], p.v[2]])
+                        .collect(),
+                    _ => unreachable!(),
+                };
+                let (degree, knots) = spline_knots(points.len());
+                let planar = matches!(entity, Entity::Spline2D(_));
+
+                writer.entity("SPLINE", owner, layer, &["AcDbSpline"]);
+                if planar {
+                    writer.coordinates(210, &[0., 0., 1.]);
+                }
+                writer.pair(70, if planar { 8 } else { 0 });
+                writer.pair(71, degree);
+                writer.pair(72, knots.len());
+                writer.pair(73, points.len());
+                writer.pair(74, 0);
+                for knot in knots {
+                    writer.pair(40, knot);
+                }
+                for point in points {
+                    writer.coordinates(10, &point);
+                }
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl DXFDocument {
+    #[new]
+    pub fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// from_dxf(text, spline_samples=16)
+    /// --
+    ///
+    /// from_dxf(text: str, spline_samples: int = 16) -> euklid_rs.io.DXFDocument
+    /// read LWPOLYLINE, POLYLINE, LINE, ARC, CIRCLE and SPLINE entities of a dxf document.
+    /// uniform cubic splines are read as b-spline curves, other splines are sampled
+    /// spline_samples (at least 1) times per knot span
+    #[staticmethod]
+    #[pyo3(signature = (text, spline_samples=16))]
+    pub fn from_dxf(text: &str, spline_samples: usize) -> PyResult<Self> {
+        if spline_samples == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "spline_samples must be at least 1",
+            ));
+        }
+
+        let lines: Vec<&str> = text.lines().collect();
+        let mut pairs = Vec::with_capacity(lines.len() / 2);
+
+        for chunk in lines.chunks(2) {
+            if chunk.len() < 2 {
+                break;
+            }
+            let code = chunk[0].trim().parse::<i32>().map_err(|_| {
+                pyo3::exceptions::PyValueError::new_err(format!("invalid group code: {}", chunk[0]))
+            })?;
+            pairs.push((code, chunk[1].trim().to_string()));
+        }
+
+        // split into groups starting with code 0
+        let mut groups: Vec<(String, Group)> = Vec::new();
+        for (code, value) in pairs {
+            if code == 0 {
+                groups.push((value, Vec::new()));
+            } else if let Some(group) = groups.last_mut() {
+                group.1.push((code, value));
+            }
+        }
+
+        let mut document = Self::new();
+        let mut section = String::new();
+        let mut index = 0;
+
+        while index < groups.len() {
+            let (name, group) = &groups[index];
+            index += 1;
+
+            match name.as_str() {
+                "SECTION" => {
+                    section = value(group, 2).unwrap_or_default();
+                    continue;
+                }
+                "ENDSEC" => {
+                    section.clear();
+                    continue;
+                }
+                "LAYER" if section == "TABLES" => {
+                    if let Some(layer) = value::<String>(group, 2) {
+                        document.layer(&layer);
+                    }
+                    continue;
+                }
+                _ if section != "ENTITIES" => continue,
+                _ => {}
+            }
+
+            let mut vertices = Vec::new();
+            if name == "POLYLINE" {
+                while index < groups.len() && groups[index].0 == "VERTEX" {
+                    vertices.push(groups[index].1.clone());
+                    index += 1;
+                }
+                if index < groups.len() && groups[index].0 == "SEQEND" {
+                    index += 1;
+                }
+            }
+
+            if let Some(entity) = read_entity(name, group, &vertices, spline_samples)? {
+                let layer: String = value(group, 8).unwrap_or_else(|| "0".to_string());
+                document.layer(&layer).push(entity);
+            }
+        }
+
+        Ok(document)
+    }
+
+    /// add($self, entity, layer="0")
+    /// --
+    ///
+    /// add(self: euklid_rs.io.DXFDocument, entity: Union[euklid_rs.polyline.PolyLine2D, euklid_rs.polyline.PolyLine3D, euklid_rs.arc.Path2D, euklid_rs.line.Segment2D, euklid_rs.arc.Arc2D, euklid_rs.arc.Circle2D, euklid_rs.spline.BSplineCurve3, euklid_rs.spline.BSplineCurve3_3D], layer: str = "0") -> None
+    /// add an entity to a layer. polylines with equal first and last node are written closed,
+    /// paths as polylines with bulges and arcs counter-clockwise
+    #[pyo3(signature = (entity, layer="0"))]
+    pub fn add(&mut self, entity: EntityArgs, layer: &str) {
+        let entity = match entity {
+            EntityArgs::PolyLine2D(line) => Entity::PolyLine2D(line),
+            EntityArgs::PolyLine3D(line) => Entity::PolyLine3D(line),
+            EntityArgs::Path(path) => Entity::Path(path),
+            EntityArgs::Segment(segment) => Entity::PolyLine2D(PolyLine2D {
+                nodes: vec![segment.p1, segment.p2],
+            }),
+            EntityArgs::Arc(arc) => Entity::Arc(arc),
+            EntityArgs::Circle(circle) => Entity::Circle(circle),
+            EntityArgs::BSplineCurve3(curve) => Entity::Spline2D(curve.controlpoints.copy()),
+            EntityArgs::BSplineCurve3_3D(curve) => Entity::Spline3D(curve.controlpoints.copy()),
+        };
+
+        self.layer(layer).push(entity);
+    }
+
+    #[getter]
+    pub fn layers(&self) -> Vec<String> {
+        self.layers.iter().map(|layer| layer.0.clone()).collect()
+    }
+
+    /// get_layer($self, name)
+    /// --
+    ///
+    /// get_layer(self: euklid_rs.io.DXFDocument, name: str) -> List[Union[euklid_rs.polyline.PolyLine2D, euklid_rs.polyline.PolyLine3D, euklid_rs.arc.Path2D, euklid_rs.arc.Arc2D, euklid_rs.arc.Circle2D, euklid_rs.spline.BSplineCurve3, euklid_rs.spline.BSplineCurve3_3D]]
+    /// get the entities of a layer. closed polylines repeat their first node,
+    /// polylines with bulges are returned as paths
+    pub fn get_layer(&self, py: Python, name: &str) -> PyResult<Vec<PyObject>> {
+        let layer = match self.layers.iter().find(|layer| layer.0 == name) {
+            Some(layer) => &layer.1,
+            None => return Err(pyo3::exceptions::PyKeyError::new_err(name.to_string())),
+        };
+
+        Ok(layer
+            .iter()
+            .map(|entity| match entity {
+                Entity::PolyLine2D(line) => line.copy().into_py(py),
+                Entity::PolyLine3D(line) => line.copy().into_py(py),
+                Entity::Path(path) => path.clone().into_py(py),
+                Entity::Arc(arc) => arc.into_py(py),
+                Entity::Circle(circle) => circle.into_py(py),
+                Entity::Spline2D(controlpoints) => {
+                    BSplineCurve3::new(controlpoints.copy()).into_py(py)
+                }
+                Entity::Spline3D(controlpoints) => {
+                    BSplineCurve3_3D::new(controlpoints.copy()).into_py(py)
+                }
+            })
+            .collect())
+    }
+
+    /// to_dxf($self)
+    /// --
+    ///
+    /// to_dxf(self: euklid_rs.io.DXFDocument) -> str
+    /// write the document as dxf r2000 (tables, blocks, entities with handles)
+    pub fn to_dxf(&self) -> String {
+        let mut writer = Writer::new();
+        let mut layers: Vec<&str> = self.layers.iter().map(|layer| layer.0.as_str()).collect();
+        if !layers.contains(&"0") {
+            layers.push("0");
+        }
+
+        writer.pair(0, "SECTION");
+        writer.pair(2, "CLASSES");
+        writer.pair(0, "ENDSEC");
+
+        writer.pair(0, "SECTION");
+        writer.pair(2, "TABLES");
+        writer.table("VPORT", &[]);
+        let linetype: &[(i32, &str)] = &[(3, ""), (72, "65"), (73, "0"), (40, "0.0")];
+        writer.table(
+            "LTYPE",
+            &[
+                ("ByBlock", "AcDbLinetypeTableRecord", linetype),
+                ("ByLayer", "AcDbLinetypeTableRecord", linetype),
+                ("Continuous", "AcDbLinetypeTableRecord", linetype),
+            ],
+        );
+        let layer: &[(i32, &str)] = &[(62, "7"), (6, "Continuous")];
+        let entries: Vec<_> = layers
+            .iter()
+            .map(|name| (*name, "AcDbLayerTableRecord", layer))
+            .collect();
+        writer.table("LAYER", &entries);
+        let style: &[(i32, &str)] = &[
+            (40, "0.0"),
+            (41, "1.0"),
+            (50, "0.0"),
+            (71, "0"),
+            (42, "2.5"),
+            (3, "txt"),
+            (4, ""),
+        ];
+        writer.table("STYLE", &[("Standard", "AcDbTextStyleTableRecord", style)]);
+        writer.table("VIEW", &[]);
+        writer.table("UCS", &[]);
+        writer.table("APPID", &[("ACAD", "AcDbRegAppTableRecord", &[])]);
+        writer.table("DIMSTYLE", &[("Standard", "AcDbDimStyleTableRecord", &[])]);
+        let records = writer.table(
+            "BLOCK_RECORD",
+            &[
+                ("*Model_Space", "AcDbBlockTableRecord", &[]),
+                ("*Paper_Space", "AcDbBlockTableRecord", &[]),
+            ],
+        );
+        writer.pair(0, "ENDSEC");
+
+        writer.pair(0, "SECTION");
+        writer.pair(2, "BLOCKS");
+        for (name, record) in ["*Model_Space", "*Paper_Space"].iter().zip(&records) {
+            writer.entity("BLOCK", record, "0", &["AcDbBlockBegin"]);
+            writer.pair(2, name);
+            writer.pair(70, 0);
+            writer.coordinates(10, &[0., 0., 0.]);
+            writer.pair(3, name);
+            writer.pair(1, "");
+            writer.entity("ENDBLK", record, "0", &["AcDbBlockEnd"]);
+        }
+        writer.pair(0, "ENDSEC");
+
+        writer.pair(0, "SECTION");
+        writer.pair(2, "ENTITIES");
+        for (name, entities) in &self.layers {
+            for entity in entities {
+                Self::write_entity(&mut writer, &records[0], name, entity);
+            }
+        }
+        writer.pair(0, "ENDSEC");
+
+        // root dictionary with the (empty) group dictionary
+        writer.pair(0, "SECTION");
+        writer.pair(2, "OBJECTS");
+        let root = writer.next_handle();
+        let groups = writer.next_handle();
+        writer.start("DICTIONARY", &root, "0");
+        writer.pair(100, "AcDbDictionary");
+        writer.pair(281, 1);
+        writer.pair(3, "ACAD_GROUP");
+        writer.pair(350, &groups);
+        writer.start("DICTIONARY", &groups, &root);
+        writer.pair(100, "AcDbDictionary");
+        writer.pair(281, 1);
+        writer.pair(0, "ENDSEC");
+        writer.pair(0, "EOF");
+
+        let mut header = Writer::new();
+        header.pair(0, "SECTION");
+        header.pair(2, "HEADER");
+        header.pair(9, "$ACADVER");
+        header.pair(1, "AC1015");
+        header.pair(9, "$HANDSEED");
+        header.pair(5, format!("{:X}", writer.handles + 1));
+        header.pair(0, "ENDSEC");
+
+        header.text + &writer.text
+    }
+
+    fn __repr__(&self) -> String {
+        let count: usize = self.layers.iter().map(|layer| layer.1.len()).sum();
+        format!(
+            "DXFDocument({} layers, {} entities)",
+            self.layers.len(),
+            count
+        )
+    }
+}
+
+// json data: {"PolyLine2D": {...}}, {"Spline2D": {"nodes": [...]}} (controlpoints), ...
+impl JsonData for Entity {
+    fn json_data(&self, py: Python) -> PyObject {
+        match self {
+            Entity::PolyLine2D(line) => variant(py, "PolyLine2D", line.json_data(py)),
+            Entity::PolyLine3D(line) => variant(py, "PolyLine3D", line.json_data(py)),
+            Entity::Path(path) => variant(py, "Path", path.json_data(py)),
+            Entity::Arc(arc) => variant(py, "Arc", arc.json_data(py)),
+            Entity::Circle(circle) => variant(py, "Circle", circle.json_data(py)),
+            Entity::Spline2D(controlpoints) => variant(py, "Spline2D", controlpoints.json_data(py)),
+            Entity::Spline3D(controlpoints) => variant(py, "Spline3D", controlpoints.json_data(py)),
+        }
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        let (name, data) = tagged(data)?;
+
+        Ok(match name.as_str() {
+            "PolyLine2D" => Entity::PolyLine2D(JsonData::from_json_data(data)?),
+            "PolyLine3D" => Entity::PolyLine3D(JsonData::from_json_data(data)?),
+            "Path" => Entity::Path(JsonData::from_json_data(data)?),
+            "Arc" => Entity::Arc(JsonData::from_json_data(data)?),
+            "Circle" => Entity::Circle(JsonData::from_json_data(data)?),
+            "Spline2D" => Entity::Spline2D(JsonData::from_json_data(data)?),
+            "Spline3D" => Entity::Spline3D(JsonData::from_json_data(data)?),
+            _ => return Err(unknown_variant(&name)),
+        })
+    }
+}
+
+// json data: {"layers": [[name, [entity, ...]], ...]}
+impl JsonData for DXFDocument {
+    fn json_data(&self, py: Python) -> PyObject {
+        [("layers", self.layers.json_data(py))]
+            .into_py_dict(py)
+            .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            layers: JsonData::from_json_data(data.get_item("layers")?)?,
+        })
+    }
+}
+
+pickle_methods!(DXFDocument);
@@ -0,0 +1,389 @@
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyDict};
+
+/// version of the json schema written by to_json. bump on incompatible changes of any type's data
+pub const JSON_VERSION: u32 = 1;
+
+/// Conversion from and to json-compatible python data (dicts, lists and numbers).
+/// The serde representation (cargo feature "serde") uses the same layout.
+pub trait JsonData: Sized {
+    fn json_data(&self, py: Python) -> PyObject;
+    fn from_json_data(data: &PyAny) -> PyResult<Self>;
+}
+
+impl<T: JsonData> JsonData for Vec<T> {
+    fn json_data(&self, py: Python) -> PyObject {
+        let items: Vec<PyObject> = self.iter().map(|item| item.json_data(py)).collect();
+
+        items.into_py(py)
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        data.iter()?.map(|item| T::from_json_data(item?)).collect()
+    }
+}
+
+macro_rules! json_primitive {
+    ($($type: ty),*) => {
+        $(
+            impl JsonData for $type {
+                fn json_data(&self, py: Python) -> PyObject {
+                    self.to_object(py)
+                }
+
+                fn from_json_data(data: &PyAny) -> PyResult<Self> {
+                    data.extract()
+                }
+            }
+        )*
+    };
+}
+
+json_primitive!(bool, f64, usize, String);
+
+// null or the value
+impl<T: JsonData> JsonData for Option<T> {
+    fn json_data(&self, py: Python) -> PyObject {
+        match self {
+            Some(value) => value.json_data(py),
+            None => py.None(),
+        }
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        if data.is_none() {
+            Ok(None)
+        } else {
+            Ok(Some(T::from_json_data(data)?))
+        }
+    }
+}
+
+// two-element list
+impl<A: JsonData, B: JsonData> JsonData for (A, B) {
+    fn json_data(&self, py: Python) -> PyObject {
+        vec![self.0.json_data(py), self.1.json_data(py)].into_py(py)
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        match data.extract::<Vec<&PyAny>>()?[..] {
+            [a, b] => Ok((A::from_json_data(a)?, B::from_json_data(b)?)),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(
+                "expected a list of two elements",
+            )),
+        }
+    }
+}
+
+/// externally tagged enum variant: {"Name": data}
+pub fn variant(py: Python, name: &str, data: PyObject) -> PyObject {
+    [(name, data)].into_py_dict(py).into()
+}
+
+/// split an externally tagged enum variant into name and data
+pub fn tagged(data: &PyAny) -> PyResult<(String, &PyAny)> {
+    let dict: &PyDict = data.downcast()?;
+
+    match dict.iter().next() {
+        Some((name, data)) if dict.len() == 1 => Ok((name.extract()?, data)),
+        _ => Err(pyo3::exceptions::PyValueError::new_err(
+            "expected a single-key dict {variant: data}",
+        )),
+    }
+}
+
+/// error for an unknown enum variant
+pub fn unknown_variant(name: &str) -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(format!("unknown variant: {}", name))
+}
+
+/// __from_json__ accepts the data either positionally or as keyword arguments
+pub fn json_argument<'a>(
+    data: Option<&'a PyAny>,
+    fields: Option<&'a PyDict>,
+) -> PyResult<&'a PyAny> {
+    match (data, fields) {
+        (Some(data), None) => Ok(data),
+        (None, Some(fields)) => Ok(fields),
+        _ => Err(pyo3::exceptions::PyTypeError::new_err(
+            "expected the json data either as a single argument or as keyword arguments",
+        )),
+    }
+}
+
+/// wrap data in the versioned envelope {"type": ..., "version": ..., "data": ...}
+pub fn dumps(py: Python, type_name: &str, data: PyObject) -> PyResult<String> {
+    let envelope = PyDict::new(py);
+    envelope.set_item("type", type_name)?;
+    envelope.set_item("version", JSON_VERSION)?;
+    envelope.set_item("data", data)?;
+
+    py.import("json")?
+        .call_method1("dumps", (envelope,))?
+        .extract()
+}
+
+/// unwrap the data of a versioned envelope, checking type and version
+pub fn loads<'py>(py: Python<'py>, type_name: &str, text: &str) -> PyResult<&'py PyAny> {
+    let envelope = py.import("json")?.call_method1("loads", (text,))?;
+
+    let found: String = envelope.get_item("type")?.extract()?;
+    if found != type_name {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "expected json for {}, got {}",
+            type_name, found
+        )));
+    }
+
+    let version: u32 = envelope.get_item("version")?.extract()?;
+    if version > JSON_VERSION {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "unsupported json version {} (supported: {})",
+            version, JSON_VERSION
+        )));
+    }
+
+    envelope.get_item("data")
+}
+
+/// __json__/__from_json__ and the pickle and copy protocols, based on JsonData
+macro_rules! pickle_methods {
+    ($dst: ident) => {
+        #[pymethods]
+        impl $dst {
+            pub fn __json__(&self, py: Python) -> PyObject {
+                $crate::io::json::JsonData::json_data(self, py)
+            }
+
+            #[staticmethod]
+            #[pyo3(signature = (data=None, **fields))]
+            pub fn __from_json__(
+                data: Option<&PyAny>,
+                fields: Option<&pyo3::types::PyDict>,
+            ) -> PyResult<Self> {
+                let data = $crate::io::json::json_argument(data, fields)?;
+
+                <Self as $crate::io::json::JsonData>::from_json_data(data)
+            }
+
+            pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (PyObject,))> {
+                let constructor = py.get_type::<Self>().getattr("__from_json__")?;
+                let data = $crate::io::json::JsonData::json_data(self, py);
+
+                Ok((constructor.into_py(py), (data,)))
+            }
+
+            pub fn __copy__(&self) -> Self {
+                self.clone()
+            }
+
+            // no python objects inside, a copy is a deep copy
+            pub fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+                self.clone()
+            }
+        }
+    };
+}
+
+/// pickle_methods and versioned json strings (to_json/from_json)
+macro_rules! json_methods {
+    ($dst: ident) => {
+        $crate::io::json::pickle_methods!($dst);
+
+        #[pymethods]
+        impl $dst {
+            /// to_json($self)
+            /// --
+            ///
+            #[doc = concat!("to_json(self: ", stringify!($dst), ") -> str")]
+            #[doc = "serialize to a json string with type and schema version"]
+            #[allow(clippy::wrong_self_convention)]
+            pub fn to_json(&self, py: Python) -> PyResult<String> {
+                let data = $crate::io::json::JsonData::json_data(self, py);
+
+                $crate::io::json::dumps(py, stringify!($dst), data)
+            }
+
+            /// from_json(text)
+            /// --
+            ///
+            #[doc = concat!("from_json(text: str) -> ", stringify!($dst))]
+            #[doc = "load from a json string written by to_json"]
+            #[staticmethod]
+            pub fn from_json(py: Python, text: &str) -> PyResult<Self> {
+                let data = $crate::io::json::loads(py, stringify!($dst), text)?;
+
+                <Self as $crate::io::json::JsonData>::from_json_data(data)
+            }
+        }
+    };
+}
+
+pub(crate) use json_methods;
+pub(crate) use pickle_methods;
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::JsonData;
+    use pyo3::prelude::*;
+    use serde_json::{json, Value};
+
+    use crate::arc::{Arc2D, Circle2D, Path2D, PathElement};
+    use crate::boundary::{BoundingBox2D, BoundingBox3D, OrientedBox2D, OrientedBox3D};
+    use crate::line::{Line2D, Line3D, Ray2D, Ray3D, Segment2D, Segment3D};
+    use crate::mesh::Mesh;
+    use crate::nesting::Placement;
+    use crate::plane::Plane;
+    use crate::polyline::{PolyLine2D, PolyLine3D};
+    use crate::spline::{BSplineCurve3, BSplineCurve3_3D, BezierCurve, BezierCurve3D};
+    use crate::vector::{Quaternion, Rotation2D, Transformation, Transformation2D};
+    use crate::vector::{Vector2D, Vector3D};
+
+    fn v2(x: f64, y: f64) -> Vector2D {
+        Vector2D::__new__([x, y])
+    }
+
+    fn v3(x: f64, y: f64, z: f64) -> Vector3D {
+        Vector3D::__new__([x, y, z])
+    }
+
+    fn polyline_2d() -> PolyLine2D {
+        PolyLine2D {
+            nodes: vec![v2(0., 0.), v2(1., 0.5), v2(2., -1.), v2(3., 0.)],
+        }
+    }
+
+    fn polyline_3d() -> PolyLine3D {
+        PolyLine3D {
+            nodes: vec![
+                v3(0., 0., 0.),
+                v3(1., 0.5, 2.),
+                v3(2., -1., 1.),
+                v3(3., 0., 0.),
+            ],
+        }
+    }
+
+    /// the layout of __json__, read back through the python json module
+    fn json_layout<T: JsonData>(value: &T) -> Value {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let text: String = py
+                .import("json")
+                .unwrap()
+                .call_method1("dumps", (value.json_data(py),))
+                .unwrap()
+                .extract()
+                .unwrap();
+
+            serde_json::from_str(&text).unwrap()
+        })
+    }
+
+    /// serialize, compare against the __json__ layout and deserialize again
+    fn round_trip<T>(value: &T)
+    where
+        T: JsonData + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let data = serde_json::to_value(value).unwrap();
+        assert_eq!(data, json_layout(value));
+
+        let restored: T = serde_json::from_value(data.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&restored).unwrap(), data);
+    }
+
+    #[test]
+    fn vectors() {
+        round_trip(&v2(1., -2.5));
+        round_trip(&v3(1., -2.5, 1e-10));
+        round_trip(&Quaternion::__new__(0.5, -0.5, 0.5, 0.5).unwrap());
+        round_trip(&Rotation2D::new(0.5));
+        round_trip(&Transformation2D::rotation(0.5, Some(v2(1., 2.))));
+        round_trip(
+            &Transformation::from_matrix(vec![
+                vec![1., 2., 0., 4.],
+                vec![0., 1., 0., -1.],
+                vec![0.5, 0., 2., 0.],
+                vec![0., 0., 0., 1.],
+            ])
+            .unwrap(),
+        );
+    }
+
+    #[test]
+    fn polylines_and_curves() {
+        round_trip(&polyline_2d());
+        round_trip(&polyline_3d());
+        round_trip(&BezierCurve::new(polyline_2d()));
+        round_trip(&BSplineCurve3::new(polyline_2d()));
+        round_trip(&BezierCurve3D::new(polyline_3d()));
+        round_trip(&BSplineCurve3_3D::new(polyline_3d()));
+    }
+
+    #[test]
+    fn lines_and_arcs() {
+        round_trip(&Line2D::try_new(v2(0., 0.), v2(1., 1.)).unwrap());
+        round_trip(&Ray2D::try_new(v2(0., 0.), v2(1., 1.)).unwrap());
+        round_trip(&Segment2D::try_new(v2(0., 0.), v2(1., 1.)).unwrap());
+        round_trip(&Line3D::try_new(v3(0., 0., 0.), v3(1., 1., 1.)).unwrap());
+        round_trip(&Ray3D::try_new(v3(0., 0., 0.), v3(1., 1., 1.)).unwrap());
+        round_trip(&Segment3D::try_new(v3(0., 0., 0.), v3(1., 1., 1.)).unwrap());
+
+        let arc = Arc2D::try_new(v2(1., 1.), 2., 0.5, -1.).unwrap();
+        round_trip(&arc);
+        round_trip(&Circle2D::try_new(v2(1., 1.), 2.).unwrap());
+        round_trip(&Path2D::new(vec![
+            PathElement::Segment(Segment2D::try_new(v2(0., 0.), arc.start()).unwrap()),
+            PathElement::Arc(arc),
+        ]));
+    }
+
+    #[test]
+    fn other_types() {
+        round_trip(&Plane::new(v3(1., 2., 3.), v3(1., 0., 0.), v3(0.5, 1., 0.)));
+        round_trip(&Mesh::new(polyline_3d().nodes, vec![[0, 1, 2], [0, 2, 3]]).unwrap());
+        round_trip(&BoundingBox2D::from_nodes(&polyline_2d().nodes));
+        round_trip(&BoundingBox3D::from_nodes(&polyline_3d().nodes));
+        round_trip(&OrientedBox2D::from_axis(&polyline_2d().nodes, v2(1., 1.)));
+        round_trip(&OrientedBox3D::from_axes(
+            &polyline_3d().nodes,
+            [v3(1., 0., 0.), v3(0., 1., 0.), v3(0., 0., 1.)],
+        ));
+        round_trip(&Placement {
+            index: 3,
+            angle: 0.5,
+            offset: v2(1., 2.),
+        });
+    }
+
+    #[test]
+    fn invalid_data() {
+        let non_affine = json!({"matrix": [[1., 0., 0., 0.], [0., 1., 0., 0.], [0., 0., 1., 0.], [0., 1., 0., 1.]]});
+        assert!(serde_json::from_value::<Transformation>(non_affine).is_err());
+
+        let non_affine = json!({"matrix": [[1., 0., 0.], [0., 1., 0.], [1., 0., 1.]]});
+        assert!(serde_json::from_value::<Transformation2D>(non_affine).is_err());
+
+        let zero = json!({"w": 0., "x": 0., "y": 0., "z": 0.});
+        assert!(serde_json::from_value::<Quaternion>(zero).is_err());
+
+        let degenerate = json!({"p1": [1., 2.], "p2": [1., 2.]});
+        assert!(serde_json::from_value::<Segment2D>(degenerate).is_err());
+
+        let radius = json!({"center": [0., 0.], "radius": -1., "start_angle": 0., "end_angle": 1.});
+        assert!(serde_json::from_value::<Arc2D>(radius).is_err());
+
+        let sweep = json!({"center": [0., 0.], "radius": 1., "start_angle": 0., "end_angle": 1e12});
+        assert!(serde_json::from_value::<Arc2D>(sweep).is_err());
+
+        let radius = json!({"center": [0., 0.], "radius": 0.});
+        assert!(serde_json::from_value::<Circle2D>(radius).is_err());
+
+        let path = json!({"elements": [{"Segment": {"p1": [0., 0.], "p2": [0., 0.]}}]});
+        assert!(serde_json::from_value::<Path2D>(path).is_err());
+
+        let mesh = json!({"vertices": [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]], "triangles": [[0, 1, 3]]});
+        assert!(serde_json::from_value::<Mesh>(mesh).is_err());
+    }
+}
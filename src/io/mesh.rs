@@ -0,0 +1,719 @@
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyBytes};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::io::json::{pickle_methods, tagged, unknown_variant, variant, JsonData};
+use crate::mesh::Mesh;
+use crate::polyline::PolyLine3D;
+use crate::vector::_vector::*;
+
+fn invalid(message: &str) -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(message.to_string())
+}
+
+fn normal(mesh: &Mesh, triangle: &[usize; 3]) -> Vector3D {
+    let [a, b, c] = *triangle;
+    let normal =
+        (mesh.vertices[b] - mesh.vertices[a]).cross(&(mesh.vertices[c] - mesh.vertices[a]));
+
+    if normal.length() > 0. {
+        normal.normalized()
+    } else {
+        normal
+    }
+}
+
+/// triangulate a convex polygon as a fan
+fn fan(polygon: &[usize]) -> impl Iterator<Item = [usize; 3]> + '_ {
+    (1..polygon.len().saturating_sub(1)).map(|i| [polygon[0], polygon[i], polygon[i + 1]])
+}
+
+/// mesh from triangle corners, identical vertices are merged
+fn merged(corners: Vec<[f64; 3]>) -> Mesh {
+    let mut indices: HashMap<[u64; 3], usize> = HashMap::new();
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::with_capacity(corners.len() / 3);
+
+    for triangle in corners.chunks(3) {
+        let mut result = [0; 3];
+
+        for (i, corner) in triangle.iter().enumerate() {
+            // +0. turns -0. into 0.
+            let key = corner.map(|x| (x + 0.).to_bits());
+            result[i] = *indices.entry(key).or_insert_with(|| {
+                vertices.push(Vector3D::__new__(*corner));
+                vertices.len() - 1
+            });
+        }
+
+        triangles.push(result);
+    }
+
+    Mesh {
+        vertices,
+        triangles,
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, size: usize) -> PyResult<&'a [u8]> {
+        if self.position + size > self.data.len() {
+            return Err(invalid("unexpected end of data"));
+        }
+        self.position += size;
+        Ok(&self.data[self.position - size..self.position])
+    }
+
+    fn f32(&mut self) -> PyResult<f64> {
+        Ok(f32::from_le_bytes(self.take(4)?.try_into().unwrap()) as f64)
+    }
+}
+
+/// Binary and ascii STL files
+#[pyclass(name = "STL", module = "euklid_rs.io")]
+pub struct Stl {}
+
+#[pymethods]
+impl Stl {
+    /// write(mesh, binary=True, name="euklid_rs")
+    /// --
+    ///
+    /// write(mesh: euklid_rs.mesh.Mesh, binary: bool = True, name: str = "euklid_rs") -> bytes
+    /// write a mesh with facet normals
+    #[staticmethod]
+    #[pyo3(signature = (mesh, binary=true, name="euklid_rs"))]
+    pub fn write(py: Python, mesh: &Mesh, binary: bool, name: &str) -> PyObject {
+        if binary {
+            let mut result = Vec::with_capacity(84 + 50 * mesh.triangles.len());
+            let mut header = [0u8; 80];
+            let length = name.len().min(80);
+            header[..length].copy_from_slice(&name.as_bytes()[..length]);
+
+            result.extend_from_slice(&header);
+            result.extend_from_slice(&(mesh.triangles.len() as u32).to_le_bytes());
+
+            for triangle in &mesh.triangles {
+                let normal = normal(mesh, triangle);
+                let points = [normal]
+                    .into_iter()
+                    .chain(triangle.iter().map(|index| mesh.vertices[*index]));
+
+                for point in points {
+                    for value in point.v.iter() {
+                        result.extend_from_slice(&(*value as f32).to_le_bytes());
+                    }
+                }
+                result.extend_from_slice(&[0, 0]);
+            }
+
+            PyBytes::new(py, &result).into()
+        } else {
+            let mut result = String::new();
+            writeln!(result, "solid {}", name).unwrap();
+
+            for triangle in &mesh.triangles {
+                let n = normal(mesh, triangle);
+                writeln!(result, "  facet normal {} {} {}", n.v[0], n.v[1], n.v[2]).unwrap();
+                writeln!(result, "    outer loop").unwrap();
+                for index in triangle {
+                    let p = mesh.vertices[*index];
+                    writeln!(result, "      vertex {} {} {}", p.v[0], p.v[1], p.v[2]).unwrap();
+                }
+                writeln!(result, "    endloop\n  endfacet").unwrap();
+            }
+
+            writeln!(result, "endsolid {}", name).unwrap();
+            PyBytes::new(py, result.as_bytes()).into()
+        }
+    }
+
+    /// read(data)
+    /// --
+    ///
+    /// read(data: bytes) -> euklid_rs.mesh.Mesh
+    /// read a binary or ascii stl file, identical vertices are merged
+    #[staticmethod]
+    pub fn read(data: &[u8]) -> PyResult<Mesh> {
+        let mut corners = Vec::new();
+
+        let binary = data.len() >= 84 && {
+            let count = u32::from_le_bytes(data[80..84].try_into().unwrap()) as usize;
+            data.len() == 84 + 50 * count
+        };
+
+        if binary {
+            let mut reader = Reader { data, position: 84 };
+
+            while reader.position < data.len() {
+                reader.take(12)?;
+                for _ in 0..3 {
+                    corners.push([reader.f32()?, reader.f32()?, reader.f32()?]);
+                }
+                reader.take(2)?;
+            }
+        } else {
+            let text = std::str::from_utf8(data).map_err(|_| invalid("invalid stl data"))?;
+            if !text.trim_start().starts_with("solid") {
+                return Err(invalid("invalid stl data"));
+            }
+
+            for line in text.lines() {
+                let mut parts = line.split_whitespace();
+                if parts.next() == Some("vertex") {
+                    let values: Vec<f64> = parts.filter_map(|part| part.parse().ok()).collect();
+                    if values.len() != 3 {
+                        return Err(invalid(&format!("invalid vertex: {}", line.trim())));
+                    }
+                    corners.push([values[0], values[1], values[2]]);
+                }
+            }
+
+            if corners.len() % 3 != 0 {
+                return Err(invalid("incomplete facet"));
+            }
+        }
+
+        Ok(merged(corners))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Scalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl Scalar {
+    fn parse(name: &str) -> PyResult<Self> {
+        Ok(match name {
+            "char" | "int8" => Scalar::I8,
+            "uchar" | "uint8" => Scalar::U8,
+            "short" | "int16" => Scalar::I16,
+            "ushort" | "uint16" => Scalar::U16,
+            "int" | "int32" => Scalar::I32,
+            "uint" | "uint32" => Scalar::U32,
+            "float" | "float32" => Scalar::F32,
+            "double" | "float64" => Scalar::F64,
+            _ => return Err(invalid(&format!("invalid ply type: {}", name))),
+        })
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Scalar::I8 | Scalar::U8 => 1,
+            Scalar::I16 | Scalar::U16 => 2,
+            Scalar::I32 | Scalar::U32 | Scalar::F32 => 4,
+            Scalar::F64 => 8,
+        }
+    }
+
+    fn read(&self, bytes: &[u8], big_endian: bool) -> f64 {
+        macro_rules! convert {
+            ($t: ty) => {{
+                let array = bytes.try_into().unwrap();
+                if big_endian {
+                    <$t>::from_be_bytes(array) as f64
+                } else {
+                    <$t>::from_le_bytes(array) as f64
+                }
+            }};
+        }
+
+        match self {
+            Scalar::I8 => convert!(i8),
+            Scalar::U8 => convert!(u8),
+            Scalar::I16 => convert!(i16),
+            Scalar::U16 => convert!(u16),
+            Scalar::I32 => convert!(i32),
+            Scalar::U32 => convert!(u32),
+            Scalar::F32 => convert!(f32),
+            Scalar::F64 => convert!(f64),
+        }
+    }
+}
+
+struct Property {
+    name: String,
+    // count type of list properties
+    list: Option<Scalar>,
+    scalar: Scalar,
+}
+
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+/// Binary and ascii PLY files (vertex positions and faces)
+#[pyclass(name = "PLY", module = "euklid_rs.io")]
+pub struct Ply {}
+
+#[pymethods]
+impl Ply {
+    /// write(mesh, binary=True)
+    /// --
+    ///
+    /// write(mesh: euklid_rs.mesh.Mesh, binary: bool = True) -> bytes
+    /// write a mesh (binary little endian or ascii)
+    #[staticmethod]
+    #[pyo3(signature = (mesh, binary=true))]
+    pub fn write(py: Python, mesh: &Mesh, binary: bool) -> PyObject {
+        let mut header = String::new();
+        writeln!(
+            header,
+            "ply\nformat {} 1.0\ncomment euklid_rs",
+            if binary {
+                "binary_little_endian"
+            } else {
+                "ascii"
+            }
+        )
+        .unwrap();
+        writeln!(header, "element vertex {}", mesh.vertices.len()).unwrap();
+        writeln!(
+            header,
+            "property double x\nproperty double y\nproperty double z"
+        )
+        .unwrap();
+        writeln!(header, "element face {}", mesh.triangles.len()).unwrap();
+        writeln!(header, "property list uchar int vertex_indices\nend_header").unwrap();
+
+        let mut result = header.into_bytes();
+
+        if binary {
+            for vertex in &mesh.vertices {
+                for value in vertex.v.iter() {
+                    result.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            for triangle in &mesh.triangles {
+                result.push(3);
+                for index in triangle {
+                    result.extend_from_slice(&(*index as i32).to_le_bytes());
+                }
+            }
+        } else {
+            let mut body = String::new();
+            for vertex in &mesh.vertices {
+                writeln!(body, "{} {} {}", vertex.v[0], vertex.v[1], vertex.v[2]).unwrap();
+            }
+            for [a, b, c] in &mesh.triangles {
+                writeln!(body, "3 {} {} {}", a, b, c).unwrap();
+            }
+            result.extend_from_slice(body.as_bytes());
+        }
+
+        PyBytes::new(py, &result).into()
+    }
+
+    /// read(data)
+    /// --
+    ///
+    /// read(data: bytes) -> euklid_rs.mesh.Mesh
+    /// read vertex positions and faces of a ply file, polygons are triangulated as fans
+    #[staticmethod]
+    pub fn read(data: &[u8]) -> PyResult<Mesh> {
+        let end = data
+            .windows(11)
+            .position(|window| window == b"end_header\n" || window == b"end_header\r")
+            .ok_or_else(|| invalid("missing ply header"))?;
+        let header =
+            std::str::from_utf8(&data[..end]).map_err(|_| invalid("invalid ply header"))?;
+        let mut body = end + 11;
+        if data.get(body - 1) == Some(&b'\r') && data.get(body) == Some(&b'\n') {
+            body += 1;
+        }
+
+        let mut format = "";
+        let mut elements: Vec<Element> = Vec::new();
+
+        for (i, line) in header.lines().enumerate() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+
+            match parts.as_slice() {
+                ["ply"] if i == 0 => {}
+                _ if i == 0 => return Err(invalid("missing ply header")),
+                ["format", name, _] => format = name,
+                ["element", name, count] => elements.push(Element {
+                    name: name.to_string(),
+                    count: count
+                        .parse()
+                        .map_err(|_| invalid("invalid element count"))?,
+                    properties: Vec::new(),
+                }),
+                ["property", "list", count, item, name] => elements
+                    .last_mut()
+                    .ok_or_else(|| invalid("property without element"))?
+                    .properties
+                    .push(Property {
+                        name: name.to_string(),
+                        list: Some(Scalar::parse(count)?),
+                        scalar: Scalar::parse(item)?,
+                    }),
+                ["property", scalar, name] => elements
+                    .last_mut()
+                    .ok_or_else(|| invalid("property without element"))?
+                    .properties
+                    .push(Property {
+                        name: name.to_string(),
+                        list: None,
+                        scalar: Scalar::parse(scalar)?,
+                    }),
+                _ => {}
+            }
+        }
+
+        let binary = match format {
+            "ascii" => None,
+            "binary_little_endian" => Some(false),
+            "binary_big_endian" => Some(true),
+            _ => return Err(invalid(&format!("invalid ply format: {}", format))),
+        };
+
+        let mut reader = Reader {
+            data,
+            position: body,
+        };
+        let text = match binary {
+            None => std::str::from_utf8(&data[body..]).map_err(|_| invalid("invalid ply data"))?,
+            Some(_) => "",
+        };
+        let mut tokens = text.split_whitespace();
+
+        let mut next = |scalar: Scalar| -> PyResult<f64> {
+            match binary {
+                Some(big_endian) => Ok(scalar.read(reader.take(scalar.size())?, big_endian)),
+                None => tokens
+                    .next()
+                    .and_then(|token| token.parse().ok())
+                    .ok_or_else(|| invalid("unexpected end of ply data")),
+            }
+        };
+
+        let mut vertices = Vec::new();
+        let mut triangles = Vec::new();
+
+        for element in &elements {
+            for _ in 0..element.count {
+                let mut position = [0.; 3];
+                let mut polygon = Vec::new();
+
+                for property in &element.properties {
+                    match property.list {
+                        Some(count) => {
+                            let count = next(count)? as usize;
+                            let items = (0..count)
+                                .map(|_| next(property.scalar))
+                                .collect::<PyResult<Vec<f64>>>()?;
+
+                            if property.name == "vertex_indices" || property.name == "vertex_index"
+                            {
+                                polygon = items.into_iter().map(|index| index as usize).collect();
+                            }
+                        }
+                        None => {
+                            let value = next(property.scalar)?;
+                            match property.name.as_str() {
+                                "x" => position[0] = value,
+                                "y" => position[1] = value,
+                                "z" => position[2] = value,
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+
+                match element.name.as_str() {
+                    "vertex" => vertices.push(Vector3D::__new__(position)),
+                    "face" => triangles.extend(fan(&polygon)),
+                    _ => {}
+                }
+            }
+        }
+
+        Mesh::new(vertices, triangles)
+    }
+}
+
+#[derive(Clone)]
+enum Item {
+    Mesh(Mesh),
+    PolyLine(PolyLine3D),
+}
+
+#[derive(FromPyObject)]
+pub enum ItemArgs {
+    Mesh(Mesh),
+    PolyLine(PolyLine3D),
+}
+
+/// Wavefront OBJ file with named groups of meshes and polylines (line elements)
+#[pyclass(module = "euklid_rs.io")]
+#[derive(Clone)]
+pub struct OBJDocument {
+    groups: Vec<(String, Vec<Item>)>,
+}
+
+impl OBJDocument {
+    fn group(&mut self, name: &str) -> &mut Vec<Item> {
+        let index = match self.groups.iter().position(|group| group.0 == name) {
+            Some(index) => index,
+            None => {
+                self.groups.push((name.to_string(), Vec::new()));
+                self.groups.len() - 1
+            }
+        };
+
+        &mut self.groups[index].1
+    }
+}
+
+#[pymethods]
+impl OBJDocument {
+    #[new]
+    pub fn new() -> Self {
+        Self { groups: Vec::new() }
+    }
+
+    /// from_obj(text)
+    /// --
+    ///
+    /// from_obj(text: str) -> euklid_rs.io.OBJDocument
+    /// read vertices, faces and lines. the faces of each group form one mesh (triangulated as fans),
+    /// each line element is a polyline
+    #[staticmethod]
+    pub fn from_obj(text: &str) -> PyResult<Self> {
+        let mut vertices: Vec<Vector3D> = Vec::new();
+        let mut faces: Vec<(String, Vec<[usize; 3]>)> = Vec::new();
+        let mut document = Self::new();
+        let mut group = "default".to_string();
+
+        for (number, line) in text.lines().enumerate() {
+            let mut parts = line.split_whitespace();
+            let keyword = parts.next();
+
+            // indices are 1-based, negative indices are relative to the end
+            let index = |part: &str| -> PyResult<usize> {
+                let value: i64 = part
+                    .split('/')
+                    .next()
+                    .and_then(|index| index.parse().ok())
+                    .ok_or_else(|| invalid(&format!("invalid index in line {}", number + 1)))?;
+                let index = if value < 0 {
+                    vertices.len() as i64 + value
+                } else {
+                    value - 1
+                };
+
+                if index < 0 || index as usize >= vertices.len() {
+                    return Err(invalid(&format!(
+                        "index out of bounds in line {}",
+                        number + 1
+                    )));
+                }
+                Ok(index as usize)
+            };
+
+            match keyword {
+                Some("v") => {
+                    let values: Vec<f64> =
+                        parts.take(3).filter_map(|part| part.parse().ok()).collect();
+                    if values.len() != 3 {
+                        return Err(invalid(&format!("invalid vertex in line {}", number + 1)));
+                    }
+                    vertices.push(Vector3D::__new__([values[0], values[1], values[2]]));
+                }
+                Some("g") | Some("o") => {
+                    group = parts.collect::<Vec<&str>>().join(" ");
+                    if group.is_empty() {
+                        group = "default".to_string();
+                    }
+                    document.group(&group);
+                }
+                Some("f") => {
+                    let polygon = parts.map(index).collect::<PyResult<Vec<usize>>>()?;
+                    let triangles = match faces.iter_mut().find(|faces| faces.0 == group) {
+                        Some(faces) => &mut faces.1,
+                        None => {
+                            faces.push((group.clone(), Vec::new()));
+                            &mut faces.last_mut().unwrap().1
+                        }
+                    };
+                    triangles.extend(fan(&polygon));
+                }
+                Some("l") => {
+                    let nodes = parts
+                        .map(|part| index(part).map(|i| vertices[i]))
+                        .collect::<PyResult<Vec<Vector3D>>>()?;
+                    document
+                        .group(&group)
+                        .push(Item::PolyLine(PolyLine3D { nodes }));
+                }
+                _ => {}
+            }
+        }
+
+        // one mesh per group with the used vertices only
+        for (name, triangles) in faces {
+            let mut indices = HashMap::new();
+            let mut mesh_vertices = Vec::new();
+            let triangles = triangles
+                .iter()
+                .map(|triangle| {
+                    triangle.map(|index| {
+                        *indices.entry(index).or_insert_with(|| {
+                            mesh_vertices.push(vertices[index]);
+                            mesh_vertices.len() - 1
+                        })
+                    })
+                })
+                .collect();
+
+            document.group(&name).insert(
+                0,
+                Item::Mesh(Mesh {
+                    vertices: mesh_vertices,
+                    triangles,
+                }),
+            );
+        }
+
+        Ok(document)
+    }
+
+    /// add($self, item, group="default")
+    /// --
+    ///
+    /// add(self: euklid_rs.io.OBJDocument, item: Union[euklid_rs.mesh.Mesh, euklid_rs.polyline.PolyLine3D], group: str = "default") -> None
+    /// add a mesh or a polyline to a group
+    #[pyo3(signature = (item, group="default"))]
+    pub fn add(&mut self, item: ItemArgs, group: &str) {
+        let item = match item {
+            ItemArgs::Mesh(mesh) => Item::Mesh(mesh),
+            ItemArgs::PolyLine(line) => Item::PolyLine(line),
+        };
+
+        self.group(group).push(item);
+    }
+
+    #[getter]
+    pub fn groups(&self) -> Vec<String> {
+        self.groups.iter().map(|group| group.0.clone()).collect()
+    }
+
+    /// get_group($self, name)
+    /// --
+    ///
+    /// get_group(self: euklid_rs.io.OBJDocument, name: str) -> List[Union[euklid_rs.mesh.Mesh, euklid_rs.polyline.PolyLine3D]]
+    /// get the meshes and polylines of a group
+    pub fn get_group(&self, py: Python, name: &str) -> PyResult<Vec<PyObject>> {
+        match self.groups.iter().find(|group| group.0 == name) {
+            Some(group) => Ok(group
+                .1
+                .iter()
+                .map(|item| match item {
+                    Item::Mesh(mesh) => mesh.clone().into_py(py),
+                    Item::PolyLine(line) => line.copy().into_py(py),
+                })
+                .collect()),
+            None => Err(pyo3::exceptions::PyKeyError::new_err(name.to_string())),
+        }
+    }
+
+    /// to_obj($self)
+    /// --
+    ///
+    /// to_obj(self: euklid_rs.io.OBJDocument) -> str
+    /// write all groups, polylines as line elements
+    pub fn to_obj(&self) -> String {
+        let mut result = String::from("# euklid_rs\n");
+        let mut offset = 1;
+
+        for (name, items) in &self.groups {
+            writeln!(result, "g {}", name).unwrap();
+
+            for item in items {
+                let vertices = match item {
+                    Item::Mesh(mesh) => &mesh.vertices,
+                    Item::PolyLine(line) => &line.nodes,
+                };
+                for vertex in vertices {
+                    writeln!(result, "v {} {} {}", vertex.v[0], vertex.v[1], vertex.v[2]).unwrap();
+                }
+
+                match item {
+                    Item::Mesh(mesh) => {
+                        for [a, b, c] in &mesh.triangles {
+                            writeln!(result, "f {} {} {}", a + offset, b + offset, c + offset)
+                                .unwrap();
+                        }
+                    }
+                    Item::PolyLine(line) => {
+                        let indices: Vec<String> = (0..line.nodes.len())
+                            .map(|i| (i + offset).to_string())
+                            .collect();
+                        writeln!(result, "l {}", indices.join(" ")).unwrap();
+                    }
+                }
+
+                offset += vertices.len();
+            }
+        }
+
+        result
+    }
+
+    fn __repr__(&self) -> String {
+        format!("OBJDocument({} groups)", self.groups.len())
+    }
+}
+
+// json data: {"Mesh": {...}} or {"PolyLine": {...}}
+impl JsonData for Item {
+    fn json_data(&self, py: Python) -> PyObject {
+        match self {
+            Item::Mesh(mesh) => variant(py, "Mesh", mesh.json_data(py)),
+            Item::PolyLine(line) => variant(py, "PolyLine", line.json_data(py)),
+        }
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        let (name, data) = tagged(data)?;
+
+        match name.as_str() {
+            "Mesh" => Ok(Item::Mesh(JsonData::from_json_data(data)?)),
+            "PolyLine" => Ok(Item::PolyLine(JsonData::from_json_data(data)?)),
+            _ => Err(unknown_variant(&name)),
+        }
+    }
+}
+
+// json data: {"groups": [[name, [item, ...]], ...]}
+impl JsonData for OBJDocument {
+    fn json_data(&self, py: Python) -> PyObject {
+        [("groups", self.groups.json_data(py))]
+            .into_py_dict(py)
+            .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            groups: JsonData::from_json_data(data.get_item("groups")?)?,
+        })
+    }
+}
+
+pickle_methods!(OBJDocument);
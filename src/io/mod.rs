@@ -0,0 +1,35 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+mod dxf;
+pub mod json;
+mod mesh;
+mod plotter;
+mod svg;
+
+pub use dxf::DXFDocument;
+pub use mesh::{OBJDocument, Ply, Stl};
+pub use plotter::PlotterWriter;
+pub use svg::SVGDocument;
+
+pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    #[pymodule]
+    fn io(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<PlotterWriter>()?;
+        m.add_class::<SVGDocument>()?;
+        m.add_class::<DXFDocument>()?;
+        m.add_class::<OBJDocument>()?;
+        m.add_class::<Stl>()?;
+        m.add_class::<Ply>()?;
+        m.add("JSON_VERSION", json::JSON_VERSION)?;
+        Ok(())
+    }
+
+    m.add_wrapped(wrap_pymodule!(io))?;
+
+    let sys = PyModule::import(_py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item("euklid_rs.io", m.getattr("io")?)?;
+    Ok(())
+}
@@ -0,0 +1,444 @@
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+use crate::arc::{Arc2D, Path2D, PathElement};
+use crate::io::json::{pickle_methods, JsonData};
+use crate::line::Segment2D;
+use crate::polyline::PolyLine2D;
+use crate::vector::_vector::*;
+
+const PLOTTER_TOLERANCE: f64 = 1e-9;
+
+#[derive(FromPyObject)]
+pub enum ToolpathArgs {
+    PolyLine(PolyLine2D),
+    Path(Path2D),
+}
+
+enum Move {
+    Travel(Vector2D),
+    Line(Vector2D),
+    Arc(Arc2D),
+}
+
+/// format a number with a fixed precision, without negative zeros
+pub fn number(value: f64, precision: usize) -> String {
+    let result = format!("{:.*}", precision, value);
+
+    if result.starts_with('-') && result.trim_matches(['-', '0', '.']).is_empty() {
+        result[1..].to_string()
+    } else {
+        result
+    }
+}
+
+/// Writer for 2D cutting and plotting paths (G-code and HPGL).
+/// Coordinates are in units ("mm" or "in"), the feed rate in units per minute.
+#[pyclass(module = "euklid_rs.io")]
+#[derive(Clone)]
+pub struct PlotterWriter {
+    #[pyo3(get)]
+    pub units: String,
+
+    #[pyo3(get, set)]
+    pub feed_rate: f64,
+
+    /// g-code command to lift the tool
+    #[pyo3(get, set)]
+    pub tool_up: String,
+
+    /// g-code command to lower the tool
+    #[pyo3(get, set)]
+    pub tool_down: String,
+
+    /// length of the tangential approach before each path
+    #[pyo3(get, set)]
+    pub lead_in: f64,
+
+    /// length of the tangential overrun after each path
+    #[pyo3(get, set)]
+    pub lead_out: f64,
+
+    /// reorder and reverse paths to minimize the travel distance
+    #[pyo3(get, set)]
+    pub optimize: bool,
+
+    /// number of decimals in g-code
+    #[pyo3(get, set)]
+    pub precision: usize,
+
+    /// hpgl pen number
+    #[pyo3(get, set)]
+    pub pen: usize,
+
+    paths: Vec<Vec<PathElement>>,
+}
+
+impl PlotterWriter {
+    fn hpgl_scale(&self) -> f64 {
+        // plotter units: 0.025 mm
+        match self.units.as_str() {
+            "in" => 1016.,
+            _ => 40.,
+        }
+    }
+
+    /// paths in output order, greedy nearest neighbour starting at the origin
+    fn ordered(&self) -> Vec<Vec<PathElement>> {
+        if !self.optimize {
+            return self.paths.clone();
+        }
+
+        let mut remaining: Vec<&Vec<PathElement>> = self.paths.iter().collect();
+        let mut result = Vec::with_capacity(remaining.len());
+        let mut position = Vector2D::__new__([0., 0.]);
+
+        while !remaining.is_empty() {
+            let mut best = (0, false, f64::INFINITY);
+
+            for (i, path) in remaining.iter().enumerate() {
+                let to_start = (path[0].start() - position).length();
+                let to_end = (path[path.len() - 1].end() - position).length();
+
+                if to_start < best.2 {
+                    best = (i, false, to_start);
+                }
+                if to_end < best.2 {
+                    best = (i, true, to_end);
+                }
+            }
+
+            let path = remaining.remove(best.0);
+            let path: Vec<PathElement> = if best.1 {
+                path.iter().rev().map(|element| element.reverse()).collect()
+            } else {
+                path.clone()
+            };
+
+            position = path[path.len() - 1].end();
+            result.push(path);
+        }
+
+        result
+    }
+
+    fn moves(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+
+        for path in self.ordered() {
+            let first = &path[0];
+            let last = &path[path.len() - 1];
+
+            moves.push(Move::Travel(
+                first.start() - first.tangent(0.) * self.lead_in,
+            ));
+            if self.lead_in > 0. {
+                moves.push(Move::Line(first.start()));
+            }
+
+            for element in &path {
+                moves.push(match element {
+                    PathElement::Segment(segment) => Move::Line(segment.p2),
+                    PathElement::Arc(arc) => Move::Arc(*arc),
+                });
+            }
+
+            if self.lead_out > 0. {
+                moves.push(Move::Line(last.end() + last.tangent(1.) * self.lead_out));
+            }
+        }
+
+        moves
+    }
+}
+
+#[pymethods]
+impl PlotterWriter {
+    #[new]
+    #[pyo3(signature = (units="mm", feed_rate=1000., tool_up="M5", tool_down="M3", lead_in=0., lead_out=0., optimize=true, precision=3, pen=1))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        units: &str,
+        feed_rate: f64,
+        tool_up: &str,
+        tool_down: &str,
+        lead_in: f64,
+        lead_out: f64,
+        optimize: bool,
+        precision: usize,
+        pen: usize,
+    ) -> PyResult<Self> {
+        if units != "mm" && units != "in" {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "invalid units: {} (mm or in)",
+                units
+            )));
+        }
+
+        Ok(Self {
+            units: units.to_string(),
+            feed_rate,
+            tool_up: tool_up.to_string(),
+            tool_down: tool_down.to_string(),
+            lead_in,
+            lead_out,
+            optimize,
+            precision,
+            pen,
+            paths: Vec::new(),
+        })
+    }
+
+    /// add($self, path)
+    /// --
+    ///
+    /// add(self: euklid_rs.io.PlotterWriter, path: Union[euklid_rs.polyline.PolyLine2D, euklid_rs.arc.Path2D]) -> None
+    /// add a toolpath. paths are split where consecutive elements do not connect
+    pub fn add(&mut self, path: ToolpathArgs) {
+        let elements = match path {
+            ToolpathArgs::PolyLine(line) => line
+                .nodes
+                .windows(2)
+                .filter(|edge| (edge[1] - edge[0]).length() > PLOTTER_TOLERANCE)
+                .map(|edge| {
+                    PathElement::Segment(Segment2D {
+                        p1: edge[0],
+                        p2: edge[1],
+                    })
+                })
+                .collect(),
+            ToolpathArgs::Path(path) => path.elements,
+        };
+
+        let mut current: Vec<PathElement> = Vec::new();
+
+        for element in elements {
+            if let Some(last) = current.last() {
+                if (element.start() - last.end()).length() > PLOTTER_TOLERANCE {
+                    self.paths.push(std::mem::take(&mut current));
+                }
+            }
+            current.push(element);
+        }
+
+        if !current.is_empty() {
+            self.paths.push(current);
+        }
+    }
+
+    /// get_travel_length($self)
+    /// --
+    ///
+    /// get_travel_length(self: euklid_rs.io.PlotterWriter) -> float
+    /// get the length of all moves with the tool up, starting at the origin
+    pub fn get_travel_length(&self) -> f64 {
+        let mut position = Vector2D::__new__([0., 0.]);
+        let mut length = 0.;
+
+        for movement in self.moves() {
+            match movement {
+                Move::Travel(target) => {
+                    length += (target - position).length();
+                    position = target;
+                }
+                Move::Line(target) => position = target,
+                Move::Arc(arc) => position = arc.end(),
+            }
+        }
+
+        length
+    }
+
+    /// to_gcode($self)
+    /// --
+    ///
+    /// to_gcode(self: euklid_rs.io.PlotterWriter) -> str
+    /// write all paths as g-code (absolute coordinates, arcs as G2/G3 with relative centers)
+    pub fn to_gcode(&self) -> String {
+        let p = self.precision;
+        let mut result = String::new();
+        let mut position = Vector2D::__new__([0., 0.]);
+        let mut tool_down = false;
+        let mut feed = false;
+
+        let units = if self.units == "in" { "G20" } else { "G21" };
+        writeln!(result, "{}\nG90\n{}", units, self.tool_up).unwrap();
+
+        for movement in self.moves() {
+            match movement {
+                Move::Travel(target) => {
+                    if tool_down {
+                        writeln!(result, "{}", self.tool_up).unwrap();
+                        tool_down = false;
+                    }
+                    writeln!(
+                        result,
+                        "G0 X{} Y{}",
+                        number(target.v[0], p),
+                        number(target.v[1], p)
+                    )
+                    .unwrap();
+                    position = target;
+                    continue;
+                }
+                Move::Line(target) => {
+                    if !tool_down {
+                        writeln!(result, "{}", self.tool_down).unwrap();
+                        tool_down = true;
+                        feed = true;
+                    }
+                    write!(
+                        result,
+                        "G1 X{} Y{}",
+                        number(target.v[0], p),
+                        number(target.v[1], p)
+                    )
+                    .unwrap();
+                    position = target;
+                }
+                Move::Arc(arc) => {
+                    if !tool_down {
+                        writeln!(result, "{}", self.tool_down).unwrap();
+                        tool_down = true;
+                        feed = true;
+                    }
+                    let end = arc.end();
+                    let offset = arc.center - position;
+                    write!(
+                        result,
+                        "{} X{} Y{} I{} J{}",
+                        if arc.sweep() > 0. { "G3" } else { "G2" },
+                        number(end.v[0], p),
+                        number(end.v[1], p),
+                        number(offset.v[0], p),
+                        number(offset.v[1], p)
+                    )
+                    .unwrap();
+                    position = end;
+                }
+            }
+
+            if feed {
+                write!(result, " F{}", number(self.feed_rate, p)).unwrap();
+                feed = false;
+            }
+            result.push('\n');
+        }
+
+        if tool_down {
+            writeln!(result, "{}", self.tool_up).unwrap();
+        }
+        result.push_str("M2\n");
+
+        result
+    }
+
+    /// to_hpgl($self)
+    /// --
+    ///
+    /// to_hpgl(self: euklid_rs.io.PlotterWriter) -> str
+    /// write all paths as hpgl (plotter units of 0.025 mm, arcs as AA)
+    pub fn to_hpgl(&self) -> String {
+        let scale = self.hpgl_scale();
+        let point = |v: Vector2D| {
+            format!(
+                "{},{}",
+                (v.v[0] * scale).round() as i64,
+                (v.v[1] * scale).round() as i64
+            )
+        };
+
+        // velocity in cm/s
+        let velocity = self.feed_rate * scale / 400. / 60.;
+
+        let mut result = String::new();
+        let mut tool_down = false;
+
+        writeln!(
+            result,
+            "IN;\nSP{};\nVS{};",
+            self.pen,
+            number(velocity, self.precision)
+        )
+        .unwrap();
+
+        for movement in self.moves() {
+            match movement {
+                Move::Travel(target) => {
+                    writeln!(result, "PU{};", point(target)).unwrap();
+                    tool_down = false;
+                }
+                Move::Line(target) => {
+                    writeln!(result, "PD{};", point(target)).unwrap();
+                    tool_down = true;
+                }
+                Move::Arc(arc) => {
+                    if !tool_down {
+                        writeln!(result, "PD;").unwrap();
+                        tool_down = true;
+                    }
+                    writeln!(
+                        result,
+                        "AA{},{};",
+                        point(arc.center),
+                        number(arc.sweep() * 180. / PI, self.precision)
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        result.push_str("PU;\nSP0;\n");
+
+        result
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.paths.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PlotterWriter({} paths, {})", self.paths.len(), self.units)
+    }
+}
+
+// json data: the settings and {"paths": [[element, ...], ...]}
+impl JsonData for PlotterWriter {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("units", self.units.json_data(py)),
+            ("feed_rate", self.feed_rate.json_data(py)),
+            ("tool_up", self.tool_up.json_data(py)),
+            ("tool_down", self.tool_down.json_data(py)),
+            ("lead_in", self.lead_in.json_data(py)),
+            ("lead_out", self.lead_out.json_data(py)),
+            ("optimize", self.optimize.json_data(py)),
+            ("precision", self.precision.json_data(py)),
+            ("pen", self.pen.json_data(py)),
+            ("paths", self.paths.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        let mut writer = Self::new(
+            data.get_item("units")?.extract()?,
+            data.get_item("feed_rate")?.extract()?,
+            data.get_item("tool_up")?.extract()?,
+            data.get_item("tool_down")?.extract()?,
+            data.get_item("lead_in")?.extract()?,
+            data.get_item("lead_out")?.extract()?,
+            data.get_item("optimize")?.extract()?,
+            data.get_item("precision")?.extract()?,
+            data.get_item("pen")?.extract()?,
+        )?;
+        writer.paths = JsonData::from_json_data(data.get_item("paths")?)?;
+
+        Ok(writer)
+    }
+}
+
+pickle_methods!(PlotterWriter);
@@ -0,0 +1,1292 @@
+use nalgebra as na;
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+use crate::arc::{check_tolerance, Arc2D, Circle2D, Path2D, PathElement};
+use crate::io::json::{pickle_methods, tagged, unknown_variant, variant, JsonData};
+use crate::io::plotter::number;
+use crate::polyline::PolyLine2D;
+use crate::spline::{BSplineCurve3, BezierCurve};
+use crate::vector::_vector::*;
+use crate::vector::Transformation2D;
+
+const SVG_TOLERANCE: f64 = 1e-9;
+const MAX_DEPTH: usize = 16;
+
+#[derive(FromPyObject)]
+pub enum ShapeArgs<'a> {
+    PolyLine(PolyLine2D),
+    Path(Path2D),
+    Arc(Arc2D),
+    Circle(Circle2D),
+    BezierCurve(PyRefMut<'a, BezierCurve>),
+    BSplineCurve3(PyRefMut<'a, BSplineCurve3>),
+}
+
+#[derive(Clone, Copy)]
+enum Command {
+    Move(Vector2D),
+    Line(Vector2D),
+    Quadratic(Vector2D, Vector2D),
+    Cubic(Vector2D, Vector2D, Vector2D),
+    Arc(Arc2D),
+    Close,
+}
+
+#[derive(Clone)]
+struct Shape {
+    commands: Vec<Command>,
+    // written as <polyline> / <polygon> instead of <path>
+    polyline: bool,
+}
+
+#[derive(Clone)]
+struct Layer {
+    name: String,
+    stroke: String,
+    stroke_width: f64,
+    fill: String,
+    dash: Option<Vec<f64>>,
+    shapes: Vec<Shape>,
+}
+
+impl Layer {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            stroke: "black".to_string(),
+            stroke_width: 1.,
+            fill: "none".to_string(),
+            dash: None,
+            shapes: Vec::new(),
+        }
+    }
+}
+
+fn segment_distance(point: &Vector2D, p1: &Vector2D, p2: &Vector2D) -> f64 {
+    let direction = *p2 - *p1;
+    let length = direction.dot(&direction);
+
+    if length < SVG_TOLERANCE {
+        return (*point - *p1).length();
+    }
+
+    let t = ((*point - *p1).dot(&direction) / length).clamp(0., 1.);
+    (*point - (*p1 + direction * t)).length()
+}
+
+fn subdivide(
+    f: &mut dyn FnMut(f64) -> Vector2D,
+    (t0, p0): (f64, Vector2D),
+    (t1, p1): (f64, Vector2D),
+    tolerance: f64,
+    depth: usize,
+    nodes: &mut Vec<Vector2D>,
+) {
+    let t = (t0 + t1) / 2.;
+    let p = f(t);
+
+    if depth < MAX_DEPTH && (depth < 3 || segment_distance(&p, &p0, &p1) > tolerance) {
+        subdivide(f, (t0, p0), (t, p), tolerance, depth + 1, nodes);
+        subdivide(f, (t, p), (t1, p1), tolerance, depth + 1, nodes);
+    } else {
+        nodes.push(p1);
+    }
+}
+
+/// sample f on (0, 1] until the chord deviation is below tolerance
+fn flatten(f: &mut dyn FnMut(f64) -> Vector2D, tolerance: f64, nodes: &mut Vec<Vector2D>) {
+    let start = f(0.);
+    let end = f(1.);
+    subdivide(f, (0., start), (1., end), tolerance, 0, nodes);
+}
+
+fn quadratic(p0: Vector2D, p1: Vector2D, p2: Vector2D, t: f64) -> Vector2D {
+    let s = 1. - t;
+    p0 * (s * s) + p1 * (2. * s * t) + p2 * (t * t)
+}
+
+fn cubic(p0: Vector2D, p1: Vector2D, p2: Vector2D, p3: Vector2D, t: f64) -> Vector2D {
+    let s = 1. - t;
+    p0 * (s * s * s) + p1 * (3. * s * s * t) + p2 * (3. * s * t * t) + p3 * (t * t * t)
+}
+
+/// split a clamped cubic b-spline into bezier segments (knot insertion)
+fn bspline_segments(knots: &[f64], controlpoints: &[Vector2D]) -> Vec<[Vector2D; 4]> {
+    let mut knots = knots.to_vec();
+    let mut points = controlpoints.to_vec();
+    let inner: Vec<f64> = knots[4..knots.len() - 4].to_vec();
+
+    for u in inner {
+        let multiplicity = knots
+            .iter()
+            .filter(|k| (**k - u).abs() < SVG_TOLERANCE)
+            .count();
+
+        for _ in multiplicity..3 {
+            let k = knots.iter().rposition(|k| *k <= u).unwrap();
+            let mut inserted = Vec::with_capacity(points.len() + 1);
+
+            for i in 0..=points.len() {
+                inserted.push(if i + 3 <= k {
+                    points[i]
+                } else if i > k {
+                    points[i - 1]
+                } else {
+                    let a = (u - knots[i]) / (knots[i + 3] - knots[i]);
+                    points[i - 1] * (1. - a) + points[i] * a
+                });
+            }
+
+            knots.insert(k + 1, u);
+            points = inserted;
+        }
+    }
+
+    points
+        .windows(4)
+        .step_by(3)
+        .map(|p| [p[0], p[1], p[2], p[3]])
+        .collect()
+}
+
+/// split arcs into parts of at most 180 degrees, avoiding the large-arc flag
+fn arc_commands(arc: &Arc2D) -> Vec<Command> {
+    let parts = (arc.sweep().abs() / PI - SVG_TOLERANCE).ceil().max(1.) as usize;
+    let step = arc.sweep() / parts as f64;
+
+    (0..parts)
+        .map(|i| {
+            let start_angle = arc.start_angle + step * i as f64;
+            Command::Arc(Arc2D {
+                start_angle,
+                end_angle: start_angle + step,
+                ..*arc
+            })
+        })
+        .collect()
+}
+
+struct PathParser<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PathParser<'a> {
+    fn skip_separators(&mut self) {
+        while self.position < self.data.len()
+            && (self.data[self.position].is_ascii_whitespace() || self.data[self.position] == b',')
+        {
+            self.position += 1;
+        }
+    }
+
+    fn command(&mut self) -> Option<u8> {
+        self.skip_separators();
+        let byte = *self.data.get(self.position)?;
+
+        if byte.is_ascii_alphabetic() && byte != b'e' && byte != b'E' {
+            self.position += 1;
+            Some(byte)
+        } else {
+            None
+        }
+    }
+
+    fn has_number(&mut self) -> bool {
+        self.skip_separators();
+        matches!(
+            self.data.get(self.position),
+            Some(b'0'..=b'9' | b'-' | b'+' | b'.')
+        )
+    }
+
+    fn number(&mut self) -> PyResult<f64> {
+        self.skip_separators();
+        let start = self.position;
+        let mut dot = false;
+        let mut exponent = false;
+
+        while let Some(byte) = self.data.get(self.position) {
+            match byte {
+                b'+' | b'-'
+                    if self.position == start
+                        || matches!(self.data[self.position - 1], b'e' | b'E') => {}
+                b'0'..=b'9' => {}
+                b'.' if !dot && !exponent => dot = true,
+                b'e' | b'E' if !exponent && self.position > start => exponent = true,
+                _ => break,
+            }
+            self.position += 1;
+        }
+
+        std::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "invalid number in path data at {}",
+                    start
+                ))
+            })
+    }
+
+    fn flag(&mut self) -> PyResult<bool> {
+        self.skip_separators();
+
+        match self.data.get(self.position) {
+            Some(b'0') => {
+                self.position += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.position += 1;
+                Ok(true)
+            }
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "invalid flag in path data at {}",
+                self.position
+            ))),
+        }
+    }
+
+    fn point(&mut self, relative: bool, current: Vector2D) -> PyResult<Vector2D> {
+        let point = Vector2D::__new__([self.number()?, self.number()?]);
+        Ok(if relative { current + point } else { point })
+    }
+}
+
+/// flatten an svg elliptical arc (endpoint parameterization)
+#[allow(clippy::too_many_arguments)]
+fn flatten_arc(
+    p1: Vector2D,
+    p2: Vector2D,
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    tolerance: f64,
+    nodes: &mut Vec<Vector2D>,
+) {
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+
+    if rx < SVG_TOLERANCE || ry < SVG_TOLERANCE || (p2 - p1).length() < SVG_TOLERANCE {
+        nodes.push(p2);
+        return;
+    }
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let half = (p1 - p2) / 2.;
+    let x1 = cos * half.v[0] + sin * half.v[1];
+    let y1 = -sin * half.v[0] + cos * half.v[1];
+
+    let scale = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if scale > 1. {
+        rx *= scale.sqrt();
+        ry *= scale.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut coefficient = (numerator / denominator).max(0.).sqrt();
+    if large_arc == sweep {
+        coefficient = -coefficient;
+    }
+
+    let cx1 = coefficient * rx * y1 / ry;
+    let cy1 = -coefficient * ry * x1 / rx;
+    let middle = (p1 + p2) / 2.;
+    let cx = cos * cx1 - sin * cy1 + middle.v[0];
+    let cy = sin * cx1 + cos * cy1 + middle.v[1];
+
+    let angle = |ux: f64, uy: f64, vx: f64, vy: f64| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+    let theta = angle(1., 0., (x1 - cx1) / rx, (y1 - cy1) / ry);
+    let mut delta = angle(
+        (x1 - cx1) / rx,
+        (y1 - cy1) / ry,
+        (-x1 - cx1) / rx,
+        (-y1 - cy1) / ry,
+    );
+
+    if !sweep && delta > 0. {
+        delta -= 2. * PI;
+    } else if sweep && delta < 0. {
+        delta += 2. * PI;
+    }
+
+    let mut f = |t: f64| {
+        if t >= 1. {
+            return p2;
+        }
+        let (s, c) = (theta + delta * t).sin_cos();
+        Vector2D::__new__([
+            cx + rx * cos * c - ry * sin * s,
+            cy + rx * sin * c + ry * cos * s,
+        ])
+    };
+    flatten(&mut f, tolerance, nodes);
+}
+
+/// parse svg path data into polylines, one per subpath
+fn parse_path_data(data: &str, tolerance: f64) -> PyResult<Vec<Vec<Vector2D>>> {
+    let mut parser = PathParser {
+        data: data.as_bytes(),
+        position: 0,
+    };
+    let mut lines: Vec<Vec<Vector2D>> = Vec::new();
+    let mut current = Vector2D::__new__([0., 0.]);
+    let mut start = current;
+    // reflected control point for S / T
+    let mut last_control: Option<(u8, Vector2D)> = None;
+    let mut command = None;
+
+    loop {
+        let next = match parser.command() {
+            Some(next) => next,
+            None => match command {
+                // implicit repetition, M continues as L
+                Some(b'M') if parser.has_number() => b'L',
+                Some(b'm') if parser.has_number() => b'l',
+                Some(c) if c != b'Z' && c != b'z' && parser.has_number() => c,
+                _ => break,
+            },
+        };
+        command = Some(next);
+
+        let relative = next.is_ascii_lowercase();
+        let mut control = None;
+
+        match next.to_ascii_uppercase() {
+            b'M' => {
+                current = parser.point(relative, current)?;
+                start = current;
+                lines.push(vec![current]);
+            }
+            b'Z' => {
+                if let Some(line) = lines.last_mut() {
+                    if (line[line.len() - 1] - start).length() > SVG_TOLERANCE {
+                        line.push(start);
+                    }
+                }
+                current = start;
+                // following commands start a new subpath
+                lines.push(vec![current]);
+            }
+            other => {
+                if lines.is_empty() {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "path data has to start with a moveto",
+                    ));
+                }
+                let line = lines.last_mut().unwrap();
+
+                match other {
+                    b'L' => current = parser.point(relative, current)?,
+                    b'H' => {
+                        let x = parser.number()?;
+                        current.v[0] = if relative { current.v[0] + x } else { x };
+                    }
+                    b'V' => {
+                        let y = parser.number()?;
+                        current.v[1] = if relative { current.v[1] + y } else { y };
+                    }
+                    b'C' | b'S' => {
+                        let c1 = if other == b'C' {
+                            parser.point(relative, current)?
+                        } else {
+                            match last_control {
+                                Some((b'C', c)) => current * 2. - c,
+                                _ => current,
+                            }
+                        };
+                        let c2 = parser.point(relative, current)?;
+                        let end = parser.point(relative, current)?;
+                        let p0 = current;
+                        flatten(&mut |t| cubic(p0, c1, c2, end, t), tolerance, line);
+                        line.pop();
+                        control = Some((b'C', c2));
+                        current = end;
+                    }
+                    b'Q' | b'T' => {
+                        let c = if other == b'Q' {
+                            parser.point(relative, current)?
+                        } else {
+                            match last_control {
+                                Some((b'Q', c)) => current * 2. - c,
+                                _ => current,
+                            }
+                        };
+                        let end = parser.point(relative, current)?;
+                        let p0 = current;
+                        flatten(&mut |t| quadratic(p0, c, end, t), tolerance, line);
+                        line.pop();
+                        control = Some((b'Q', c));
+                        current = end;
+                    }
+                    b'A' => {
+                        let rx = parser.number()?;
+                        let ry = parser.number()?;
+                        let rotation = parser.number()?;
+                        let large_arc = parser.flag()?;
+                        let sweep = parser.flag()?;
+                        let end = parser.point(relative, current)?;
+                        flatten_arc(
+                            current, end, rx, ry, rotation, large_arc, sweep, tolerance, line,
+                        );
+                        line.pop();
+                        current = end;
+                    }
+                    _ => {
+                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                            "unsupported path command: {}",
+                            other as char
+                        )))
+                    }
+                }
+
+                line.push(current);
+            }
+        }
+
+        last_control = control;
+    }
+
+    lines.retain(|line| line.len() > 1);
+    Ok(lines)
+}
+
+fn parse_points(data: &str) -> PyResult<Vec<Vector2D>> {
+    let mut parser = PathParser {
+        data: data.as_bytes(),
+        position: 0,
+    };
+    let mut nodes = Vec::new();
+
+    while parser.has_number() {
+        nodes.push(Vector2D::__new__([parser.number()?, parser.number()?]));
+    }
+
+    Ok(nodes)
+}
+
+/// parse a transform attribute (matrix, translate, scale, rotate, skewX, skewY)
+fn parse_transform(data: &str) -> PyResult<Transformation2D> {
+    let invalid =
+        || pyo3::exceptions::PyValueError::new_err(format!("invalid transform: {}", data));
+    let mut result = Transformation2D::identity();
+    let mut rest = data;
+
+    while let Some(open) = rest.find('(') {
+        let name = rest[..open].trim_matches(|c: char| c.is_whitespace() || c == ',');
+        let close = rest[open..].find(')').ok_or_else(invalid)? + open;
+        let mut parser = PathParser {
+            data: &rest.as_bytes()[open + 1..close],
+            position: 0,
+        };
+        let mut values = Vec::new();
+        while parser.has_number() {
+            values.push(parser.number()?);
+        }
+        rest = &rest[close + 1..];
+
+        #[rustfmt::skip]
+        let matrix = match (name, values.as_slice()) {
+            ("matrix", &[a, b, c, d, e, f]) => na::Matrix3::new(
+                a, c, e,
+                b, d, f,
+                0., 0., 1.,
+            ),
+            ("translate", &[x]) => na::Matrix3::new_translation(&na::Vector2::new(x, 0.)),
+            ("translate", &[x, y]) => na::Matrix3::new_translation(&na::Vector2::new(x, y)),
+            ("scale", &[x]) => na::Matrix3::new_nonuniform_scaling(&na::Vector2::new(x, x)),
+            ("scale", &[x, y]) => na::Matrix3::new_nonuniform_scaling(&na::Vector2::new(x, y)),
+            ("rotate", &[angle]) => na::Matrix3::new_rotation(angle.to_radians()),
+            ("rotate", &[angle, x, y]) => {
+                let origin = na::Vector2::new(x, y);
+                na::Matrix3::new_translation(&origin)
+                    * na::Matrix3::new_rotation(angle.to_radians())
+                    * na::Matrix3::new_translation(&-origin)
+            }
+            ("skewX", &[angle]) => na::Matrix3::new(
+                1., angle.to_radians().tan(), 0.,
+                0., 1., 0.,
+                0., 0., 1.,
+            ),
+            ("skewY", &[angle]) => na::Matrix3::new(
+                1., 0., 0.,
+                angle.to_radians().tan(), 1., 0.,
+                0., 0., 1.,
+            ),
+            _ => return Err(invalid()),
+        };
+
+        result = result.chain(&Transformation2D::new(matrix));
+    }
+
+    if !rest.trim().is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(result)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// attributes of an xml start tag
+fn attributes(tag: &str) -> HashMap<String, String> {
+    let mut result = HashMap::new();
+    let mut rest = tag;
+
+    while let Some(equal) = rest.find('=') {
+        let name = rest[..equal]
+            .split_whitespace()
+            .last()
+            .unwrap_or("")
+            .to_string();
+        let value = rest[equal + 1..].trim_start();
+
+        let quote = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => quote,
+            _ => break,
+        };
+        let end = match value[1..].find(quote) {
+            Some(end) => end + 1,
+            None => break,
+        };
+
+        result.insert(name, unescape(&value[1..end]));
+        rest = &value[end + 1..];
+    }
+
+    result
+}
+
+/// SVG drawing with named layers (groups) of 2D geometry.
+/// With flip_y the y-axis points up as in the geometry, svg coordinates are (x, -y).
+#[pyclass(module = "euklid_rs.io")]
+#[derive(Clone)]
+pub struct SVGDocument {
+    #[pyo3(get, set)]
+    pub flip_y: bool,
+
+    /// number of decimals in the output
+    #[pyo3(get, set)]
+    pub precision: usize,
+
+    /// chord tolerance for curves without a native svg representation
+    pub tolerance: f64,
+
+    layers: Vec<Layer>,
+}
+
+impl SVGDocument {
+    fn layer(&mut self, name: &str) -> &mut Layer {
+        let index = match self.layers.iter().position(|layer| layer.name == name) {
+            Some(index) => index,
+            None => {
+                self.layers.push(Layer::new(name));
+                self.layers.len() - 1
+            }
+        };
+
+        &mut self.layers[index]
+    }
+
+    fn to_svg_point(&self, point: &Vector2D) -> Vector2D {
+        if self.flip_y {
+            Vector2D::__new__([point.v[0], -point.v[1]])
+        } else {
+            *point
+        }
+    }
+
+    fn format_point(&self, point: &Vector2D) -> String {
+        let point = self.to_svg_point(point);
+        format!(
+            "{} {}",
+            number(point.v[0], self.precision),
+            number(point.v[1], self.precision)
+        )
+    }
+
+    fn path_data(&self, commands: &[Command]) -> String {
+        let mut parts = Vec::new();
+
+        for command in commands {
+            parts.push(match command {
+                Command::Move(p) => format!("M {}", self.format_point(p)),
+                Command::Line(p) => format!("L {}", self.format_point(p)),
+                Command::Quadratic(c, p) => {
+                    format!("Q {} {}", self.format_point(c), self.format_point(p))
+                }
+                Command::Cubic(c1, c2, p) => format!(
+                    "C {} {} {}",
+                    self.format_point(c1),
+                    self.format_point(c2),
+                    self.format_point(p)
+                ),
+                Command::Arc(arc) => {
+                    // the flip mirrors the direction of rotation
+                    let sweep = (arc.sweep() > 0.) != self.flip_y;
+                    format!(
+                        "A {r} {r} 0 0 {} {}",
+                        sweep as u8,
+                        self.format_point(&arc.end()),
+                        r = number(arc.radius, self.precision)
+                    )
+                }
+                Command::Close => "Z".to_string(),
+            });
+        }
+
+        parts.join(" ")
+    }
+
+    /// all points relevant for the extent of a shape (control points included)
+    fn extent_points(&self, commands: &[Command]) -> Vec<Vector2D> {
+        let mut points = Vec::new();
+
+        for command in commands {
+            match command {
+                Command::Move(p) | Command::Line(p) => points.push(*p),
+                Command::Quadratic(c, p) => points.extend([*c, *p]),
+                Command::Cubic(c1, c2, p) => points.extend([*c1, *c2, *p]),
+                Command::Arc(arc) => points.extend(arc.discretize(self.tolerance).nodes),
+                Command::Close => {}
+            }
+        }
+
+        points.iter().map(|p| self.to_svg_point(p)).collect()
+    }
+
+    fn flatten_shape(&self, shape: &Shape) -> Vec<PolyLine2D> {
+        let mut lines: Vec<Vec<Vector2D>> = Vec::new();
+        let mut start = Vector2D::__new__([0., 0.]);
+
+        for command in &shape.commands {
+            if let Command::Move(p) = command {
+                start = *p;
+                lines.push(vec![*p]);
+                continue;
+            }
+
+            let line = match lines.last_mut() {
+                Some(line) => line,
+                None => continue,
+            };
+            let current = line[line.len() - 1];
+
+            match *command {
+                Command::Line(p) => line.push(p),
+                Command::Quadratic(c, p) => {
+                    flatten(&mut |t| quadratic(current, c, p, t), self.tolerance, line)
+                }
+                Command::Cubic(c1, c2, p) => {
+                    flatten(&mut |t| cubic(current, c1, c2, p, t), self.tolerance, line)
+                }
+                Command::Arc(arc) => {
+                    line.extend(arc.discretize(self.tolerance).nodes.into_iter().skip(1))
+                }
+                Command::Close => {
+                    if (current - start).length() > SVG_TOLERANCE {
+                        line.push(start);
+                    }
+                }
+                Command::Move(_) => {}
+            }
+        }
+
+        lines
+            .into_iter()
+            .filter(|nodes| nodes.len() > 1)
+            .map(|nodes| PolyLine2D { nodes })
+            .collect()
+    }
+}
+
+#[pymethods]
+impl SVGDocument {
+    #[new]
+    #[pyo3(signature = (flip_y=true, precision=3, tolerance=1e-3))]
+    pub fn new(flip_y: bool, precision: usize, tolerance: f64) -> PyResult<Self> {
+        check_tolerance(tolerance)?;
+
+        Ok(Self {
+            flip_y,
+            precision,
+            tolerance,
+            layers: Vec::new(),
+        })
+    }
+
+    /// chord tolerance for curves without a native svg representation
+    #[getter]
+    pub fn get_tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    #[setter]
+    pub fn set_tolerance(&mut self, tolerance: f64) -> PyResult<()> {
+        check_tolerance(tolerance)?;
+        self.tolerance = tolerance;
+
+        Ok(())
+    }
+
+    /// from_svg(text, tolerance=1e-3, flip_y=True)
+    /// --
+    ///
+    /// from_svg(text: str, tolerance: float = 1e-3, flip_y: bool = True) -> euklid_rs.io.SVGDocument
+    /// read path, polyline, polygon and line elements of an svg document as polylines,
+    /// curves are flattened with tolerance. layers are taken from the id of enclosing groups,
+    /// transform attributes of groups and elements are applied. coordinates are read in user
+    /// units, the viewBox and the width/height of the document are not applied
+    #[staticmethod]
+    #[pyo3(signature = (text, tolerance=1e-3, flip_y=true))]
+    pub fn from_svg(text: &str, tolerance: f64, flip_y: bool) -> PyResult<Self> {
+        let mut document = Self::new(flip_y, 3, tolerance)?;
+        // (layer name, accumulated transformation) of the enclosing groups
+        let mut groups: Vec<(Option<String>, Transformation2D)> = Vec::new();
+        let mut rest = text;
+
+        while let Some(open) = rest.find('<') {
+            let close = match rest[open..].find('>') {
+                Some(close) => open + close,
+                None => break,
+            };
+            let tag = &rest[open + 1..close];
+            rest = &rest[close + 1..];
+
+            if let Some(name) = tag.strip_prefix('/') {
+                if name.trim() == "g" {
+                    groups.pop();
+                }
+                continue;
+            }
+            if tag.starts_with('?') || tag.starts_with('!') {
+                continue;
+            }
+
+            let self_closing = tag.ends_with('/');
+            let tag = tag.trim_end_matches('/');
+            let name = tag.split_whitespace().next().unwrap_or("");
+            let attributes = attributes(&tag[name.len()..]);
+
+            let mut transformation = match groups.last() {
+                Some((_, transformation)) => *transformation,
+                None => Transformation2D::identity(),
+            };
+            if let Some(data) = attributes.get("transform") {
+                transformation = transformation.chain(&parse_transform(data)?);
+            }
+
+            if name == "g" {
+                let id = attributes
+                    .get("inkscape:label")
+                    .or_else(|| attributes.get("id"))
+                    .cloned();
+                if !self_closing {
+                    groups.push((id, transformation));
+                }
+                continue;
+            }
+
+            let lines = match name {
+                "path" => match attributes.get("d") {
+                    Some(data) => parse_path_data(data, tolerance)?,
+                    None => continue,
+                },
+                "polyline" | "polygon" => {
+                    let mut nodes = parse_points(attributes.get("points").map_or("", |p| p))?;
+                    if name == "polygon" && !nodes.is_empty() {
+                        nodes.push(nodes[0]);
+                    }
+                    vec![nodes]
+                }
+                "line" => {
+                    let value = |key: &str| {
+                        attributes
+                            .get(key)
+                            .and_then(|value| value.trim().parse::<f64>().ok())
+                            .unwrap_or(0.)
+                    };
+                    vec![vec![
+                        Vector2D::__new__([value("x1"), value("y1")]),
+                        Vector2D::__new__([value("x2"), value("y2")]),
+                    ]]
+                }
+                _ => continue,
+            };
+
+            let layer_name = groups
+                .iter()
+                .rev()
+                .find_map(|(id, _)| id.clone())
+                .unwrap_or_else(|| "default".to_string());
+
+            for nodes in lines {
+                if nodes.len() < 2 {
+                    continue;
+                }
+                let commands = nodes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, node)| {
+                        // flipping back to geometry coordinates is the same mirroring
+                        let node = document.to_svg_point(&transformation.apply_vec(node));
+                        if i == 0 {
+                            Command::Move(node)
+                        } else {
+                            Command::Line(node)
+                        }
+                    })
+                    .collect();
+
+                document.layer(&layer_name).shapes.push(Shape {
+                    commands,
+                    polyline: true,
+                });
+            }
+        }
+
+        Ok(document)
+    }
+
+    /// parse_path(data, tolerance=1e-3, flip_y=True)
+    /// --
+    ///
+    /// parse_path(data: str, tolerance: float = 1e-3, flip_y: bool = True) -> List[euklid_rs.polyline.PolyLine2D]
+    /// parse svg path data (M, L, H, V, C, S, Q, T, A, Z) into one polyline per subpath,
+    /// curves are flattened with tolerance
+    #[staticmethod]
+    #[pyo3(signature = (data, tolerance=1e-3, flip_y=true))]
+    pub fn parse_path(data: &str, tolerance: f64, flip_y: bool) -> PyResult<Vec<PolyLine2D>> {
+        check_tolerance(tolerance)?;
+
+        Ok(parse_path_data(data, tolerance)?
+            .into_iter()
+            .map(|nodes| {
+                let nodes = nodes
+                    .into_iter()
+                    .map(|node| {
+                        if flip_y {
+                            Vector2D::__new__([node.v[0], -node.v[1]])
+                        } else {
+                            node
+                        }
+                    })
+                    .collect();
+                PolyLine2D { nodes }
+            })
+            .collect())
+    }
+
+    /// add_layer($self, name, stroke="black", stroke_width=1., fill="none", dash=None)
+    /// --
+    ///
+    /// add_layer(self: euklid_rs.io.SVGDocument, name: str, stroke: str = "black", stroke_width: float = 1., fill: str = "none", dash: Optional[List[float]] = None) -> None
+    /// create a layer or change the style of an existing one
+    #[pyo3(signature = (name, stroke="black", stroke_width=1., fill="none", dash=None))]
+    pub fn add_layer(
+        &mut self,
+        name: &str,
+        stroke: &str,
+        stroke_width: f64,
+        fill: &str,
+        dash: Option<Vec<f64>>,
+    ) {
+        let layer = self.layer(name);
+        layer.stroke = stroke.to_string();
+        layer.stroke_width = stroke_width;
+        layer.fill = fill.to_string();
+        layer.dash = dash;
+    }
+
+    #[getter]
+    pub fn layers(&self) -> Vec<String> {
+        self.layers.iter().map(|layer| layer.name.clone()).collect()
+    }
+
+    /// add($self, shape, layer="default", closed=False)
+    /// --
+    ///
+    /// add(self: euklid_rs.io.SVGDocument, shape: Union[euklid_rs.polyline.PolyLine2D, euklid_rs.arc.Path2D, euklid_rs.arc.Arc2D, euklid_rs.arc.Circle2D, euklid_rs.spline.BezierCurve, euklid_rs.spline.BSplineCurve3], layer: str = "default", closed: bool = False) -> None
+    /// add a shape to a layer. polylines are written as polyline (polygon if closed),
+    /// bezier curves up to degree 3 and cubic b-splines as native bezier paths, arcs as svg arcs
+    #[pyo3(signature = (shape, layer="default", closed=false))]
+    pub fn add(&mut self, shape: ShapeArgs, layer: &str, closed: bool) -> PyResult<()> {
+        let mut polyline = false;
+
+        let mut commands = match shape {
+            ShapeArgs::PolyLine(line) => {
+                polyline = true;
+                line.nodes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, node)| {
+                        if i == 0 {
+                            Command::Move(*node)
+                        } else {
+                            Command::Line(*node)
+                        }
+                    })
+                    .collect()
+            }
+            ShapeArgs::Path(path) => {
+                let mut commands = Vec::new();
+                let mut position: Option<Vector2D> = None;
+
+                for element in &path.elements {
+                    if position.is_none_or(|p| (p - element.start()).length() > SVG_TOLERANCE) {
+                        commands.push(Command::Move(element.start()));
+                    }
+                    match element {
+                        PathElement::Segment(segment) => commands.push(Command::Line(segment.p2)),
+                        PathElement::Arc(arc) => commands.extend(arc_commands(arc)),
+                    }
+                    position = Some(element.end());
+                }
+
+                commands
+            }
+            ShapeArgs::Arc(arc) => {
+                let mut commands = vec![Command::Move(arc.start())];
+                commands.extend(arc_commands(&arc));
+                commands
+            }
+            ShapeArgs::Circle(circle) => {
+                let arc = Arc2D::new(circle.center, circle.radius, 0., 2. * PI)?;
+                let mut commands = vec![Command::Move(arc.start())];
+                commands.extend(arc_commands(&arc));
+                commands.push(Command::Close);
+                commands
+            }
+            ShapeArgs::BezierCurve(mut curve) => {
+                let nodes = curve.controlpoints.nodes.clone();
+
+                match nodes.len() {
+                    0 | 1 => {
+                        return Err(pyo3::exceptions::PyValueError::new_err(
+                            "curve needs at least two controlpoints",
+                        ))
+                    }
+                    2 => vec![Command::Move(nodes[0]), Command::Line(nodes[1])],
+                    3 => vec![
+                        Command::Move(nodes[0]),
+                        Command::Quadratic(nodes[1], nodes[2]),
+                    ],
+                    4 => vec![
+                        Command::Move(nodes[0]),
+                        Command::Cubic(nodes[1], nodes[2], nodes[3]),
+                    ],
+                    _ => {
+                        let mut line = vec![curve.get(0.)];
+                        flatten(&mut |t| curve.get(t), self.tolerance, &mut line);
+                        line.iter()
+                            .enumerate()
+                            .map(|(i, node)| {
+                                if i == 0 {
+                                    Command::Move(*node)
+                                } else {
+                                    Command::Line(*node)
+                                }
+                            })
+                            .collect()
+                    }
+                }
+            }
+            ShapeArgs::BSplineCurve3(curve) => {
+                let nodes = &curve.controlpoints.nodes;
+
+                if nodes.len() < 4 {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "cubic b-spline needs at least four controlpoints",
+                    ));
+                }
+
+                let segments = bspline_segments(curve.knots(), nodes);
+                let mut commands = vec![Command::Move(segments[0][0])];
+                commands.extend(segments.iter().map(|s| Command::Cubic(s[1], s[2], s[3])));
+                commands
+            }
+        };
+
+        if closed {
+            commands.push(Command::Close);
+        }
+
+        self.layer(layer).shapes.push(Shape { commands, polyline });
+        Ok(())
+    }
+
+    /// get_layer($self, name)
+    /// --
+    ///
+    /// get_layer(self: euklid_rs.io.SVGDocument, name: str) -> List[euklid_rs.polyline.PolyLine2D]
+    /// get the shapes of a layer as polylines, curves are flattened with the tolerance
+    pub fn get_layer(&self, name: &str) -> PyResult<Vec<PolyLine2D>> {
+        match self.layers.iter().find(|layer| layer.name == name) {
+            Some(layer) => Ok(layer
+                .shapes
+                .iter()
+                .flat_map(|shape| self.flatten_shape(shape))
+                .collect()),
+            None => Err(pyo3::exceptions::PyKeyError::new_err(name.to_string())),
+        }
+    }
+
+    /// to_svg($self, margin=0., unit=None)
+    /// --
+    ///
+    /// to_svg(self: euklid_rs.io.SVGDocument, margin: float = 0., unit: Optional[str] = None) -> str
+    /// write the document, the viewBox covers all shapes plus margin.
+    /// with a unit (e.g. "mm") width and height are set to the real size
+    #[pyo3(signature = (margin=0., unit=None))]
+    pub fn to_svg(&self, margin: f64, unit: Option<&str>) -> String {
+        let p = self.precision;
+        let mut min = Vector2D::__new__([f64::INFINITY, f64::INFINITY]);
+        let mut max = Vector2D::__new__([f64::NEG_INFINITY, f64::NEG_INFINITY]);
+
+        for layer in &self.layers {
+            for shape in &layer.shapes {
+                for point in self.extent_points(&shape.commands) {
+                    for i in 0..2 {
+                        min.v[i] = min.v[i].min(point.v[i]);
+                        max.v[i] = max.v[i].max(point.v[i]);
+                    }
+                }
+            }
+        }
+
+        if min.v[0] > max.v[0] {
+            min = Vector2D::__new__([0., 0.]);
+            max = min;
+        }
+
+        let size = max - min + Vector2D::__new__([2. * margin, 2. * margin]);
+        let mut result = String::new();
+
+        writeln!(result, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>").unwrap();
+        write!(result, "<svg xmlns=\"http://www.w3.org/2000/svg\"").unwrap();
+        if let Some(unit) = unit {
+            write!(
+                result,
+                " width=\"{}{unit}\" height=\"{}{unit}\"",
+                number(size.v[0], p),
+                number(size.v[1], p)
+            )
+            .unwrap();
+        }
+        writeln!(
+            result,
+            " viewBox=\"{} {} {} {}\">",
+            number(min.v[0] - margin, p),
+            number(min.v[1] - margin, p),
+            number(size.v[0], p),
+            number(size.v[1], p)
+        )
+        .unwrap();
+
+        for layer in &self.layers {
+            write!(
+                result,
+                "  <g id=\"{}\" stroke=\"{}\" stroke-width=\"{}\" fill=\"{}\"",
+                escape(&layer.name),
+                escape(&layer.stroke),
+                number(layer.stroke_width, p),
+                escape(&layer.fill)
+            )
+            .unwrap();
+            if let Some(dash) = &layer.dash {
+                let dash: Vec<String> = dash.iter().map(|d| number(*d, p)).collect();
+                write!(result, " stroke-dasharray=\"{}\"", dash.join(" ")).unwrap();
+            }
+            writeln!(result, ">").unwrap();
+
+            for shape in &layer.shapes {
+                let closed = matches!(shape.commands.last(), Some(Command::Close));
+
+                if shape.polyline {
+                    let points: Vec<String> = shape
+                        .commands
+                        .iter()
+                        .filter_map(|command| match command {
+                            Command::Move(p) | Command::Line(p) => {
+                                let point = self.to_svg_point(p);
+                                Some(format!(
+                                    "{},{}",
+                                    number(point.v[0], self.precision),
+                                    number(point.v[1], self.precision)
+                                ))
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    let mut points: Vec<String> = points;
+                    // polygons are closed implicitly
+                    if closed && points.len() > 1 && points[0] == points[points.len() - 1] {
+                        points.pop();
+                    }
+
+                    writeln!(
+                        result,
+                        "    <{} points=\"{}\"/>",
+                        if closed { "polygon" } else { "polyline" },
+                        points.join(" ")
+                    )
+                    .unwrap();
+                } else {
+                    writeln!(
+                        result,
+                        "    <path d=\"{}\"/>",
+                        self.path_data(&shape.commands)
+                    )
+                    .unwrap();
+                }
+            }
+
+            writeln!(result, "  </g>").unwrap();
+        }
+
+        result.push_str("</svg>\n");
+        result
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SVGDocument({} layers)", self.layers.len())
+    }
+}
+
+fn invalid_points(command: &str) -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(format!("wrong number of points for {}", command))
+}
+
+// json data: {"Move": [x, y]}, {"Cubic": [[...], [...], [...]]}, {"Close": null}, ...
+impl JsonData for Command {
+    fn json_data(&self, py: Python) -> PyObject {
+        match self {
+            Command::Move(point) => variant(py, "Move", point.json_data(py)),
+            Command::Line(point) => variant(py, "Line", point.json_data(py)),
+            Command::Quadratic(control, end) => {
+                variant(py, "Quadratic", vec![*control, *end].json_data(py))
+            }
+            Command::Cubic(control_1, control_2, end) => variant(
+                py,
+                "Cubic",
+                vec![*control_1, *control_2, *end].json_data(py),
+            ),
+            Command::Arc(arc) => variant(py, "Arc", arc.json_data(py)),
+            Command::Close => variant(py, "Close", py.None()),
+        }
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        let (name, data) = tagged(data)?;
+
+        Ok(match name.as_str() {
+            "Move" => Command::Move(JsonData::from_json_data(data)?),
+            "Line" => Command::Line(JsonData::from_json_data(data)?),
+            "Quadratic" => match Vec::<Vector2D>::from_json_data(data)?[..] {
+                [control, end] => Command::Quadratic(control, end),
+                _ => return Err(invalid_points("Quadratic")),
+            },
+            "Cubic" => match Vec::<Vector2D>::from_json_data(data)?[..] {
+                [control_1, control_2, end] => Command::Cubic(control_1, control_2, end),
+                _ => return Err(invalid_points("Cubic")),
+            },
+            "Arc" => Command::Arc(JsonData::from_json_data(data)?),
+            "Close" => Command::Close,
+            _ => return Err(unknown_variant(&name)),
+        })
+    }
+}
+
+// json data: {"commands": [...], "polyline": bool}
+impl JsonData for Shape {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("commands", self.commands.json_data(py)),
+            ("polyline", self.polyline.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            commands: JsonData::from_json_data(data.get_item("commands")?)?,
+            polyline: data.get_item("polyline")?.extract()?,
+        })
+    }
+}
+
+// json data: the style and {"shapes": [...]}
+impl JsonData for Layer {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("name", self.name.json_data(py)),
+            ("stroke", self.stroke.json_data(py)),
+            ("stroke_width", self.stroke_width.json_data(py)),
+            ("fill", self.fill.json_data(py)),
+            ("dash", self.dash.json_data(py)),
+            ("shapes", self.shapes.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            name: data.get_item("name")?.extract()?,
+            stroke: data.get_item("stroke")?.extract()?,
+            stroke_width: data.get_item("stroke_width")?.extract()?,
+            fill: data.get_item("fill")?.extract()?,
+            dash: JsonData::from_json_data(data.get_item("dash")?)?,
+            shapes: JsonData::from_json_data(data.get_item("shapes")?)?,
+        })
+    }
+}
+
+// json data: the settings and {"layers": [...]}
+impl JsonData for SVGDocument {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("flip_y", self.flip_y.json_data(py)),
+            ("precision", self.precision.json_data(py)),
+            ("tolerance", self.tolerance.json_data(py)),
+            ("layers", self.layers.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        let mut document = Self::new(
+            data.get_item("flip_y")?.extract()?,
+            data.get_item("precision")?.extract()?,
+            data.get_item("tolerance")?.extract()?,
+        )?;
+        document.layers = JsonData::from_json_data(data.get_item("layers")?)?;
+
+        Ok(document)
+    }
+}
+
+pickle_methods!(SVGDocument);
@@ -1,5 +1,12 @@
 use pyo3::prelude::*;
 
+mod arc;
+mod boundary;
+mod io;
+mod line;
+mod mesh;
+mod nesting;
+mod parallel;
 mod plane;
 mod polyline;
 mod spline;
@@ -8,7 +15,13 @@ pub mod vector;
 /// A Python module implemented in Rust.
 #[pymodule]
 fn euklid_rs(_py: Python, m: &PyModule) -> PyResult<()> {
+    arc::register(_py, m)?;
+    boundary::register(_py, m)?;
+    io::register(_py, m)?;
+    line::register(_py, m)?;
     plane::register(_py, m)?;
+    mesh::register(_py, m)?;
+    nesting::register(_py, m)?;
     vector::register(_py, m)?;
     polyline::register(_py, m)?;
     spline::register(_py, m)?;
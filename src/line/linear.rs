@@ -0,0 +1,468 @@
+// parameter ranges: line (-inf, inf), ray [0, inf), segment [0, 1]
+macro_rules! define_linear {
+    ($dst: ident, $vecClass: ident, $intersection: ident, $t_min: expr, $t_max: expr) => {
+        #[pyclass(module = "euklid_rs.line")]
+        #[derive(Clone, Copy)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(try_from = "LinearData", into = "LinearData")
+        )]
+        pub struct $dst {
+            #[pyo3(get)]
+            pub p1: $vecClass,
+
+            #[pyo3(get)]
+            pub p2: $vecClass,
+        }
+
+        impl $dst {
+            pub fn try_new(p1: $vecClass, p2: $vecClass) -> Result<Self, &'static str> {
+                if (p2 - p1).length() < SMALL_N {
+                    return Err("p1 and p2 must not be equal");
+                }
+
+                Ok(Self { p1, p2 })
+            }
+
+            pub fn linear(&self) -> Linear {
+                Linear {
+                    origin: self.p1,
+                    direction: self.p2 - self.p1,
+                    range: ($t_min, $t_max),
+                }
+            }
+        }
+
+        // json data: {"p1": [...], "p2": [...]}
+        impl JsonData for $dst {
+            fn json_data(&self, py: Python) -> PyObject {
+                [("p1", self.p1.json_data(py)), ("p2", self.p2.json_data(py))]
+                    .into_py_dict(py)
+                    .into()
+            }
+
+            fn from_json_data(data: &PyAny) -> PyResult<Self> {
+                Self::__new__(
+                    JsonData::from_json_data(data.get_item("p1")?)?,
+                    JsonData::from_json_data(data.get_item("p2")?)?,
+                )
+            }
+        }
+
+        json_methods!($dst);
+
+        #[cfg(feature = "serde")]
+        impl From<$dst> for LinearData {
+            fn from(linear: $dst) -> Self {
+                Self {
+                    p1: linear.p1,
+                    p2: linear.p2,
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl TryFrom<LinearData> for $dst {
+            type Error = &'static str;
+
+            fn try_from(data: LinearData) -> Result<Self, Self::Error> {
+                Self::try_new(data.p1, data.p2)
+            }
+        }
+
+        #[pymethods]
+        impl $dst {
+            #[new]
+            pub fn __new__(p1: $vecClass, p2: $vecClass) -> PyResult<Self> {
+                Self::try_new(p1, p2).map_err(pyo3::exceptions::PyValueError::new_err)
+            }
+
+            /// unit direction from p1 to p2
+            #[getter]
+            pub fn direction(&self) -> $vecClass {
+                (self.p2 - self.p1).normalized()
+            }
+
+            /// get($self, t)
+            /// --
+            ///
+            #[doc = concat!("get(self: ", stringify!($dst), ", t: float) -> ", stringify!($vecClass))]
+            #[doc = "evaluate p1 + t * (p2 - p1)"]
+            pub fn get(&self, t: f64) -> $vecClass {
+                self.linear().get(t)
+            }
+
+            /// closest_parameter($self, point)
+            /// --
+            ///
+            #[doc = concat!("closest_parameter(self: ", stringify!($dst), ", point: ", stringify!($vecClass), ") -> float")]
+            #[doc = "get the parameter of the closest point"]
+            pub fn closest_parameter(&self, point: &$vecClass) -> f64 {
+                self.linear().closest_parameter(point)
+            }
+
+            /// closest_point($self, point)
+            /// --
+            ///
+            #[doc = concat!("closest_point(self: ", stringify!($dst), ", point: ", stringify!($vecClass), ") -> ", stringify!($vecClass))]
+            #[doc = "get the closest point"]
+            pub fn closest_point(&self, point: &$vecClass) -> $vecClass {
+                self.get(self.closest_parameter(point))
+            }
+
+            /// distance($self, point)
+            /// --
+            ///
+            #[doc = concat!("distance(self: ", stringify!($dst), ", point: ", stringify!($vecClass), ") -> float")]
+            #[doc = "get the distance to a point"]
+            pub fn distance(&self, point: &$vecClass) -> f64 {
+                (self.closest_point(point) - *point).length()
+            }
+
+            /// contains($self, point, tolerance=1e-8)
+            /// --
+            ///
+            #[doc = concat!("contains(self: ", stringify!($dst), ", point: ", stringify!($vecClass), ", tolerance: float = 1e-8) -> bool")]
+            #[doc = "check if a point lies on the object"]
+            #[pyo3(signature = (point, tolerance=SMALL_N))]
+            pub fn contains(&self, point: &$vecClass, tolerance: f64) -> bool {
+                self.distance(point) <= tolerance
+            }
+
+            /// closest_points($self, other)
+            /// --
+            ///
+            #[doc = concat!("closest_points(self: ", stringify!($dst), ", other: Union[Line, Ray, Segment]) -> Tuple[float, float, ", stringify!($vecClass), ", ", stringify!($vecClass), "]")]
+            #[doc = "get the parameters and points with the smallest distance between two objects"]
+            pub fn closest_points(&self, other: LinearArgs) -> (f64, f64, $vecClass, $vecClass) {
+                let (t1, t2) = self.linear().closest_parameters(&other.linear());
+
+                (t1, t2, self.get(t1), other.linear().get(t2))
+            }
+
+            /// distance_to($self, other)
+            /// --
+            ///
+            #[doc = concat!("distance_to(self: ", stringify!($dst), ", other: Union[Line, Ray, Segment]) -> float")]
+            #[doc = "get the smallest distance between two objects"]
+            pub fn distance_to(&self, other: LinearArgs) -> f64 {
+                let (_, _, p1, p2) = self.closest_points(other);
+
+                (p2 - p1).length()
+            }
+
+            /// intersect($self, other, tolerance=1e-8)
+            /// --
+            ///
+            #[doc = concat!("intersect(self: ", stringify!($dst), ", other: Union[Line, Ray, Segment], tolerance: float = 1e-8) -> ", stringify!($intersection))]
+            #[doc = "intersect two objects, the result tells why there is no single intersection point"]
+            #[pyo3(signature = (other, tolerance=SMALL_N))]
+            pub fn intersect(&self, other: LinearArgs, tolerance: f64) -> $intersection {
+                self.linear().intersect(&other.linear(), tolerance)
+            }
+
+            fn __repr__(&self) -> String {
+                format!(
+                    "{}({:?} -> {:?})",
+                    stringify!($dst),
+                    self.p1.tolist(),
+                    self.p2.tolist()
+                )
+            }
+        }
+    };
+}
+
+macro_rules! define_line {
+    ($line: ident, $ray: ident, $segment: ident, $intersection: ident, $vecClass: ident, $module: ident) => {
+        pub mod $module {
+            use crate::io::json::{json_methods, pickle_methods, JsonData};
+            use crate::vector::_vector::*;
+            use pyo3::prelude::*;
+            use pyo3::types::IntoPyDict;
+
+            const SMALL_N: f64 = 1e-8;
+
+            #[cfg(feature = "serde")]
+            #[derive(serde::Serialize, serde::Deserialize)]
+            struct LinearData {
+                p1: $vecClass,
+                p2: $vecClass,
+            }
+
+            #[derive(FromPyObject)]
+            pub enum LinearArgs {
+                Line($line),
+                Ray($ray),
+                Segment($segment),
+            }
+
+            impl LinearArgs {
+                pub fn linear(&self) -> Linear {
+                    match self {
+                        LinearArgs::Line(line) => line.linear(),
+                        LinearArgs::Ray(ray) => ray.linear(),
+                        LinearArgs::Segment(segment) => segment.linear(),
+                    }
+                }
+            }
+
+            /// Result of an intersection. kind is one of:
+            /// "point": single intersection (point, ik_1, ik_2 are set),
+            /// "overlap": collinear with a common section (overlap is the parameter range on the first object),
+            /// "collinear": collinear without a common section,
+            /// "parallel": parallel, not collinear,
+            /// "disjoint": not parallel, but no common point
+            #[pyclass(module = "euklid_rs.line")]
+            #[derive(Clone)]
+            pub struct $intersection {
+                #[pyo3(get)]
+                pub kind: String,
+
+                #[pyo3(get)]
+                pub point: Option<$vecClass>,
+
+                #[pyo3(get)]
+                pub ik_1: Option<f64>,
+
+                #[pyo3(get)]
+                pub ik_2: Option<f64>,
+
+                #[pyo3(get)]
+                pub overlap: Option<(f64, f64)>,
+            }
+
+            impl $intersection {
+                fn new(kind: &str) -> Self {
+                    Self {
+                        kind: kind.to_string(),
+                        point: None,
+                        ik_1: None,
+                        ik_2: None,
+                        overlap: None,
+                    }
+                }
+            }
+
+            // json data: {"kind": kind, "point": [...] | null, "ik_1": ik | null, "ik_2": ik | null, "overlap": [start, end] | null}
+            impl JsonData for $intersection {
+                fn json_data(&self, py: Python) -> PyObject {
+                    [
+                        ("kind", self.kind.json_data(py)),
+                        ("point", self.point.json_data(py)),
+                        ("ik_1", self.ik_1.json_data(py)),
+                        ("ik_2", self.ik_2.json_data(py)),
+                        ("overlap", self.overlap.json_data(py)),
+                    ]
+                    .into_py_dict(py)
+                    .into()
+                }
+
+                fn from_json_data(data: &PyAny) -> PyResult<Self> {
+                    Ok(Self {
+                        kind: data.get_item("kind")?.extract()?,
+                        point: JsonData::from_json_data(data.get_item("point")?)?,
+                        ik_1: JsonData::from_json_data(data.get_item("ik_1")?)?,
+                        ik_2: JsonData::from_json_data(data.get_item("ik_2")?)?,
+                        overlap: JsonData::from_json_data(data.get_item("overlap")?)?,
+                    })
+                }
+            }
+
+            pickle_methods!($intersection);
+
+            #[pymethods]
+            impl $intersection {
+                fn __bool__(&self) -> bool {
+                    self.kind == "point" || self.kind == "overlap"
+                }
+
+                fn __repr__(&self) -> String {
+                    match (self.point, self.overlap) {
+                        (Some(point), _) => format!(
+                            "{}({}: {:?})",
+                            stringify!($intersection),
+                            self.kind,
+                            point.tolist()
+                        ),
+                        (_, Some(overlap)) => format!(
+                            "{}({}: {:?})",
+                            stringify!($intersection),
+                            self.kind,
+                            overlap
+                        ),
+                        _ => format!("{}({})", stringify!($intersection), self.kind),
+                    }
+                }
+            }
+
+            /// parametric form shared by lines, rays and segments
+            pub struct Linear {
+                pub origin: $vecClass,
+                pub direction: $vecClass,
+                pub range: (f64, f64),
+            }
+
+            impl Linear {
+                pub fn get(&self, t: f64) -> $vecClass {
+                    self.origin + self.direction * t
+                }
+
+                fn clamp(&self, t: f64) -> f64 {
+                    t.clamp(self.range.0, self.range.1)
+                }
+
+                pub fn closest_parameter(&self, point: &$vecClass) -> f64 {
+                    let t = (*point - self.origin).dot(&self.direction)
+                        / self.direction.dot(&self.direction);
+
+                    self.clamp(t)
+                }
+
+                fn is_parallel(&self, other: &Linear) -> bool {
+                    let a = self.direction.dot(&self.direction);
+                    let b = self.direction.dot(&other.direction);
+                    let e = other.direction.dot(&other.direction);
+
+                    a * e - b * b <= SMALL_N * a * e
+                }
+
+                // closest points of two clamped parametric lines (Ericson, Real-Time Collision Detection)
+                pub fn closest_parameters(&self, other: &Linear) -> (f64, f64) {
+                    let r = self.origin - other.origin;
+                    let a = self.direction.dot(&self.direction);
+                    let b = self.direction.dot(&other.direction);
+                    let c = self.direction.dot(&r);
+                    let e = other.direction.dot(&other.direction);
+                    let f = other.direction.dot(&r);
+
+                    // all ranges contain 0, which is a valid start for parallel objects
+                    let mut s = if self.is_parallel(other) {
+                        0.
+                    } else {
+                        self.clamp((b * f - c * e) / (a * e - b * b))
+                    };
+
+                    let t = (b * s + f) / e;
+                    let t_clamped = other.clamp(t);
+
+                    if t_clamped != t || self.is_parallel(other) {
+                        s = self.clamp((b * t_clamped - c) / a);
+                    }
+
+                    (s, t_clamped)
+                }
+
+                pub fn intersect(&self, other: &Linear, tolerance: f64) -> $intersection {
+                    if self.is_parallel(other) {
+                        let distance = {
+                            let infinite = Linear {
+                                origin: self.origin,
+                                direction: self.direction,
+                                range: (f64::NEG_INFINITY, f64::INFINITY),
+                            };
+                            (infinite.get(infinite.closest_parameter(&other.origin)) - other.origin)
+                                .length()
+                        };
+
+                        if distance > tolerance {
+                            return $intersection::new("parallel");
+                        }
+
+                        // range of the other object in parameters of self
+                        let a = self.direction.dot(&self.direction);
+                        let to_self = |t: f64| {
+                            if t.is_infinite() {
+                                t * self.direction.dot(&other.direction).signum()
+                            } else {
+                                (other.get(t) - self.origin).dot(&self.direction) / a
+                            }
+                        };
+                        let u1 = to_self(other.range.0);
+                        let u2 = to_self(other.range.1);
+
+                        let low = u1.min(u2).max(self.range.0);
+                        let high = u1.max(u2).min(self.range.1);
+                        let parameter_tolerance = tolerance / a.sqrt();
+
+                        if low > high + parameter_tolerance {
+                            return $intersection::new("collinear");
+                        }
+
+                        if high - low <= parameter_tolerance {
+                            let point = self.get(low);
+                            let mut result = $intersection::new("point");
+                            result.ik_1 = Some(low);
+                            result.ik_2 = Some(other.closest_parameter(&point));
+                            result.point = Some(point);
+
+                            return result;
+                        }
+
+                        let mut result = $intersection::new("overlap");
+                        result.overlap = Some((low, high));
+
+                        return result;
+                    }
+
+                    let (s, t) = self.closest_parameters(other);
+                    let p1 = self.get(s);
+                    let p2 = other.get(t);
+
+                    if (p2 - p1).length() > tolerance {
+                        return $intersection::new("disjoint");
+                    }
+
+                    let mut result = $intersection::new("point");
+                    result.point = Some((p1 + p2) / 2.);
+                    result.ik_1 = Some(s);
+                    result.ik_2 = Some(t);
+
+                    result
+                }
+            }
+
+            define_linear!(
+                $line,
+                $vecClass,
+                $intersection,
+                f64::NEG_INFINITY,
+                f64::INFINITY
+            );
+            define_linear!($ray, $vecClass, $intersection, 0., f64::INFINITY);
+            define_linear!($segment, $vecClass, $intersection, 0., 1.);
+
+            #[pymethods]
+            impl $segment {
+                pub fn length(&self) -> f64 {
+                    (self.p2 - self.p1).length()
+                }
+
+                pub fn midpoint(&self) -> $vecClass {
+                    (self.p1 + self.p2) / 2.
+                }
+            }
+        }
+    };
+}
+
+define_line!(
+    Line2D,
+    Ray2D,
+    Segment2D,
+    Intersection2D,
+    Vector2D,
+    linear_2d
+);
+define_line!(
+    Line3D,
+    Ray3D,
+    Segment3D,
+    Intersection3D,
+    Vector3D,
+    linear_3d
+);
+
+pub use linear_2d::{Intersection2D, Line2D, Ray2D, Segment2D};
+pub use linear_3d::{Intersection3D, Line3D, Ray3D, Segment3D};
@@ -0,0 +1,31 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+mod linear;
+
+pub use linear::linear_2d::LinearArgs as LinearArgs2D;
+pub use linear::{Intersection2D, Line2D, Ray2D, Segment2D};
+pub use linear::{Intersection3D, Line3D, Ray3D, Segment3D};
+
+pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    #[pymodule]
+    fn line(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<Line2D>()?;
+        m.add_class::<Ray2D>()?;
+        m.add_class::<Segment2D>()?;
+        m.add_class::<Intersection2D>()?;
+        m.add_class::<Line3D>()?;
+        m.add_class::<Ray3D>()?;
+        m.add_class::<Segment3D>()?;
+        m.add_class::<Intersection3D>()?;
+        Ok(())
+    }
+
+    m.add_wrapped(wrap_pymodule!(line))?;
+
+    let sys = PyModule::import(_py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item("euklid_rs.line", m.getattr("line")?)?;
+    Ok(())
+}
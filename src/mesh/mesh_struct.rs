@@ -0,0 +1,143 @@
+use crate::io::json::{json_methods, JsonData};
+use crate::vector::_vector::*;
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+
+#[pyclass(module = "euklid_rs.mesh")]
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "MeshData", into = "MeshData")
+)]
+pub struct Mesh {
+    #[pyo3(get)]
+    pub vertices: Vec<Vector3D>,
+
+    #[pyo3(get)]
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl Mesh {
+    pub fn try_new(
+        vertices: Vec<Vector3D>,
+        triangles: Vec<[usize; 3]>,
+    ) -> Result<Self, &'static str> {
+        for triangle in &triangles {
+            if triangle.iter().any(|&index| index >= vertices.len()) {
+                return Err("triangle index out of bounds");
+            }
+        }
+
+        Ok(Self {
+            vertices,
+            triangles,
+        })
+    }
+}
+
+#[pymethods]
+impl Mesh {
+    #[new]
+    pub fn new(vertices: Vec<Vector3D>, triangles: Vec<[usize; 3]>) -> PyResult<Self> {
+        Self::try_new(vertices, triangles).map_err(pyo3::exceptions::PyIndexError::new_err)
+    }
+
+    pub fn copy(&self) -> Self {
+        self.clone()
+    }
+
+    /// get_area($self)
+    /// --
+    ///
+    /// get_area(self: euklid_rs.mesh.Mesh) -> float
+    /// sum of all triangle areas
+    pub fn get_area(&self) -> f64 {
+        let mut area = 0.;
+
+        for [a, b, c] in &self.triangles {
+            let ab = self.vertices[*b] - self.vertices[*a];
+            let ac = self.vertices[*c] - self.vertices[*a];
+            area += ab.cross(&ac).length() / 2.;
+        }
+
+        area
+    }
+
+    /// get_volume($self)
+    /// --
+    ///
+    /// get_volume(self: euklid_rs.mesh.Mesh) -> float
+    /// enclosed volume of a closed, outward oriented mesh
+    pub fn get_volume(&self) -> f64 {
+        let mut volume = 0.;
+
+        for [a, b, c] in &self.triangles {
+            let p1 = self.vertices[*a];
+            let p2 = self.vertices[*b];
+            let p3 = self.vertices[*c];
+            volume += p1.dot(&p2.cross(&p3)) / 6.;
+        }
+
+        volume
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.triangles.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Mesh({} vertices, {} triangles)",
+            self.vertices.len(),
+            self.triangles.len()
+        )
+    }
+}
+
+// json data: {"vertices": [[x, y, z], ...], "triangles": [[i, j, k], ...]}
+impl JsonData for Mesh {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("vertices", self.vertices.json_data(py)),
+            ("triangles", self.triangles.to_object(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Self::new(
+            JsonData::from_json_data(data.get_item("vertices")?)?,
+            data.get_item("triangles")?.extract()?,
+        )
+    }
+}
+
+json_methods!(Mesh);
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MeshData {
+    vertices: Vec<Vector3D>,
+    triangles: Vec<[usize; 3]>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Mesh> for MeshData {
+    fn from(mesh: Mesh) -> Self {
+        Self {
+            vertices: mesh.vertices,
+            triangles: mesh.triangles,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<MeshData> for Mesh {
+    type Error = &'static str;
+
+    fn try_from(data: MeshData) -> Result<Self, Self::Error> {
+        Self::try_new(data.vertices, data.triangles)
+    }
+}
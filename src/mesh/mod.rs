@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+mod mesh_struct;
+
+pub use mesh_struct::Mesh;
+
+pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    #[pymodule]
+    fn mesh(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<Mesh>()?;
+        Ok(())
+    }
+
+    m.add_wrapped(wrap_pymodule!(mesh))?;
+
+    let sys = PyModule::import(_py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item("euklid_rs.mesh", m.getattr("mesh")?)?;
+    Ok(())
+}
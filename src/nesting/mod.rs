@@ -0,0 +1,25 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+mod nesting_struct;
+mod panel;
+
+pub use nesting_struct::{Nesting, NestingResult, Placement};
+
+pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    #[pymodule]
+    fn nesting(_py: Python, m: &PyModule) -> PyResult<()> {
+        m.add_class::<Nesting>()?;
+        m.add_class::<NestingResult>()?;
+        m.add_class::<Placement>()?;
+        Ok(())
+    }
+
+    m.add_wrapped(wrap_pymodule!(nesting))?;
+
+    let sys = PyModule::import(_py, "sys")?;
+    let sys_modules: &PyDict = sys.getattr("modules")?.downcast()?;
+    sys_modules.set_item("euklid_rs.nesting", m.getattr("nesting")?)?;
+    Ok(())
+}
@@ -0,0 +1,393 @@
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+
+use crate::io::json::{json_methods, pickle_methods, JsonData};
+use crate::nesting::panel::{rotate, PlacedShape, Shape};
+use crate::polyline::PolyLine2D;
+use crate::vector::_vector::*;
+use crate::vector::Transformation2D;
+
+const NESTING_TOLERANCE: f64 = 1e-6;
+
+/// Position of a panel on the roll: rotation about the origin followed by a translation
+#[pyclass(module = "euklid_rs.nesting")]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Placement {
+    #[pyo3(get)]
+    pub index: usize,
+
+    #[pyo3(get)]
+    pub angle: f64,
+
+    #[pyo3(get)]
+    pub offset: Vector2D,
+}
+
+impl Placement {
+    pub fn apply_node(&self, node: &Vector2D) -> Vector2D {
+        rotate(node, self.angle) + self.offset
+    }
+}
+
+#[pymethods]
+impl Placement {
+    /// apply($self, panel)
+    /// --
+    ///
+    /// apply(self: euklid_rs.nesting.Placement, panel: euklid_rs.polyline.PolyLine2D) -> euklid_rs.polyline.PolyLine2D
+    /// move a panel to its position on the roll
+    pub fn apply(&self, panel: &PolyLine2D) -> PolyLine2D {
+        let nodes = panel
+            .nodes
+            .iter()
+            .map(|node| self.apply_node(node))
+            .collect();
+
+        PolyLine2D { nodes }
+    }
+
+    /// transformation($self)
+    /// --
+    ///
+    /// transformation(self: euklid_rs.nesting.Placement) -> euklid_rs.vector.Transformation2D
+    /// get the placement as a 2D transformation
+    pub fn transformation(&self) -> Transformation2D {
+        Transformation2D::translation(&self.offset)
+            .chain(&Transformation2D::rotation(self.angle, None))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Placement({}: {:.4} rad, ({:.4}, {:.4}))",
+            self.index, self.angle, self.offset.v[0], self.offset.v[1]
+        )
+    }
+}
+
+#[pyclass(module = "euklid_rs.nesting")]
+#[derive(Clone)]
+pub struct NestingResult {
+    /// placements in the order the panels were added
+    #[pyo3(get)]
+    pub placements: Vec<Placement>,
+
+    /// used length of the roll
+    #[pyo3(get)]
+    pub length: f64,
+}
+
+/// Bottom-left-fill nesting of closed panels onto a roll.
+/// The roll starts at the origin, with its width along the y-axis and
+/// its length along the x-axis.
+#[pyclass(module = "euklid_rs.nesting")]
+#[derive(Clone)]
+pub struct Nesting {
+    #[pyo3(get)]
+    pub width: f64,
+
+    #[pyo3(get)]
+    pub spacing: f64,
+
+    /// allowed rotations for panels without own rotations
+    #[pyo3(get)]
+    pub rotations: Vec<f64>,
+
+    panels: Vec<(PolyLine2D, Option<Vec<f64>>)>,
+}
+
+fn area(nodes: &[Vector2D]) -> f64 {
+    let mut area = 0.;
+
+    for i in 0..nodes.len() {
+        let j = (i + 1) % nodes.len();
+        area += nodes[i].cross(&nodes[j]);
+    }
+
+    f64::abs(area / 2.)
+}
+
+impl Nesting {
+    fn fits(&self, shape: &PlacedShape, placed: &[PlacedShape]) -> bool {
+        shape.bbox.min.v[0] > -NESTING_TOLERANCE
+            && shape.bbox.min.v[1] > -NESTING_TOLERANCE
+            && shape.bbox.max.v[1] < self.width + NESTING_TOLERANCE
+            && !placed
+                .iter()
+                .any(|other| shape.collides(other, self.spacing))
+    }
+
+    /// slide a valid position down and to the left as far as possible
+    fn compact(&self, shape: &Shape, mut position: Vector2D, placed: &[PlacedShape]) -> Vector2D {
+        for _ in 0..10 {
+            let start = position;
+
+            for direction in [1, 0] {
+                let mut target = position;
+                target.v[direction] = 0.;
+
+                if self.fits(&shape.moved(target), placed) {
+                    position = target;
+                    continue;
+                }
+
+                // bisect the largest valid step
+                let mut low = 0.;
+                let mut high = position.v[direction];
+                while high - low > NESTING_TOLERANCE {
+                    let mid = (low + high) / 2.;
+                    target.v[direction] = position.v[direction] - mid;
+
+                    if self.fits(&shape.moved(target), placed) {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+                position.v[direction] -= low;
+            }
+
+            if (position - start).length() < NESTING_TOLERANCE {
+                break;
+            }
+        }
+
+        position
+    }
+
+    fn place(&self, shape: &Shape, placed: &[PlacedShape]) -> Option<Vector2D> {
+        let free_height = self.width - shape.height();
+
+        if free_height < -NESTING_TOLERANCE {
+            return None;
+        }
+
+        let mut xs = vec![0.];
+        let mut ys = vec![0., free_height.max(0.)];
+
+        for other in placed {
+            xs.push(other.bbox.max.v[0] + self.spacing);
+            ys.push(other.bbox.max.v[1] + self.spacing);
+            ys.push(other.bbox.min.v[1] - self.spacing - shape.height());
+        }
+
+        ys.retain(|y| -NESTING_TOLERANCE < *y && *y < free_height + NESTING_TOLERANCE);
+
+        let mut candidates = Vec::new();
+        for x in &xs {
+            for y in &ys {
+                candidates.push(Vector2D::__new__([*x, y.clamp(0., free_height.max(0.))]));
+            }
+        }
+
+        candidates.sort_by(|a, b| {
+            a.v[0]
+                .partial_cmp(&b.v[0])
+                .unwrap()
+                .then(a.v[1].partial_cmp(&b.v[1]).unwrap())
+        });
+
+        candidates
+            .into_iter()
+            .find(|position| self.fits(&shape.moved(*position), placed))
+            .map(|position| self.compact(shape, position, placed))
+    }
+}
+
+#[pymethods]
+impl Nesting {
+    #[new]
+    #[pyo3(signature = (width, spacing=0., rotations=vec![0.]))]
+    pub fn new(width: f64, spacing: f64, rotations: Vec<f64>) -> PyResult<Self> {
+        if !(width > 0. && width.is_finite() && spacing >= 0. && spacing.is_finite())
+            || rotations.iter().any(|angle| !angle.is_finite())
+        {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "width must be positive, spacing non-negative and rotations finite",
+            ));
+        }
+
+        Ok(Self {
+            width,
+            spacing,
+            rotations,
+            panels: Vec::new(),
+        })
+    }
+
+    /// add_panel($self, panel, rotations=None)
+    /// --
+    ///
+    /// add_panel(self: euklid_rs.nesting.Nesting, panel: euklid_rs.polyline.PolyLine2D, rotations: Optional[List[float]] = None) -> int
+    /// add a closed panel outline. rotations override the allowed rotations (grain direction) for this panel
+    #[pyo3(signature = (panel, rotations=None))]
+    pub fn add_panel(&mut self, panel: PolyLine2D, rotations: Option<Vec<f64>>) -> PyResult<usize> {
+        if panel.nodes.len() < 2 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "a panel needs at least two nodes",
+            ));
+        }
+        if panel
+            .nodes
+            .iter()
+            .any(|node| !node.v[0].is_finite() || !node.v[1].is_finite())
+            || rotations.iter().flatten().any(|angle| !angle.is_finite())
+        {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "panel nodes and rotations must be finite",
+            ));
+        }
+
+        self.panels.push((panel, rotations));
+
+        Ok(self.panels.len() - 1)
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.panels.len()
+    }
+
+    /// solve($self)
+    /// --
+    ///
+    /// solve(self: euklid_rs.nesting.Nesting) -> euklid_rs.nesting.NestingResult
+    /// place all panels, largest panels first
+    pub fn solve(&self) -> PyResult<NestingResult> {
+        let mut order: Vec<usize> = (0..self.panels.len()).collect();
+        order.sort_by(|a, b| {
+            area(&self.panels[*b].0.nodes)
+                .partial_cmp(&area(&self.panels[*a].0.nodes))
+                .unwrap()
+        });
+
+        let mut placed = Vec::new();
+        let mut placements = vec![None; self.panels.len()];
+
+        for index in order {
+            let (panel, rotations) = &self.panels[index];
+            let mut best: Option<(Shape, Vector2D)> = None;
+
+            for angle in rotations.as_ref().unwrap_or(&self.rotations) {
+                let shape = Shape::new(&panel.nodes, *angle);
+
+                if let Some(position) = self.place(&shape, &placed) {
+                    let is_better = best.as_ref().is_none_or(|(best_shape, best_position)| {
+                        let end = position.v[0] + shape.width();
+                        let best_end = best_position.v[0] + best_shape.width();
+
+                        end < best_end - NESTING_TOLERANCE
+                            || (end < best_end + NESTING_TOLERANCE
+                                && position.v[1] < best_position.v[1])
+                    });
+
+                    if is_better {
+                        best = Some((shape, position));
+                    }
+                }
+            }
+
+            match best {
+                Some((shape, position)) => {
+                    placements[index] = Some(Placement {
+                        index,
+                        angle: shape.angle,
+                        offset: shape.offset + position,
+                    });
+                    placed.push(shape.moved(position));
+                }
+                None => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "panel {} does not fit onto the roll",
+                        index
+                    )))
+                }
+            }
+        }
+
+        let length = placed
+            .iter()
+            .map(|shape| shape.bbox.max.v[0])
+            .fold(0., f64::max);
+
+        Ok(NestingResult {
+            placements: placements.into_iter().flatten().collect(),
+            length,
+        })
+    }
+}
+
+// json data: {"index": index, "angle": angle, "offset": [...]}
+impl JsonData for Placement {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("index", self.index.into_py(py)),
+            ("angle", self.angle.into_py(py)),
+            ("offset", self.offset.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            index: data.get_item("index")?.extract()?,
+            angle: data.get_item("angle")?.extract()?,
+            offset: JsonData::from_json_data(data.get_item("offset")?)?,
+        })
+    }
+}
+
+json_methods!(Placement);
+
+// json data: {"placements": [...], "length": length}
+impl JsonData for NestingResult {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("placements", self.placements.json_data(py)),
+            ("length", self.length.into_py(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            placements: JsonData::from_json_data(data.get_item("placements")?)?,
+            length: data.get_item("length")?.extract()?,
+        })
+    }
+}
+
+pickle_methods!(NestingResult);
+
+// json data: {"width": width, "spacing": spacing, "rotations": [...], "panels": [[{"nodes": [...]}, [...] | null], ...]}
+impl JsonData for Nesting {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("width", self.width.into_py(py)),
+            ("spacing", self.spacing.into_py(py)),
+            ("rotations", self.rotations.json_data(py)),
+            ("panels", self.panels.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        let mut nesting = Self::new(
+            data.get_item("width")?.extract()?,
+            data.get_item("spacing")?.extract()?,
+            JsonData::from_json_data(data.get_item("rotations")?)?,
+        )?;
+        let panels: Vec<(PolyLine2D, Option<Vec<f64>>)> =
+            JsonData::from_json_data(data.get_item("panels")?)?;
+
+        for (panel, rotations) in panels {
+            nesting.add_panel(panel, rotations)?;
+        }
+
+        Ok(nesting)
+    }
+}
+
+pickle_methods!(Nesting);
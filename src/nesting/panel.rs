@@ -0,0 +1,144 @@
+use crate::boundary::BoundingBox2D;
+use crate::vector::_vector::*;
+
+/// A (rotated) panel outline, moved so that its bounding box starts at the origin
+#[derive(Clone)]
+pub struct Shape {
+    pub angle: f64,
+    pub nodes: Vec<Vector2D>,
+    pub bbox: BoundingBox2D,
+    /// offset to apply to the rotated panel to move it to the origin
+    pub offset: Vector2D,
+}
+
+pub fn rotate(node: &Vector2D, angle: f64) -> Vector2D {
+    let (sin, cos) = angle.sin_cos();
+    Vector2D::__new__([
+        cos * node.v[0] - sin * node.v[1],
+        sin * node.v[0] + cos * node.v[1],
+    ])
+}
+
+impl Shape {
+    pub fn new(nodes: &[Vector2D], angle: f64) -> Self {
+        let mut rotated: Vec<Vector2D> = nodes.iter().map(|node| rotate(node, angle)).collect();
+
+        // polygons are closed implicitly
+        if rotated.len() > 1 && rotated.first().unwrap().v == rotated.last().unwrap().v {
+            rotated.pop();
+        }
+
+        let bbox = BoundingBox2D::from_nodes(&rotated);
+        let offset = bbox.min * -1.;
+
+        let nodes = rotated.iter().map(|node| *node + offset).collect();
+
+        Self {
+            angle,
+            nodes,
+            bbox: BoundingBox2D::new(Vector2D::zero(), bbox.size()),
+            offset,
+        }
+    }
+
+    pub fn width(&self) -> f64 {
+        self.bbox.max.v[0]
+    }
+
+    pub fn height(&self) -> f64 {
+        self.bbox.max.v[1]
+    }
+
+    pub fn moved(&self, position: Vector2D) -> PlacedShape {
+        PlacedShape {
+            nodes: self.nodes.iter().map(|node| *node + position).collect(),
+            bbox: BoundingBox2D::new(self.bbox.min + position, self.bbox.max + position),
+        }
+    }
+}
+
+pub struct PlacedShape {
+    pub nodes: Vec<Vector2D>,
+    pub bbox: BoundingBox2D,
+}
+
+fn segments_cross(p1: &Vector2D, p2: &Vector2D, q1: &Vector2D, q2: &Vector2D) -> bool {
+    let d1 = (*q1 - *p1).cross(&(*p2 - *p1));
+    let d2 = (*q2 - *p1).cross(&(*p2 - *p1));
+    let d3 = (*p1 - *q1).cross(&(*q2 - *q1));
+    let d4 = (*p2 - *q1).cross(&(*q2 - *q1));
+
+    d1 * d2 < 0. && d3 * d4 < 0.
+}
+
+fn point_distance(p: &Vector2D, a: &Vector2D, b: &Vector2D) -> f64 {
+    let segment = *b - *a;
+    let length = segment.dot(&segment);
+    let k = if length > 0. {
+        ((*p - *a).dot(&segment) / length).clamp(0., 1.)
+    } else {
+        0.
+    };
+
+    (*p - (*a + segment * k)).length()
+}
+
+/// distance between two non-crossing segments
+fn segment_distance(p1: &Vector2D, p2: &Vector2D, q1: &Vector2D, q2: &Vector2D) -> f64 {
+    f64::min(
+        f64::min(point_distance(p1, q1, q2), point_distance(p2, q1, q2)),
+        f64::min(point_distance(q1, p1, p2), point_distance(q2, p1, p2)),
+    )
+}
+
+fn contains(polygon: &[Vector2D], point: &Vector2D) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+
+    for i in 0..polygon.len() {
+        let (a, b) = (&polygon[i], &polygon[j]);
+        if (a.v[1] > point.v[1]) != (b.v[1] > point.v[1])
+            && point.v[0] < (b.v[0] - a.v[0]) * (point.v[1] - a.v[1]) / (b.v[1] - a.v[1]) + a.v[0]
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+
+    inside
+}
+
+impl PlacedShape {
+    /// check if two shapes overlap or are closer than spacing
+    pub fn collides(&self, other: &PlacedShape, spacing: f64) -> bool {
+        let tolerance = spacing - Vector2D::SMALL_N;
+
+        for i in 0..2 {
+            if self.bbox.min.v[i] > other.bbox.max.v[i] + tolerance
+                || other.bbox.min.v[i] > self.bbox.max.v[i] + tolerance
+            {
+                return false;
+            }
+        }
+
+        let n = self.nodes.len();
+        let m = other.nodes.len();
+
+        for i in 0..n {
+            let (p1, p2) = (&self.nodes[i], &self.nodes[(i + 1) % n]);
+            for j in 0..m {
+                let (q1, q2) = (&other.nodes[j], &other.nodes[(j + 1) % m]);
+
+                if segments_cross(p1, p2, q1, q2) {
+                    return true;
+                }
+                if spacing > 0. && segment_distance(p1, p2, q1, q2) < tolerance {
+                    return true;
+                }
+            }
+        }
+
+        (n > 2 && contains(&self.nodes, &other.nodes[0]))
+            || (m > 2 && contains(&other.nodes, &self.nodes[0]))
+    }
+}
@@ -0,0 +1,42 @@
+//! Optional data parallelism for batch operations.
+//! With the cargo feature "parallel" the items are processed on the rayon thread pool,
+//! otherwise sequentially. The results keep the order of the items.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// apply f to all items
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    let result = items.par_iter().map(f).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    let result = items.iter().map(f).collect();
+
+    result
+}
+
+/// apply f to all items with a mutable state, created by init (once per thread)
+pub fn map_init<T, S, R, I, F>(items: &[T], init: I, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    I: Fn() -> S + Sync + Send,
+    F: Fn(&mut S, &T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    let result = items.par_iter().map_init(init, f).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    let result = {
+        let mut state = init();
+        items.iter().map(|item| f(&mut state, item)).collect()
+    };
+
+    result
+}
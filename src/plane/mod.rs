@@ -4,10 +4,12 @@ use pyo3::wrap_pymodule;
 
 mod plane_struct;
 
+pub use plane_struct::Plane;
+
 pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
     #[pymodule]
     fn plane(_py: Python, m: &PyModule) -> PyResult<()> {
-        m.add_class::<plane_struct::Plane>()?;
+        m.add_class::<Plane>()?;
         Ok(())
     }
 
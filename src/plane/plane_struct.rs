@@ -1,10 +1,33 @@
+use crate::io::json::{json_methods, JsonData};
+use crate::polyline::{PolyLine2D, PolyLine3D};
 use crate::vector::_vector;
 use crate::vector::transform;
 use nalgebra as na;
 use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
 
-#[pyclass]
+#[derive(FromPyObject)]
+pub enum PointsArgs {
+    PolyLine(PolyLine3D),
+    List(Vec<_vector::Vector3D>),
+}
+
+impl PointsArgs {
+    pub fn nodes(&self) -> &[_vector::Vector3D] {
+        match self {
+            PointsArgs::PolyLine(line) => &line.nodes,
+            PointsArgs::List(nodes) => nodes,
+        }
+    }
+}
+
+#[pyclass(module = "euklid_rs.plane")]
 #[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "PlaneData", into = "PlaneData")
+)]
 pub struct Plane {
     #[pyo3(get, set)]
     pub p0: _vector::Vector3D,
@@ -18,17 +41,15 @@ pub struct Plane {
     #[pyo3(get, set)]
     pub normvector: _vector::Vector3D,
 
-    transformation: transform::Transformation,
+    pub(crate) transformation: transform::Transformation,
 }
 
-#[pymethods]
 impl Plane {
-    #[new]
-    fn __new__(p0: _vector::Vector3D, v1: _vector::Vector3D, v2: _vector::Vector3D) -> Self {
+    pub const SMALL_N: f64 = 1e-8;
+
+    pub fn new(p0: _vector::Vector3D, v1: _vector::Vector3D, v2: _vector::Vector3D) -> Self {
         let n = v1.cross(&v2);
-        let mut matrix = na::Matrix4::<f64>::new(
-            0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0.,
-        );
+        let mut matrix = na::Matrix4::<f64>::identity();
 
         for i in 0..3 {
             matrix[(i, 0)] = v1.v[i]; // x
@@ -41,15 +62,209 @@ impl Plane {
         Plane::setup(transform::Transformation::new(matrix))
     }
 
+    /// unit normvector, raises ValueError for a degenerate plane (parallel x and y vectors)
+    pub fn unit_normal(&self) -> PyResult<na::Vector3<f64>> {
+        if self.normvector.length() < Self::SMALL_N {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "plane normal must not be zero",
+            ));
+        }
+
+        Ok(self.normvector.v.normalize())
+    }
+
+    // parameter of the intersection along p1 -> p2 (p1 + ik * (p2 - p1))
+    fn intersect_param(
+        &self,
+        p1: &_vector::Vector3D,
+        p2: &_vector::Vector3D,
+    ) -> PyResult<Option<f64>> {
+        let normal = self.unit_normal()?;
+        let denominator = normal.dot(&(p2.v - p1.v));
+
+        if denominator.abs() < Self::SMALL_N {
+            Ok(None)
+        } else {
+            Ok(Some(normal.dot(&(self.p0.v - p1.v)) / denominator))
+        }
+    }
+
+    /// dual basis of (x_vector, y_vector): project(p0 + a * x + b * y) = (a, b) for
+    /// non-orthonormal planes. falls back to the plain vectors for degenerate planes
+    fn dual_basis(&self) -> (na::Vector3<f64>, na::Vector3<f64>) {
+        let x = self.x_vector.v;
+        let y = self.y_vector.v;
+        let gram = na::Matrix2::new(x.dot(&x), x.dot(&y), x.dot(&y), y.dot(&y));
+
+        match gram.try_inverse() {
+            Some(inverse) => (
+                x * inverse[(0, 0)] + y * inverse[(0, 1)],
+                x * inverse[(1, 0)] + y * inverse[(1, 1)],
+            ),
+            None => (x, y),
+        }
+    }
+
+    fn project_dual(
+        &self,
+        dual: &(na::Vector3<f64>, na::Vector3<f64>),
+        vec: &_vector::Vector3D,
+    ) -> _vector::Vector2D {
+        let diff = vec.v - self.p0.v;
+
+        _vector::Vector2D {
+            v: na::Vector2::new(dual.0.dot(&diff), dual.1.dot(&diff)),
+        }
+    }
+
+    /// gram-schmidt: x along v1, y in the (v1, v2) plane, all unit length
+    pub fn orthonormal(
+        p0: _vector::Vector3D,
+        v1: _vector::Vector3D,
+        v2: _vector::Vector3D,
+    ) -> PyResult<Self> {
+        let x = v1.v;
+        let y = v2.v - x * (x.dot(&v2.v) / x.norm_squared());
+
+        if x.norm() < Self::SMALL_N || y.norm() < Self::SMALL_N * v2.v.norm().max(1.) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "plane vectors must not be zero or parallel",
+            ));
+        }
+
+        Ok(Plane::new(
+            p0,
+            _vector::Vector3D { v: x.normalize() },
+            _vector::Vector3D { v: y.normalize() },
+        ))
+    }
+}
+
+#[pymethods]
+impl Plane {
+    /// Plane(p0, v1, v2, normalize=False)
+    /// --
+    ///
+    /// Plane(p0: euklid_rs.vector.Vector3D, v1: euklid_rs.vector.Vector3D, v2: euklid_rs.vector.Vector3D, normalize: bool = False) -> euklid_rs.plane.Plane
+    /// create a plane from an origin and two vectors. with normalize the vectors are
+    /// orthonormalized (gram-schmidt), otherwise they are used as given
+    #[new]
+    #[pyo3(signature = (p0, v1, v2, normalize=false))]
+    pub fn __new__(
+        p0: _vector::Vector3D,
+        v1: _vector::Vector3D,
+        v2: _vector::Vector3D,
+        normalize: bool,
+    ) -> PyResult<Self> {
+        if normalize {
+            Plane::orthonormal(p0, v1, v2)
+        } else {
+            Ok(Plane::new(p0, v1, v2))
+        }
+    }
+
+    /// from_points(p1, p2, p3)
+    /// --
+    ///
+    /// from_points(p1: euklid_rs.vector.Vector3D, p2: euklid_rs.vector.Vector3D, p3: euklid_rs.vector.Vector3D) -> euklid_rs.plane.Plane
+    /// create an orthonormal plane through three points with origin p1 and x-axis towards p2
+    #[staticmethod]
+    pub fn from_points(
+        p1: _vector::Vector3D,
+        p2: _vector::Vector3D,
+        p3: _vector::Vector3D,
+    ) -> PyResult<Self> {
+        Plane::orthonormal(p1, p2 - p1, p3 - p1)
+    }
+
+    /// from_normal(p0, normal)
+    /// --
+    ///
+    /// from_normal(p0: euklid_rs.vector.Vector3D, normal: euklid_rs.vector.Vector3D) -> euklid_rs.plane.Plane
+    /// create an orthonormal plane from origin and normal. the x-axis is the projection
+    /// of the global x-axis (or y-axis if the normal is parallel to x)
+    #[staticmethod]
+    pub fn from_normal(p0: _vector::Vector3D, normal: _vector::Vector3D) -> PyResult<Self> {
+        if normal.v.norm() < Self::SMALL_N {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "normal must not be zero",
+            ));
+        }
+
+        let n = normal.v.normalize();
+        let mut x = na::Vector3::x() - n * n[0];
+        if x.norm() < 1e-3 {
+            x = na::Vector3::y() - n * n[1];
+        }
+        let y = n.cross(&x);
+
+        Plane::orthonormal(p0, _vector::Vector3D { v: x }, _vector::Vector3D { v: y })
+    }
+
+    /// best_fit(points)
+    /// --
+    ///
+    /// best_fit(points: Union[euklid_rs.polyline.PolyLine3D, List[euklid_rs.vector.Vector3D]]) -> Tuple[euklid_rs.plane.Plane, float]
+    /// least-squares plane through the centroid of the points and the rms distance of the points.
+    /// the x-axis follows the direction of the largest spread
+    #[staticmethod]
+    pub fn best_fit(points: PointsArgs) -> PyResult<(Self, f64)> {
+        let nodes = points.nodes();
+
+        if nodes.len() < 3 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "at least three points are needed",
+            ));
+        }
+        if nodes.iter().any(|p| !p.v.iter().all(|x| x.is_finite())) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "points must be finite",
+            ));
+        }
+
+        let centroid =
+            nodes.iter().fold(na::Vector3::zeros(), |sum, p| sum + p.v) / nodes.len() as f64;
+
+        let mut covariance = na::Matrix3::<f64>::zeros();
+        for node in nodes {
+            let diff = node.v - centroid;
+            covariance += diff * diff.transpose();
+        }
+
+        let eigen = covariance.symmetric_eigen();
+        let mut order = [0, 1, 2];
+        order.sort_by(|a, b| eigen.eigenvalues[*b].total_cmp(&eigen.eigenvalues[*a]));
+
+        let extent = eigen.eigenvalues[order[0]].max(0.).sqrt();
+        if eigen.eigenvalues[order[1]].max(0.).sqrt() <= Self::SMALL_N * extent.max(1.) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "points are collinear",
+            ));
+        }
+
+        let x = eigen.eigenvectors.column(order[0]).into_owned();
+        let y = eigen.eigenvectors.column(order[1]).into_owned();
+        let plane = Plane::orthonormal(
+            _vector::Vector3D { v: centroid },
+            _vector::Vector3D { v: x },
+            _vector::Vector3D { v: y },
+        )?;
+
+        let n = plane.normvector.v;
+        let squared_sum: f64 = nodes.iter().map(|p| (p.v - centroid).dot(&n).powi(2)).sum();
+
+        Ok((plane, (squared_sum / nodes.len() as f64).sqrt()))
+    }
+
     #[staticmethod]
     fn setup(transformation: transform::Transformation) -> Self {
-        let p0 = transformation.apply(&_vector::Vector3D {
+        let p0 = transformation.apply_vec(&_vector::Vector3D {
             v: na::Vector3::<f64>::new(0., 0., 0.),
         });
 
         let x_vector = _vector::Vector3D {
             v: transformation
-                .apply(&_vector::Vector3D {
+                .apply_vec(&_vector::Vector3D {
                     v: na::Vector3::<f64>::new(1., 0., 0.),
                 })
                 .v
@@ -57,7 +272,7 @@ impl Plane {
         };
         let y_vector = _vector::Vector3D {
             v: transformation
-                .apply(&_vector::Vector3D {
+                .apply_vec(&_vector::Vector3D {
                     v: na::Vector3::<f64>::new(0., 1., 0.),
                 })
                 .v
@@ -65,7 +280,7 @@ impl Plane {
         };
         let normvector = _vector::Vector3D {
             v: transformation
-                .apply(&_vector::Vector3D {
+                .apply_vec(&_vector::Vector3D {
                     v: na::Vector3::<f64>::new(0., 0., 1.),
                 })
                 .v
@@ -80,20 +295,274 @@ impl Plane {
         }
     }
 
-    fn project(&self, vec: _vector::Vector3D) -> _vector::Vector2D {
-        let diff = vec - self.p0;
+    /// signed_distance($self, vec)
+    /// --
+    ///
+    /// signed_distance(self: euklid_rs.plane.Plane, vec: euklid_rs.vector.Vector3D) -> float
+    /// distance of a point to the plane, positive on the side of the normvector
+    pub fn signed_distance(&self, vec: &_vector::Vector3D) -> PyResult<f64> {
+        Ok(self.unit_normal()?.dot(&(vec.v - self.p0.v)))
+    }
+
+    /// side($self, vec, tolerance=1e-8)
+    /// --
+    ///
+    /// side(self: euklid_rs.plane.Plane, vec: euklid_rs.vector.Vector3D, tolerance: float = 1e-8) -> int
+    /// 1 for points in front of the plane (normvector side), -1 behind and 0 on the plane
+    #[pyo3(signature = (vec, tolerance=Self::SMALL_N))]
+    pub fn side(&self, vec: &_vector::Vector3D, tolerance: f64) -> PyResult<i32> {
+        let distance = self.signed_distance(vec)?;
 
-        let x = self.x_vector.dot(&diff);
-        let y = self.y_vector.dot(&diff);
+        Ok(if distance.abs() <= tolerance {
+            0
+        } else if distance > 0. {
+            1
+        } else {
+            -1
+        })
+    }
 
-        let v = na::Vector2::new(x, y);
-        _vector::Vector2D { v }
+    /// intersect_line($self, p1, p2)
+    /// --
+    ///
+    /// intersect_line(self: euklid_rs.plane.Plane, p1: euklid_rs.vector.Vector3D, p2: euklid_rs.vector.Vector3D) -> Optional[Tuple[float, euklid_rs.vector.Vector3D]]
+    /// intersect with the infinite line through p1 and p2. returns the line parameter ik
+    /// (p1 + ik * (p2 - p1)) and the point, None if the line is parallel to the plane
+    pub fn intersect_line(
+        &self,
+        p1: &_vector::Vector3D,
+        p2: &_vector::Vector3D,
+    ) -> PyResult<Option<(f64, _vector::Vector3D)>> {
+        Ok(self
+            .intersect_param(p1, p2)?
+            .map(|ik| (ik, *p1 + (*p2 - *p1) * ik)))
     }
 
-    fn align(&self, vec: _vector::Vector2D) -> _vector::Vector3D {
+    /// intersect_segment($self, p1, p2)
+    /// --
+    ///
+    /// intersect_segment(self: euklid_rs.plane.Plane, p1: euklid_rs.vector.Vector3D, p2: euklid_rs.vector.Vector3D) -> Optional[Tuple[float, euklid_rs.vector.Vector3D]]
+    /// same as intersect_line, restricted to the segment (0 <= ik <= 1)
+    pub fn intersect_segment(
+        &self,
+        p1: &_vector::Vector3D,
+        p2: &_vector::Vector3D,
+    ) -> PyResult<Option<(f64, _vector::Vector3D)>> {
+        Ok(self
+            .intersect_line(p1, p2)?
+            .filter(|(ik, _)| -Self::SMALL_N <= *ik && *ik <= 1. + Self::SMALL_N))
+    }
+
+    /// intersect_polyline($self, line)
+    /// --
+    ///
+    /// intersect_polyline(self: euklid_rs.plane.Plane, line: euklid_rs.polyline.PolyLine3D) -> List[Tuple[float, euklid_rs.vector.Vector3D]]
+    /// get all crossings of a polyline with the plane as (ik, point), ordered along the polyline.
+    /// nodes on the plane are reported once
+    pub fn intersect_polyline(&self, line: &PolyLine3D) -> PyResult<Vec<(f64, _vector::Vector3D)>> {
+        let normal = self.unit_normal()?;
+        let distances: Vec<f64> = line
+            .nodes
+            .iter()
+            .map(|node| normal.dot(&(node.v - self.p0.v)))
+            .collect();
+        let mut result = Vec::new();
+
+        for (i, distance) in distances.iter().enumerate() {
+            if distance.abs() <= Self::SMALL_N {
+                result.push((i as f64, line.nodes[i]));
+                continue;
+            }
+
+            if let Some(next) = distances.get(i + 1) {
+                if next.abs() > Self::SMALL_N && distance.signum() != next.signum() {
+                    let k = distance / (distance - next);
+                    let point = line.nodes[i] + (line.nodes[i + 1] - line.nodes[i]) * k;
+                    result.push((i as f64 + k, point));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// intersect_plane($self, other)
+    /// --
+    ///
+    /// intersect_plane(self: euklid_rs.plane.Plane, other: euklid_rs.plane.Plane) -> Optional[Tuple[euklid_rs.vector.Vector3D, euklid_rs.vector.Vector3D]]
+    /// get the intersection line as (point, unit direction), None for parallel planes.
+    /// the point is the closest point of the line to self.p0
+    pub fn intersect_plane(
+        &self,
+        other: &Plane,
+    ) -> PyResult<Option<(_vector::Vector3D, _vector::Vector3D)>> {
+        let n1 = self.unit_normal()?;
+        let n2 = other.unit_normal()?;
+        let direction = n1.cross(&n2);
+
+        if direction.norm() < Self::SMALL_N {
+            return Ok(None);
+        }
+
+        // solve for the point on both planes closest to self.p0
+        let h1 = n1.dot(&self.p0.v);
+        let h2 = n2.dot(&other.p0.v);
+        let point = (n2 * h1 - n1 * h2).cross(&direction) / direction.norm_squared();
+        let direction = direction.normalize();
+        let point = point + direction * direction.dot(&(self.p0.v - point));
+
+        Ok(Some((
+            _vector::Vector3D { v: point },
+            _vector::Vector3D { v: direction },
+        )))
+    }
+
+    /// project($self, vec)
+    /// --
+    ///
+    /// project(self: euklid_rs.plane.Plane, vec: euklid_rs.vector.Vector3D) -> euklid_rs.vector.Vector2D
+    /// get the plane coordinates (a, b) of the closest point p0 + a * x_vector + b * y_vector,
+    /// the inverse of align for points on the plane
+    pub fn project(&self, vec: _vector::Vector3D) -> _vector::Vector2D {
+        self.project_dual(&self.dual_basis(), &vec)
+    }
+
+    pub fn align(&self, vec: _vector::Vector2D) -> _vector::Vector3D {
         let v = na::Vector3::<f64>::new(vec.v[0], vec.v[1], 0.);
         let vec_3d = _vector::Vector3D { v };
 
-        self.transformation.apply(&vec_3d)
+        self.transformation.apply_vec(&vec_3d)
+    }
+
+    /// project_polyline($self, line)
+    /// --
+    ///
+    /// project_polyline(self: euklid_rs.plane.Plane, line: euklid_rs.polyline.PolyLine3D) -> euklid_rs.polyline.PolyLine2D
+    /// get the plane coordinates of all nodes (see project)
+    pub fn project_polyline(&self, line: &PolyLine3D) -> PolyLine2D {
+        let dual = self.dual_basis();
+        let nodes = line
+            .nodes
+            .iter()
+            .map(|node| self.project_dual(&dual, node))
+            .collect();
+
+        PolyLine2D { nodes }
+    }
+
+    /// align_polyline($self, line)
+    /// --
+    ///
+    /// align_polyline(self: euklid_rs.plane.Plane, line: euklid_rs.polyline.PolyLine2D) -> euklid_rs.polyline.PolyLine3D
+    /// lift a polyline in plane coordinates back to 3D (see align)
+    pub fn align_polyline(&self, line: &PolyLine2D) -> PolyLine3D {
+        let nodes = line.nodes.iter().map(|node| self.align(*node)).collect();
+
+        PolyLine3D { nodes }
+    }
+
+    /// project_point_3d($self, vec)
+    /// --
+    ///
+    /// project_point_3d(self: euklid_rs.plane.Plane, vec: euklid_rs.vector.Vector3D) -> euklid_rs.vector.Vector3D
+    /// orthogonal projection onto the plane (closest point on the plane)
+    pub fn project_point_3d(&self, vec: &_vector::Vector3D) -> PyResult<_vector::Vector3D> {
+        let normal = self.unit_normal()?;
+
+        Ok(_vector::Vector3D {
+            v: vec.v - normal * normal.dot(&(vec.v - self.p0.v)),
+        })
+    }
+
+    /// project_polyline_3d($self, line)
+    /// --
+    ///
+    /// project_polyline_3d(self: euklid_rs.plane.Plane, line: euklid_rs.polyline.PolyLine3D) -> euklid_rs.polyline.PolyLine3D
+    /// orthogonal projection of all nodes onto the plane
+    pub fn project_polyline_3d(&self, line: &PolyLine3D) -> PyResult<PolyLine3D> {
+        let nodes = line
+            .nodes
+            .iter()
+            .map(|node| self.project_point_3d(node))
+            .collect::<PyResult<_>>()?;
+
+        Ok(PolyLine3D { nodes })
+    }
+
+    /// mirror_point($self, vec)
+    /// --
+    ///
+    /// mirror_point(self: euklid_rs.plane.Plane, vec: euklid_rs.vector.Vector3D) -> euklid_rs.vector.Vector3D
+    /// mirror a point across the plane
+    pub fn mirror_point(&self, vec: &_vector::Vector3D) -> PyResult<_vector::Vector3D> {
+        let normal = self.unit_normal()?;
+
+        Ok(_vector::Vector3D {
+            v: vec.v - normal * (2. * normal.dot(&(vec.v - self.p0.v))),
+        })
+    }
+
+    /// mirror_polyline($self, line)
+    /// --
+    ///
+    /// mirror_polyline(self: euklid_rs.plane.Plane, line: euklid_rs.polyline.PolyLine3D) -> euklid_rs.polyline.PolyLine3D
+    /// mirror all nodes of a polyline across the plane
+    pub fn mirror_polyline(&self, line: &PolyLine3D) -> PyResult<PolyLine3D> {
+        let nodes = line
+            .nodes
+            .iter()
+            .map(|node| self.mirror_point(node))
+            .collect::<PyResult<_>>()?;
+
+        Ok(PolyLine3D { nodes })
+    }
+}
+
+// json data: {"p0": [...], "x_vector": [...], "y_vector": [...]}, the normvector is derived
+impl JsonData for Plane {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("p0", self.p0.json_data(py)),
+            ("x_vector", self.x_vector.json_data(py)),
+            ("y_vector", self.y_vector.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self::new(
+            JsonData::from_json_data(data.get_item("p0")?)?,
+            JsonData::from_json_data(data.get_item("x_vector")?)?,
+            JsonData::from_json_data(data.get_item("y_vector")?)?,
+        ))
+    }
+}
+
+json_methods!(Plane);
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PlaneData {
+    p0: _vector::Vector3D,
+    x_vector: _vector::Vector3D,
+    y_vector: _vector::Vector3D,
+}
+
+#[cfg(feature = "serde")]
+impl From<Plane> for PlaneData {
+    fn from(plane: Plane) -> Self {
+        Self {
+            p0: plane.p0,
+            x_vector: plane.x_vector,
+            y_vector: plane.y_vector,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<PlaneData> for Plane {
+    fn from(data: PlaneData) -> Self {
+        Self::new(data.p0, data.x_vector, data.y_vector)
     }
 }
@@ -0,0 +1,305 @@
+use std::collections::HashSet;
+
+use crate::mesh::Mesh;
+use crate::vector::_vector::*;
+
+// relative tolerance (scaled with the extent of the point set)
+const HULL_TOLERANCE: f64 = 1e-10;
+
+/// Andrew's monotone chain. Returns the hull in counter-clockwise order
+/// without repeating the first node. Collinear and duplicate nodes are dropped.
+pub fn monotone_chain(nodes: &[Vector2D]) -> Result<Vec<Vector2D>, &'static str> {
+    if nodes.iter().any(|p| !p.v.iter().all(|x| x.is_finite())) {
+        return Err("points must be finite");
+    }
+
+    let mut points = nodes.to_vec();
+    points.sort_by(|p1, p2| {
+        p1.v[0]
+            .total_cmp(&p2.v[0])
+            .then(p1.v[1].total_cmp(&p2.v[1]))
+    });
+    points.dedup_by(|p1, p2| p1.v == p2.v);
+
+    if points.len() < 3 {
+        return Ok(points);
+    }
+
+    let turn = |o: &Vector2D, a: &Vector2D, b: &Vector2D| (*a - *o).cross(&(*b - *o));
+
+    let mut lower: Vec<Vector2D> = Vec::new();
+    for point in &points {
+        while lower.len() >= 2
+            && turn(&lower[lower.len() - 2], &lower[lower.len() - 1], point) <= 0.
+        {
+            lower.pop();
+        }
+        lower.push(*point);
+    }
+
+    let mut upper: Vec<Vector2D> = Vec::new();
+    for point in points.iter().rev() {
+        while upper.len() >= 2
+            && turn(&upper[upper.len() - 2], &upper[upper.len() - 1], point) <= 0.
+        {
+            upper.pop();
+        }
+        upper.push(*point);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+
+    Ok(lower)
+}
+
+struct Face {
+    vertices: [usize; 3],
+    normal: Vector3D,
+    offset: f64,
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl Face {
+    fn new(vertices: [usize; 3], points: &[Vector3D]) -> Self {
+        let [a, b, c] = vertices;
+        let normal = (points[b] - points[a]).cross(&(points[c] - points[a]));
+        let length = normal.length();
+        let normal = if length > 0. { normal / length } else { normal };
+
+        Self {
+            vertices,
+            normal,
+            offset: normal.dot(&points[a]),
+            outside: Vec::new(),
+            alive: true,
+        }
+    }
+
+    fn distance(&self, point: &Vector3D) -> f64 {
+        self.normal.dot(point) - self.offset
+    }
+
+    fn edges(&self) -> [(usize, usize); 3] {
+        let [a, b, c] = self.vertices;
+        [(a, b), (b, c), (c, a)]
+    }
+}
+
+fn assign_outside(faces: &mut [Face], candidates: &[usize], points: &[Vector3D], eps: f64) {
+    for &index in candidates {
+        let mut best: Option<(usize, f64)> = None;
+
+        for (face_index, face) in faces.iter().enumerate() {
+            if !face.alive {
+                continue;
+            }
+            let distance = face.distance(&points[index]);
+            if distance > eps && best.is_none_or(|(_, d)| distance > d) {
+                best = Some((face_index, distance));
+            }
+        }
+
+        if let Some((face_index, _)) = best {
+            faces[face_index].outside.push(index);
+        }
+    }
+}
+
+fn flat_hull(
+    points: &[Vector3D],
+    origin: Vector3D,
+    normal: Vector3D,
+) -> Result<Mesh, &'static str> {
+    let x_axis = (points
+        .iter()
+        .map(|p| *p - origin)
+        .max_by(|p1, p2| p1.length().total_cmp(&p2.length()))
+        .unwrap())
+    .normalized();
+    let y_axis = normal.cross(&x_axis);
+
+    let projected: Vec<Vector2D> = points
+        .iter()
+        .map(|p| Vector2D::__new__([(*p - origin).dot(&x_axis), (*p - origin).dot(&y_axis)]))
+        .collect();
+
+    let vertices: Vec<Vector3D> = monotone_chain(&projected)?
+        .iter()
+        .map(|p| origin + x_axis * p.v[0] + y_axis * p.v[1])
+        .collect();
+
+    let triangles = (1..vertices.len() - 1).map(|i| [0, i, i + 1]).collect();
+
+    Ok(Mesh {
+        vertices,
+        triangles,
+    })
+}
+
+/// Quickhull for a 3D point cloud. The resulting triangles are oriented outwards.
+/// Coplanar input results in a flat (single-sided) triangulation of the 2D hull.
+pub fn quickhull(points: &[Vector3D]) -> Result<Mesh, &'static str> {
+    if points.is_empty() {
+        return Err("no points given");
+    }
+    if points.iter().any(|p| !p.v.iter().all(|x| x.is_finite())) {
+        return Err("points must be finite");
+    }
+
+    let mut extent: f64 = 0.;
+    for i in 0..3 {
+        let min = points.iter().map(|p| p.v[i]).fold(f64::INFINITY, f64::min);
+        let max = points.iter().map(|p| p.v[i]).fold(-f64::INFINITY, f64::max);
+        extent = extent.max(max - min);
+    }
+    let eps = extent * HULL_TOLERANCE;
+
+    // initial simplex: two axis-extreme points with the largest distance
+    let mut extremes = Vec::new();
+    for i in 0..3 {
+        let cmp = |a: &&Vector3D, b: &&Vector3D| a.v[i].total_cmp(&b.v[i]);
+        let min = points
+            .iter()
+            .enumerate()
+            .min_by(|a, b| cmp(&a.1, &b.1))
+            .unwrap();
+        let max = points
+            .iter()
+            .enumerate()
+            .max_by(|a, b| cmp(&a.1, &b.1))
+            .unwrap();
+        extremes.push(min.0);
+        extremes.push(max.0);
+    }
+
+    let mut i0 = 0;
+    let mut i1 = 0;
+    let mut max_distance = 0.;
+    for &a in &extremes {
+        for &b in &extremes {
+            let distance = (points[a] - points[b]).length();
+            if distance > max_distance {
+                max_distance = distance;
+                i0 = a;
+                i1 = b;
+            }
+        }
+    }
+
+    if max_distance <= eps {
+        return Err("points are coincident");
+    }
+
+    let direction = (points[i1] - points[i0]).normalized();
+    let (i2, line_distance) = points
+        .iter()
+        .map(|p| (*p - points[i0]).cross(&direction).length())
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+
+    if line_distance <= eps {
+        return Err("points are collinear");
+    }
+
+    let normal = (points[i1] - points[i0])
+        .cross(&(points[i2] - points[i0]))
+        .normalized();
+    let (i3, plane_distance) = points
+        .iter()
+        .map(|p| (*p - points[i0]).dot(&normal))
+        .enumerate()
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .unwrap();
+
+    if plane_distance.abs() <= eps {
+        return flat_hull(points, points[i0], normal);
+    }
+
+    let simplex = [i0, i1, i2, i3];
+    let centroid = simplex
+        .iter()
+        .fold(Vector3D::zero(), |sum, &i| sum + points[i])
+        / 4.;
+
+    let mut faces = Vec::new();
+    for [a, b, c] in [[i0, i1, i2], [i0, i1, i3], [i0, i2, i3], [i1, i2, i3]] {
+        let mut face = Face::new([a, b, c], points);
+        if face.distance(&centroid) > 0. {
+            face = Face::new([a, c, b], points);
+        }
+        faces.push(face);
+    }
+
+    let candidates: Vec<usize> = (0..points.len()).filter(|i| !simplex.contains(i)).collect();
+    assign_outside(&mut faces, &candidates, points, eps);
+
+    while let Some(face_index) = faces
+        .iter()
+        .position(|face| face.alive && !face.outside.is_empty())
+    {
+        let face = &faces[face_index];
+        let eye = *face
+            .outside
+            .iter()
+            .max_by(|a, b| {
+                face.distance(&points[**a])
+                    .total_cmp(&face.distance(&points[**b]))
+            })
+            .unwrap();
+
+        let visible: Vec<usize> = (0..faces.len())
+            .filter(|&i| faces[i].alive && faces[i].distance(&points[eye]) > eps)
+            .collect();
+
+        let mut edges = HashSet::new();
+        for &i in &visible {
+            edges.extend(faces[i].edges());
+        }
+
+        let mut orphans = Vec::new();
+        let mut horizon = Vec::new();
+        for &i in &visible {
+            for (a, b) in faces[i].edges() {
+                if !edges.contains(&(b, a)) {
+                    horizon.push((a, b));
+                }
+            }
+            faces[i].alive = false;
+            orphans.append(&mut faces[i].outside);
+        }
+        orphans.retain(|&i| i != eye);
+
+        let first_new = faces.len();
+        for (a, b) in horizon {
+            faces.push(Face::new([a, b, eye], points));
+        }
+
+        assign_outside(&mut faces[first_new..], &orphans, points, eps);
+    }
+
+    // compact the vertex list
+    let mut index_map = vec![usize::MAX; points.len()];
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::new();
+
+    for face in faces.iter().filter(|face| face.alive) {
+        let mut triangle = [0; 3];
+        for (j, &i) in face.vertices.iter().enumerate() {
+            if index_map[i] == usize::MAX {
+                index_map[i] = vertices.len();
+                vertices.push(points[i]);
+            }
+            triangle[j] = index_map[i];
+        }
+        triangles.push(triangle);
+    }
+
+    Ok(Mesh {
+        vertices,
+        triangles,
+    })
+}
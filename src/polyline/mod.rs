@@ -1,8 +1,10 @@
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+pub mod hull;
 mod polyline;
 mod polyline_2d;
+mod polyline_3d;
 
 pub use polyline::PolyLine2D;
 pub use polyline::PolyLine3D;
@@ -3,7 +3,11 @@ macro_rules! define_polyline {
         // use a module-namespace to shadow arg-enums
         pub mod $module {
 
+            use crate::io::json::{json_methods, JsonData};
+            use crate::parallel;
             use crate::vector::_vector::*;
+            use crate::vector::buffer::{self, Parameters};
+            use pyo3::buffer::PyBuffer;
             use pyo3::prelude::*;
             use pyo3::types::IntoPyDict;
 
@@ -17,23 +21,114 @@ macro_rules! define_polyline {
 
             #[derive(FromPyObject)]
             pub enum InitArgs {
+                Array(PyBuffer<f64>),
                 VecList(Vec<$vecClass>),
                 List(Vec<[f64; $vecClass::DIMENSIONS]>),
             }
 
             // Define PolyLine
-            #[pyclass]
+            #[pyclass(module = "euklid_rs.polyline")]
             #[derive(Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct $dst {
                 #[pyo3(get)]
                 pub nodes: Vec<$vecClass>,
             }
 
+            // json data: {"nodes": [[x, y], ...]}
+            impl JsonData for $dst {
+                fn json_data(&self, py: Python) -> PyObject {
+                    [("nodes", self.tolist())].into_py_dict(py).into()
+                }
+
+                fn from_json_data(data: &PyAny) -> PyResult<Self> {
+                    Ok(Self::from_list(data.get_item("nodes")?.extract()?))
+                }
+            }
+
+            json_methods!($dst);
+
+            impl $dst {
+                pub fn get(&self, ik: f64) -> $vecClass {
+                    let ik_floor = ik.floor() as i32;
+                    let mut i = match usize::try_from(ik_floor) {
+                        Ok(val) => val,
+                        Err(_) => 0,
+                    };
+
+                    let node_num = self.nodes.len();
+
+                    let diff: $vecClass;
+
+                    // catch direct (int) values
+                    if f64::abs(ik - i as f64) < 1e-10 && 0. <= ik && ik < node_num as f64 {
+                        return self.nodes[i];
+                    }
+
+                    if i >= node_num - 1 {
+                        i = node_num - 1;
+                        diff = self.nodes[i] - self.nodes[i - 1];
+                    } else {
+                        diff = self.nodes[i + 1] - self.nodes[i];
+                    }
+
+                    let k: f64 = ik - i as f64;
+                    let p1 = self.nodes[i];
+
+                    p1 + diff * k
+                }
+
+                /// raise a ValueError for lines without segments
+                pub fn check_segments(&self) -> PyResult<()> {
+                    if self.nodes.len() < 2 {
+                        return Err(pyo3::exceptions::PyValueError::new_err(
+                            "at least two nodes are needed",
+                        ));
+                    }
+
+                    Ok(())
+                }
+
+                /// segment vector at ik, the line needs at least two nodes
+                pub fn derivative(&self, ik: f64) -> $vecClass {
+                    // the segment containing ik, extended at both ends like get
+                    let last = self.nodes.len().saturating_sub(2) as f64;
+                    let i = ik.floor().clamp(0., last) as usize;
+
+                    self.nodes[i + 1] - self.nodes[i]
+                }
+
+                pub fn resample(&self, num_points: usize) -> Self {
+                    let mut nodes = Vec::new();
+                    let mut ik = 0.;
+                    let distance = self.get_length() / ((num_points - 1) as f64);
+
+                    nodes.push(self.get(ik));
+
+                    for _i in 0..num_points - 2 {
+                        ik = self.walk(ik, distance);
+                        nodes.push(self.get(ik));
+                    }
+
+                    nodes.push(self.nodes.last().unwrap().copy());
+
+                    Self { nodes }
+                }
+
+                /// a float64 array of shape (n, dimensions), backed by a new polyline
+                pub fn into_array(self, py: Python) -> PyResult<PyObject> {
+                    Self::nodes_array(PyCell::new(py, self)?)
+                }
+            }
+
             #[pymethods]
             impl $dst {
                 #[new]
-                pub fn new(nodes: InitArgs) -> Self {
-                    match nodes {
+                pub fn new(py: Python, nodes: InitArgs) -> PyResult<Self> {
+                    Ok(match nodes {
+                        InitArgs::Array(array) => {
+                            Self::from_list(buffer::rows(py, &array)?)
+                        }
                         InitArgs::VecList(nodes) => Self { nodes },
                         InitArgs::List(lst) => {
                             let mut nodes = Vec::new();
@@ -44,7 +139,7 @@ macro_rules! define_polyline {
                             }
                             Self { nodes }
                         }
-                    }
+                    })
                 }
 
                 #[staticmethod]
@@ -69,60 +164,102 @@ macro_rules! define_polyline {
                     result
                 }
 
-                pub fn __json__(&self) -> Py<PyAny> {
-                    let result = Python::with_gil(|py| {
-                        let mut nodes = Vec::new();
-
-                        for node in &self.nodes {
-                            nodes.push(node.tolist().to_vec());
-                        }
-
-                        let dict = [("nodes", nodes)].into_py_dict(py);
-                        dict.to_object(py)
-                    });
-                    result
-                    /*
-                    let gil = Python::acquire_gil();
-                    let py = gil.python();
-                    let mut nodes = Vec::new();
-
-                    for node in &self.nodes {
-                        nodes.push(node.tolist().to_vec());
+                /// get($self, ik)
+                /// --
+                ///
+                #[doc = concat!("get(self: ", stringify!($dst), ", ik: float) -> ", stringify!($vecClass))]
+                #[doc = "get a point at a (float) index. for a sequence or array of indices, a float64 array of shape (n, dimensions) is returned"]
+                #[pyo3(name = "get")]
+                fn get_py(slf: &PyCell<Self>, ik: Parameters) -> PyResult<PyObject> {
+                    let py = slf.py();
+
+                    match ik {
+                        Parameters::Scalar(ik) => Ok(slf.borrow().get(ik).into_py(py)),
+                        Parameters::Many(iks) => slf.borrow().get_many(py, Parameters::Many(iks)),
                     }
+                }
 
+                /// get_many($self, iks)
+                /// --
+                ///
+                #[doc = concat!("get_many(self: ", stringify!($dst), ", iks: Sequence[float]) -> memoryview")]
+                #[doc = "get the points at many (float) indices as a float64 array of shape (n, dimensions)"]
+                pub fn get_many(&self, py: Python, iks: Parameters) -> PyResult<PyObject> {
+                    let iks = iks.into_vec();
+                    let nodes = py.allow_threads(|| parallel::map(&iks, |&ik| self.get(ik)));
+
+                    Self { nodes }.into_array(py)
+                }
 
+                /// derivative($self, ik)
+                /// --
+                ///
+                #[doc = concat!("derivative(self: ", stringify!($dst), ", ik: float) -> ", stringify!($vecClass))]
+                #[doc = "get the derivative (the segment vector) at a (float) index"]
+                #[pyo3(name = "derivative")]
+                fn derivative_py(&self, ik: f64) -> PyResult<$vecClass> {
+                    self.check_segments()?;
 
-                    let dict = [("nodes", nodes)].into_py_dict(py);
-                    dict.to_object(py)*/
+                    Ok(self.derivative(ik))
                 }
 
-                pub fn get(&self, ik: f64) -> $vecClass {
-                    let ik_floor = ik.floor() as i32;
-                    let mut i = match usize::try_from(ik_floor) {
-                        Ok(val) => val,
-                        Err(_) => 0,
-                    };
+                /// derivative_many($self, iks)
+                /// --
+                ///
+                #[doc = concat!("derivative_many(self: ", stringify!($dst), ", iks: Sequence[float]) -> memoryview")]
+                #[doc = "get the derivatives at many (float) indices as a float64 array of shape (n, dimensions)"]
+                pub fn derivative_many(&self, py: Python, iks: Parameters) -> PyResult<PyObject> {
+                    self.check_segments()?;
+                    let iks = iks.into_vec();
+                    let nodes = py.allow_threads(|| parallel::map(&iks, |&ik| self.derivative(ik)));
+
+                    Self { nodes }.into_array(py)
+                }
 
-                    let node_num = self.nodes.len();
+                /// walk_many($self, start, distances)
+                /// --
+                ///
+                #[doc = concat!("walk_many(self: ", stringify!($dst), ", start: float, distances: Sequence[float]) -> List[float]")]
+                #[doc = "walk each distance along the line from start and return the (float) indices"]
+                pub fn walk_many(&self, py: Python, start: f64, distances: Parameters) -> Vec<f64> {
+                    let distances = distances.into_vec();
 
-                    let diff: $vecClass;
+                    py.allow_threads(|| parallel::map(&distances, |&distance| self.walk(start, distance)))
+                }
 
-                    // catch direct (int) values
-                    if f64::abs(ik - i as f64) < 1e-10 && 0. <= ik && ik < node_num as f64 {
-                        return self.nodes[i];
-                    }
+                /// a read-only float64 memoryview of shape (n, dimensions) on the nodes (no copy)
+                #[getter]
+                fn nodes_array(slf: &PyCell<Self>) -> PyResult<PyObject> {
+                    let py = slf.py();
 
-                    if i >= node_num - 1 {
-                        i = node_num - 1;
-                        diff = self.nodes[i] - self.nodes[i - 1];
-                    } else {
-                        diff = self.nodes[i + 1] - self.nodes[i];
-                    }
+                    Ok(py
+                        .import("builtins")?
+                        .getattr("memoryview")?
+                        .call1((slf,))?
+                        .into())
+                }
 
-                    let k: f64 = ik - i as f64;
-                    let p1 = self.nodes[i];
+                // read-only float64 buffer of shape (n, dimensions), eg. for numpy.asarray.
+                // in-place methods keep the allocation, exported views stay valid
+                unsafe fn __getbuffer__(
+                    slf: &PyCell<Self>,
+                    view: *mut pyo3::ffi::Py_buffer,
+                    flags: std::os::raw::c_int,
+                ) -> PyResult<()> {
+                    let line = slf.borrow();
+                    let data = line.nodes.as_ptr() as *const f64;
+
+                    buffer::export(
+                        view,
+                        flags,
+                        slf,
+                        data,
+                        &[line.nodes.len(), $vecClass::DIMENSIONS],
+                    )
+                }
 
-                    p1 + diff * k
+                unsafe fn __releasebuffer__(&self, view: *mut pyo3::ffi::Py_buffer) {
+                    buffer::release(view)
                 }
 
                 fn get_positions(&self, ik_start: f64, ik_end: f64) -> Vec<f64> {
@@ -230,7 +367,7 @@ macro_rules! define_polyline {
                     result
                 }
 
-                fn walk(&self, start: f64, distance: f64) -> f64 {
+                pub fn walk(&self, start: f64, distance: f64) -> f64 {
                     if f64::abs(distance) < 1e-8 {
                         return start;
                     }
@@ -278,21 +415,14 @@ macro_rules! define_polyline {
                             / current_segment_length;
                 }
 
-                fn resample(&self, num_points: usize) -> Self {
-                    let mut nodes = Vec::new();
-                    let mut ik = 0.;
-                    let distance = self.get_length() / ((num_points - 1) as f64);
-
-                    nodes.push(self.get(ik));
-
-                    for _i in 0..num_points - 2 {
-                        ik = self.walk(ik, distance);
-                        nodes.push(self.get(ik));
-                    }
-
-                    nodes.push(self.nodes.last().unwrap().copy());
-
-                    Self { nodes }
+                /// resample($self, num_points)
+                /// --
+                ///
+                #[doc = concat!("resample(self: ", stringify!($dst), ", num_points: int) -> ", stringify!($dst))]
+                #[doc = "get a line with num_points equidistant nodes"]
+                #[pyo3(name = "resample")]
+                fn resample_py(&self, py: Python, num_points: usize) -> Self {
+                    py.allow_threads(|| self.resample(num_points))
                 }
 
                 pub fn copy(&self) -> Self {
@@ -321,6 +451,64 @@ macro_rules! define_polyline {
                     Self { nodes }
                 }
 
+                /// scale_nodes($self, scale, origin=None)
+                /// --
+                ///
+                #[doc = concat!("scale_nodes(self: ", stringify!($dst), ", scale: Union[float, ", stringify!($vecClass), "], origin: Optional[", stringify!($vecClass), "] = None) -> ", stringify!($dst))]
+                /// scale the nodes relative to origin (defaults to the center of all nodes)
+                #[pyo3(signature = (scale, origin=None))]
+                pub fn scale_nodes(&self, scale: ScaleArgs, origin: Option<$vecClass>) -> Self {
+                    let scale_vec = match scale {
+                        ScaleArgs::Scalar(scale_lin) => $vecClass::scalar(scale_lin),
+                        ScaleArgs::Vec(scale_vec) => scale_vec,
+                    };
+
+                    let origin = origin.unwrap_or_else(|| {
+                        let mut center = $vecClass::zero();
+                        for node in &self.nodes {
+                            center += *node;
+                        }
+                        center / self.nodes.len() as f64
+                    });
+
+                    let nodes = self
+                        .nodes
+                        .iter()
+                        .map(|node| origin + (*node - origin).scale(&scale_vec))
+                        .collect();
+
+                    Self { nodes }
+                }
+
+                #[pyo3(signature = (scale, origin=None))]
+                pub fn scale_nodes_inplace(&mut self, scale: ScaleArgs, origin: Option<$vecClass>) {
+                    let nodes = self.scale_nodes(scale, origin).nodes;
+                    self.nodes.copy_from_slice(&nodes);
+                }
+
+                pub fn r#move(&self, offset: $vecClass) -> Self {
+                    let nodes = self.nodes.iter().map(|node| *node + offset).collect();
+
+                    Self { nodes }
+                }
+
+                pub fn move_inplace(&mut self, offset: $vecClass) {
+                    for node in self.nodes.iter_mut() {
+                        *node += offset;
+                    }
+                }
+
+                pub fn reverse(&self) -> Self {
+                    let mut nodes = self.nodes.clone();
+                    nodes.reverse();
+
+                    Self { nodes }
+                }
+
+                pub fn reverse_inplace(&mut self) {
+                    self.nodes.reverse();
+                }
+
                 fn mix(&self, other: &Self, amount: f64) -> PyResult<Self> {
                     if other.nodes.len() != self.nodes.len() {
                         return Err(pyo3::exceptions::PyValueError::new_err("shit"));
@@ -1,6 +1,10 @@
-use crate::polyline::polyline::polyline_2d::{InitArgs, PolyLine2D};
+use crate::boundary::{BoundingBox2D, OrientedBox2D};
+use crate::parallel;
+use crate::polyline::hull;
+use crate::polyline::polyline::polyline_2d::PolyLine2D;
 extern crate pyo3;
 use crate::vector::_vector::*;
+use crate::vector::Transformation2D;
 use pyo3::prelude::*;
 
 const CUT_TOLERANCE: f64 = 1e-5;
@@ -19,7 +23,7 @@ impl PolyLine2D {
             normals.push(normal);
         }
 
-        Self::new(InitArgs::VecList(normals))
+        Self { nodes: normals }
     }
 
     fn normvectors(&self) -> Self {
@@ -45,7 +49,7 @@ impl PolyLine2D {
 
         normvectors.push(segment_normals.last().unwrap().copy());
 
-        Self::new(InitArgs::VecList(normvectors))
+        Self { nodes: normvectors }
     }
 
     fn offset_simple(&self, amount: f64) -> Self {
@@ -182,7 +186,125 @@ impl PolyLine2D {
         }
     }
 
-    fn cut_with_polyline(&self, other: &Self) -> Vec<[f64; 2]> {
+    /// cut_with_polyline($self, other)
+    /// --
+    ///
+    /// cut_with_polyline(self: PolyLine2D, other: PolyLine2D) -> List[Tuple[float, float]]
+    /// get the (float) indices of all cuts with another line on both lines
+    #[pyo3(name = "cut_with_polyline")]
+    fn cut_with_polyline_py(&self, py: Python, other: &Self) -> Vec<[f64; 2]> {
+        py.allow_threads(|| self.cut_with_polyline(other))
+    }
+
+    /// cut_with_polylines($self, others)
+    /// --
+    ///
+    /// cut_with_polylines(self: PolyLine2D, others: List[PolyLine2D]) -> List[List[Tuple[float, float]]]
+    /// cut_with_polyline for many lines at once
+    fn cut_with_polylines(&self, py: Python, others: Vec<Self>) -> Vec<Vec<[f64; 2]>> {
+        py.allow_threads(|| parallel::map(&others, |other| self.cut_with_polyline(other)))
+    }
+
+    /// fix_errors($self)
+    /// --
+    ///
+    /// fix_errors(self: PolyLine2D) -> PolyLine2D
+    /// remove self-intersections (loops) and zero-length segments
+    #[pyo3(name = "fix_errors")]
+    fn fix_errors_py(&self, py: Python) -> Self {
+        py.allow_threads(|| self.fix_errors())
+    }
+
+    /// offset_many(lines, amount)
+    /// --
+    ///
+    /// offset_many(lines: List[PolyLine2D], amount: float) -> List[PolyLine2D]
+    /// offset many lines (eg. the outlines of panels) at once
+    #[staticmethod]
+    fn offset_many(py: Python, lines: Vec<Self>, amount: f64) -> Vec<Self> {
+        py.allow_threads(|| parallel::map(&lines, |line| line.offset(amount)))
+    }
+
+    fn boundary(&self) -> [f64; 4] {
+        let mut min_x = f64::INFINITY;
+        let mut min_y = f64::INFINITY;
+        let mut max_x = -f64::INFINITY;
+        let mut max_y = -f64::INFINITY;
+
+        for &node in &self.nodes {
+            min_x = f64::min(min_x, node.v[0]);
+            min_y = f64::min(min_y, node.v[1]);
+            max_x = f64::max(max_x, node.v[0]);
+            max_y = f64::max(max_y, node.v[1]);
+        }
+
+        [min_x, min_y, max_x, max_y]
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox2D {
+        BoundingBox2D::from_nodes(&self.nodes)
+    }
+
+    /// min_area_box($self)
+    /// --
+    ///
+    /// min_area_box(self: euklid_rs.polyline.PolyLine2D) -> euklid_rs.boundary.OrientedBox2D
+    /// get the oriented bounding rectangle with the smallest area
+    pub fn min_area_box(&self) -> PyResult<OrientedBox2D> {
+        OrientedBox2D::min_area(&self.nodes).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// rotate($self, angle, origin=None)
+    /// --
+    ///
+    /// rotate(self: euklid_rs.polyline.PolyLine2D, angle: float, origin: Optional[euklid_rs.vector.Vector2D] = None) -> euklid_rs.polyline.PolyLine2D
+    /// rotate counter-clockwise about origin (defaults to [0, 0])
+    #[pyo3(signature = (angle, origin=None))]
+    pub fn rotate(&self, angle: f64, origin: Option<Vector2D>) -> Self {
+        Transformation2D::rotation(angle, origin).apply_polyline(self)
+    }
+
+    #[pyo3(signature = (angle, origin=None))]
+    pub fn rotate_inplace(&mut self, angle: f64, origin: Option<Vector2D>) {
+        let nodes = self.rotate(angle, origin).nodes;
+        self.nodes.copy_from_slice(&nodes);
+    }
+
+    /// mirror($self, p1, p2)
+    /// --
+    ///
+    /// mirror(self: euklid_rs.polyline.PolyLine2D, p1: euklid_rs.vector.Vector2D, p2: euklid_rs.vector.Vector2D) -> euklid_rs.polyline.PolyLine2D
+    /// mirror across the line through p1 and p2, raises ValueError for p1 == p2
+    pub fn mirror(&self, p1: Vector2D, p2: Vector2D) -> PyResult<Self> {
+        Ok(Transformation2D::mirror(p1, p2)?.apply_polyline(self))
+    }
+
+    pub fn mirror_inplace(&mut self, p1: Vector2D, p2: Vector2D) -> PyResult<()> {
+        let nodes = self.mirror(p1, p2)?.nodes;
+        self.nodes.copy_from_slice(&nodes);
+
+        Ok(())
+    }
+
+    /// convex_hull($self)
+    /// --
+    ///
+    /// convex_hull(self: euklid_rs.polyline.PolyLine2D) -> euklid_rs.polyline.PolyLine2D
+    /// get the convex hull as a closed, counter-clockwise PolyLine2D
+    pub fn convex_hull(&self) -> PyResult<Self> {
+        let mut nodes =
+            hull::monotone_chain(&self.nodes).map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        if let Some(first) = nodes.first() {
+            nodes.push(*first);
+        }
+
+        Ok(Self { nodes })
+    }
+}
+
+impl PolyLine2D {
+    pub fn cut_with_polyline(&self, other: &Self) -> Vec<[f64; 2]> {
         let mut result = Vec::new();
 
         for i in 0..other.nodes.len() - 1 {
@@ -202,7 +324,7 @@ impl PolyLine2D {
         result
     }
 
-    fn fix_errors(&self) -> Self {
+    pub fn fix_errors(&self) -> Self {
         let length = self.__len__();
 
         if length < 4 {
@@ -257,22 +379,6 @@ impl PolyLine2D {
 
         Self { nodes: new_nodes }
     }
-
-    fn boundary(&self) -> [f64; 4] {
-        let mut min_x = f64::INFINITY;
-        let mut min_y = f64::INFINITY;
-        let mut max_x = -f64::INFINITY;
-        let mut max_y = -f64::INFINITY;
-
-        for &node in &self.nodes {
-            min_x = f64::min(min_x, node.v[0]);
-            min_y = f64::min(min_y, node.v[0]);
-            max_x = f64::max(max_x, node.v[0]);
-            max_y = f64::max(max_y, node.v[0]);
-        }
-
-        [min_x, min_y, max_x, max_y]
-    }
 }
 
 /*
@@ -329,33 +435,6 @@ bool PolyLine2D::contains(const Vector2D& p1) const {
     return false;
 }
 
-PolyLine2D PolyLine2D::mirror(Vector2D& p1, Vector2D& p2) const {
-    let diff = p1 - p2;
-    let normvector = Vector2D(-diff[1], diff[0]).normalized();
-    std::vector<std::shared_ptr<Vector2D>> result;
-
-    for (let node: self.nodes) {
-        result.push_back(std::make_shared<Vector2D>(
-            *node - normvector * (2 * normvector.dot(*node-p1))
-        ));
-    }
-
-    return PolyLine2D(result);
-}
-
-
-PolyLine2D PolyLine2D::rotate(double radians, Vector2D& origin) const {
-    std::vector<std::shared_ptr<Vector2D>> new_nodes;
-    let rotation = Rotation2D(radians);
-
-    for (let node: self.nodes) {
-        new_nodes.push_back(std::make_shared<Vector2D>(origin + rotation.apply(*node - origin)));
-    }
-
-    return PolyLine2D(new_nodes);
-}
-
-
 PolyLine3D PolyLine2D::to_3d() const {
     std::vector<std::shared_ptr<Vector3D>> new_nodes;
 
@@ -0,0 +1,74 @@
+use crate::boundary::{BoundingBox3D, OrientedBox3D};
+use crate::mesh::Mesh;
+use crate::plane::Plane;
+use crate::polyline::hull;
+use crate::polyline::polyline::polyline_3d::PolyLine3D;
+use crate::vector::_vector::*;
+use crate::vector::Transformation;
+use pyo3::prelude::*;
+
+#[pymethods]
+impl PolyLine3D {
+    /// convex_hull($self)
+    /// --
+    ///
+    /// convex_hull(self: euklid_rs.polyline.PolyLine3D) -> euklid_rs.mesh.Mesh
+    /// get the convex hull of all nodes as a triangle mesh
+    pub fn convex_hull(&self) -> PyResult<Mesh> {
+        hull::quickhull(&self.nodes).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox3D {
+        BoundingBox3D::from_nodes(&self.nodes)
+    }
+
+    /// pca_box($self)
+    /// --
+    ///
+    /// pca_box(self: euklid_rs.polyline.PolyLine3D) -> euklid_rs.boundary.OrientedBox3D
+    /// get a bounding box aligned to the principal axes of the nodes
+    pub fn pca_box(&self) -> PyResult<OrientedBox3D> {
+        OrientedBox3D::pca(&self.nodes).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// min_volume_box($self)
+    /// --
+    ///
+    /// min_volume_box(self: euklid_rs.polyline.PolyLine3D) -> euklid_rs.boundary.OrientedBox3D
+    /// get an (approximated) minimum-volume bounding box
+    pub fn min_volume_box(&self) -> PyResult<OrientedBox3D> {
+        OrientedBox3D::min_volume(&self.nodes).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// rotate($self, angle, axis, origin=None)
+    /// --
+    ///
+    /// rotate(self: euklid_rs.polyline.PolyLine3D, angle: float, axis: euklid_rs.vector.Vector3D, origin: Optional[euklid_rs.vector.Vector3D] = None) -> euklid_rs.polyline.PolyLine3D
+    /// rotate about an axis through origin (defaults to [0, 0, 0])
+    #[pyo3(signature = (angle, axis, origin=None))]
+    pub fn rotate(&self, angle: f64, axis: Vector3D, origin: Option<Vector3D>) -> Self {
+        Transformation::rotation(angle, &axis, origin).apply_polyline(self)
+    }
+
+    #[pyo3(signature = (angle, axis, origin=None))]
+    pub fn rotate_inplace(&mut self, angle: f64, axis: Vector3D, origin: Option<Vector3D>) {
+        let nodes = self.rotate(angle, axis, origin).nodes;
+        self.nodes.copy_from_slice(&nodes);
+    }
+
+    /// mirror($self, plane)
+    /// --
+    ///
+    /// mirror(self: euklid_rs.polyline.PolyLine3D, plane: euklid_rs.plane.Plane) -> euklid_rs.polyline.PolyLine3D
+    /// mirror across a plane, raises ValueError for a degenerate plane
+    pub fn mirror(&self, plane: &Plane) -> PyResult<Self> {
+        plane.mirror_polyline(self)
+    }
+
+    pub fn mirror_inplace(&mut self, plane: &Plane) -> PyResult<()> {
+        let nodes = self.mirror(plane)?.nodes;
+        self.nodes.copy_from_slice(&nodes);
+
+        Ok(())
+    }
+}
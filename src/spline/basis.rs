@@ -1,3 +1,4 @@
+#[derive(Clone)]
 pub struct BezierBasis {
     bases: Vec<(f64, i32)>,
 }
@@ -6,6 +7,7 @@ pub trait Basis {
     fn new(size: usize) -> Self;
     fn dimension(&self) -> usize;
     fn get(&mut self, index: usize, x: f64) -> f64;
+    fn derivative(&mut self, index: usize, x: f64) -> f64;
     fn copy(&self) -> Self;
 }
 
@@ -60,14 +62,39 @@ impl Basis for BezierBasis {
 
         k * x.powi(i) * (1. - x).powi(size as i32 - 1 - i)
     }
+
+    fn derivative(&mut self, index: usize, x: f64) -> f64 {
+        let size = self.bases.len();
+        if index >= size {
+            panic!("invalid index")
+        }
+
+        let (k, i) = self.bases[index];
+        let m = size as i32 - 1 - i;
+        let mut result = 0.;
+
+        if i > 0 {
+            result += i as f64 * x.powi(i - 1) * (1. - x).powi(m);
+        }
+        if m > 0 {
+            result -= m as f64 * x.powi(i) * (1. - x).powi(m - 1);
+        }
+
+        k * result
+    }
 }
 
+#[derive(Clone)]
 pub struct BSplineBasis<const DEGREE: usize> {
     dimension: usize,
     knots: Vec<f64>,
 }
 
 impl<const DEGREE: usize> BSplineBasis<DEGREE> {
+    pub fn knots(&self) -> &[f64] {
+        &self.knots
+    }
+
     fn get_basis(&self, degree: usize, index: usize, x: f64) -> f64 {
         if degree == 0 {
             if self.knots[index] < x && x <= self.knots[index + 1] {
@@ -152,6 +179,25 @@ impl<const DEGREE: usize> Basis for BSplineBasis<DEGREE> {
     fn get(&mut self, index: usize, x: f64) -> f64 {
         self.get_basis(DEGREE, index, x)
     }
+
+    fn derivative(&mut self, index: usize, x: f64) -> f64 {
+        // the knot intervals are closed to the right, take the start as a limit
+        let x = x.max(1e-10);
+        let degree = DEGREE as f64;
+        let mut result = 0.;
+
+        let bottom = self.knots[index + DEGREE] - self.knots[index];
+        if bottom > 1e-8 {
+            result += degree / bottom * self.get_basis(DEGREE - 1, index, x);
+        }
+
+        let bottom = self.knots[index + DEGREE + 1] - self.knots[index + 1];
+        if bottom > 1e-8 {
+            result -= degree / bottom * self.get_basis(DEGREE - 1, index + 1, x);
+        }
+
+        result
+    }
 }
 
 /*
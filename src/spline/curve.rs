@@ -1,18 +1,23 @@
 use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
 
-use crate::polyline::PolyLine2D;
+use crate::io::json::{json_methods, JsonData};
+use crate::parallel;
+use crate::polyline::{PolyLine2D, PolyLine3D};
 use crate::spline::basis::*;
-use crate::vector::Vector2D;
+use crate::vector::buffer::Parameters;
+use crate::vector::{Vector2D, Vector3D};
 
 macro_rules! define_curve {
-    ($dst: ident, $basis: ident) => {
+    ($dst: ident, $basis: ident, $polyline: ident, $vecClass: ident) => {
         // use a module-namespace to shadow arg-enums
 
         // Define PolyLine
-        #[pyclass]
+        #[pyclass(module = "euklid_rs.spline")]
+        #[derive(Clone)]
         pub struct $dst {
             #[pyo3(get)]
-            pub controlpoints: PolyLine2D,
+            pub controlpoints: $polyline,
 
             basis: $basis,
         }
@@ -20,7 +25,7 @@ macro_rules! define_curve {
         #[pymethods]
         impl $dst {
             #[new]
-            pub fn new(controlpoints: PolyLine2D) -> Self {
+            pub fn new(controlpoints: $polyline) -> Self {
                 let basis = $basis::new(controlpoints.__len__());
 
                 Self {
@@ -29,14 +34,14 @@ macro_rules! define_curve {
                 }
             }
 
-            pub fn set_controlpoints(&mut self, controlpoints: PolyLine2D) {
+            pub fn set_controlpoints(&mut self, controlpoints: $polyline) {
                 if self.basis.dimension() != controlpoints.__len__() {
                     self.basis = $basis::new(controlpoints.__len__());
                 }
             }
 
-            pub fn get(&mut self, x: f64) -> Vector2D {
-                let mut result = Vector2D::zero();
+            pub fn get(&mut self, x: f64) -> $vecClass {
+                let mut result = $vecClass::zero();
 
                 for i in 0..self.basis.dimension() {
                     let basis_factor = self.basis.get(i, x);
@@ -53,14 +58,92 @@ macro_rules! define_curve {
                 result
             }
 
-            pub fn get_sequence(&mut self, n: usize) -> PolyLine2D {
+            /// derivative($self, x)
+            /// --
+            ///
+            #[doc = concat!("derivative(self: ", stringify!($dst), ", x: float) -> ", stringify!($vecClass))]
+            #[doc = "get the derivative with respect to the parameter x"]
+            pub fn derivative(&mut self, x: f64) -> $vecClass {
+                let mut result = $vecClass::zero();
+
+                for i in 0..self.basis.dimension() {
+                    result += self.controlpoints.nodes[i] * self.basis.derivative(i, x);
+                }
+
+                result
+            }
+
+            /// get_many($self, xs)
+            /// --
+            ///
+            #[doc = concat!("get_many(self: ", stringify!($dst), ", xs: Sequence[float]) -> memoryview")]
+            #[doc = "get the points at many parameters as a float64 array of shape (n, dimensions)"]
+            pub fn get_many(&self, py: Python, xs: Parameters) -> PyResult<PyObject> {
+                let xs = xs.into_vec();
+                let nodes = py.allow_threads(|| {
+                    parallel::map_init(&xs, || self.clone(), |curve, &x| curve.get(x))
+                });
+
+                $polyline { nodes }.into_array(py)
+            }
+
+            /// derivative_many($self, xs)
+            /// --
+            ///
+            #[doc = concat!("derivative_many(self: ", stringify!($dst), ", xs: Sequence[float]) -> memoryview")]
+            #[doc = "get the derivatives at many parameters as a float64 array of shape (n, dimensions)"]
+            pub fn derivative_many(&self, py: Python, xs: Parameters) -> PyResult<PyObject> {
+                let xs = xs.into_vec();
+                let nodes = py.allow_threads(|| {
+                    parallel::map_init(&xs, || self.clone(), |curve, &x| curve.derivative(x))
+                });
+
+                $polyline { nodes }.into_array(py)
+            }
+
+            /// walk_many($self, start, distances, segments=1000)
+            /// --
+            ///
+            #[doc = concat!("walk_many(self: ", stringify!($dst), ", start: float, distances: Sequence[float], segments: int=1000) -> List[float]")]
+            #[doc = "walk each distance along the curve from the parameter start and return the parameters."]
+            #[doc = "the curve length is approximated by a polyline of the given number of segments"]
+            #[pyo3(signature = (start, distances, segments=1000))]
+            pub fn walk_many(
+                &self,
+                py: Python,
+                start: f64,
+                distances: Parameters,
+                segments: usize,
+            ) -> PyResult<Vec<f64>> {
+                if segments == 0 {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "segments must be at least 1",
+                    ));
+                }
+                let distances = distances.into_vec();
+
+                Ok(py.allow_threads(|| {
+                    let xs: Vec<f64> = (0..=segments)
+                        .map(|i| i as f64 / segments as f64)
+                        .collect();
+                    let nodes = parallel::map_init(&xs, || self.clone(), |curve, &x| curve.get(x));
+                    let line = $polyline { nodes };
+                    let start = start * segments as f64;
+
+                    parallel::map(&distances, |&distance| {
+                        line.walk(start, distance) / segments as f64
+                    })
+                }))
+            }
+
+            pub fn get_sequence(&mut self, n: usize) -> $polyline {
                 let mut nodes = Vec::new();
 
                 for i in 0..n {
                     nodes.push(self.get(i as f64 / n as f64));
                 }
 
-                PolyLine2D { nodes }
+                $polyline { nodes }
             }
 
             pub fn copy(&self) -> Self {
@@ -70,9 +153,67 @@ macro_rules! define_curve {
                 }
             }
         }
+
+        // json data: {"controlpoints": {"nodes": [...]}}, the basis is derived
+        impl JsonData for $dst {
+            fn json_data(&self, py: Python) -> PyObject {
+                [("controlpoints", self.controlpoints.json_data(py))]
+                    .into_py_dict(py)
+                    .into()
+            }
+
+            fn from_json_data(data: &PyAny) -> PyResult<Self> {
+                Ok(Self::new(JsonData::from_json_data(
+                    data.get_item("controlpoints")?,
+                )?))
+            }
+        }
+
+        json_methods!($dst);
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $dst {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeStruct;
+
+                let mut state = serializer.serialize_struct(stringify!($dst), 1)?;
+                state.serialize_field("controlpoints", &self.controlpoints)?;
+                state.end()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $dst {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(serde::Deserialize)]
+                struct Data {
+                    controlpoints: $polyline,
+                }
+
+                let data = Data::deserialize(deserializer)?;
+                Ok(Self::new(data.controlpoints))
+            }
+        }
     };
 }
 
-define_curve!(BezierCurve, BezierBasis);
 type BSpline3Basis = BSplineBasis<3>;
-define_curve!(BSplineCurve3, BSpline3Basis);
+
+define_curve!(BezierCurve, BezierBasis, PolyLine2D, Vector2D);
+define_curve!(BSplineCurve3, BSpline3Basis, PolyLine2D, Vector2D);
+
+impl BSplineCurve3 {
+    pub fn knots(&self) -> &[f64] {
+        self.basis.knots()
+    }
+}
+
+define_curve!(BezierCurve3D, BezierBasis, PolyLine3D, Vector3D);
+// BSplineCurve3 (degree 3) in 3D
+define_curve!(BSplineCurve3_3D, BSpline3Basis, PolyLine3D, Vector3D);
+
+impl BSplineCurve3_3D {
+    pub fn knots(&self) -> &[f64] {
+        self.basis.knots()
+    }
+}
@@ -5,11 +5,15 @@ use pyo3::wrap_pymodule;
 mod basis;
 mod curve;
 
+pub use curve::{BSplineCurve3, BSplineCurve3_3D, BezierCurve, BezierCurve3D};
+
 pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
     #[pymodule]
     fn spline(_py: Python, m: &PyModule) -> PyResult<()> {
-        m.add_class::<curve::BezierCurve>()?;
-        m.add_class::<curve::BSplineCurve3>()?;
+        m.add_class::<BezierCurve>()?;
+        m.add_class::<BSplineCurve3>()?;
+        m.add_class::<BezierCurve3D>()?;
+        m.add_class::<BSplineCurve3_3D>()?;
         Ok(())
     }
 
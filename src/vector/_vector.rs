@@ -1,17 +1,34 @@
 use nalgebra as na;
 use pyo3::exceptions::PyIndexError;
 use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
 use std::convert::TryFrom;
 use std::ops;
 
-#[pyclass]
+use crate::io::json::{json_methods, pickle_methods, JsonData};
+
+#[pyclass(module = "euklid_rs.vector")]
 #[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "[f64; 2]", into = "[f64; 2]")
+)]
+// repr(C): a slice of vectors is a contiguous (n, N) float64 array (buffer protocol)
+#[repr(C)]
 pub struct Vector2D {
     pub v: na::Vector2<f64>,
 }
 
-#[pyclass]
+#[pyclass(module = "euklid_rs.vector")]
 #[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "[f64; 3]", into = "[f64; 3]")
+)]
+// repr(C): a slice of vectors is a contiguous (n, N) float64 array (buffer protocol)
+#[repr(C)]
 pub struct Vector3D {
     pub v: na::Vector3<f64>,
 }
@@ -26,6 +43,30 @@ pub trait Vector {
 
 macro_rules! pyvector {
     ($dst: ident) => {
+        impl From<[f64; $dst::DIMENSIONS]> for $dst {
+            fn from(v: [f64; $dst::DIMENSIONS]) -> Self {
+                Self::__new__(v)
+            }
+        }
+
+        impl From<$dst> for [f64; $dst::DIMENSIONS] {
+            fn from(vec: $dst) -> Self {
+                vec.v.into()
+            }
+        }
+
+        // json data: list of coordinates
+        impl JsonData for $dst {
+            fn json_data(&self, py: Python) -> PyObject {
+                self.tolist().into_py(py)
+            }
+
+            fn from_json_data(data: &PyAny) -> PyResult<Self> {
+                Ok(Self::__new__(data.extract()?))
+            }
+        }
+
+        json_methods!($dst);
 
         impl Vector for $dst {
             fn copy(&self) -> Self {
@@ -143,8 +184,19 @@ macro_rules! pyvector {
                 self.v.into()
             }
 
-            fn __json__(&self) -> [f64; Self::DIMENSIONS] {
-                self.v.into()
+            // read-only float64 buffer of shape (DIMENSIONS,), eg. for numpy.asarray
+            unsafe fn __getbuffer__(
+                slf: &PyCell<Self>,
+                view: *mut pyo3::ffi::Py_buffer,
+                flags: std::os::raw::c_int,
+            ) -> PyResult<()> {
+                let data = slf.borrow().v.as_ptr();
+
+                crate::vector::buffer::export(view, flags, slf, data, &[Self::DIMENSIONS])
+            }
+
+            unsafe fn __releasebuffer__(&self, view: *mut pyo3::ffi::Py_buffer) {
+                crate::vector::buffer::release(view)
             }
 
             fn __repr__(&self) -> PyResult<String> {
@@ -295,7 +347,7 @@ impl Vector3D {
     }
 }
 
-#[pyclass]
+#[pyclass(module = "euklid_rs.vector")]
 #[derive(Clone, Copy)]
 pub struct CutResult {
     #[pyo3(get)]
@@ -316,6 +368,29 @@ impl CutResult {
     }
 }
 
+// json data: {"ik_1": ik, "ik_2": ik, "point": [...]}
+impl JsonData for CutResult {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("ik_1", self.ik_1.into_py(py)),
+            ("ik_2", self.ik_2.into_py(py)),
+            ("point", self.point.json_data(py)),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            ik_1: data.get_item("ik_1")?.extract()?,
+            ik_2: data.get_item("ik_2")?.extract()?,
+            point: JsonData::from_json_data(data.get_item("point")?)?,
+        })
+    }
+}
+
+pickle_methods!(CutResult);
+
 pub fn cut_2d(
     l1_p1: &Vector2D,
     l1_p2: &Vector2D,
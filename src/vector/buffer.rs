@@ -0,0 +1,142 @@
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::{PyBufferError, PyValueError};
+use pyo3::ffi;
+use pyo3::prelude::*;
+use pyo3::AsPyPointer;
+use std::os::raw::{c_char, c_int, c_void};
+
+// shape and strides of an exported view, freed in release
+struct Layout {
+    shape: Vec<ffi::Py_ssize_t>,
+    strides: Vec<ffi::Py_ssize_t>,
+}
+
+/// Export `shape`-sized float64 data owned by `owner` as a read-only, C-contiguous buffer.
+///
+/// # Safety
+/// `view` must be a valid Py_buffer and `data` must stay valid (and must not be moved)
+/// as long as `owner` is alive.
+pub unsafe fn export(
+    view: *mut ffi::Py_buffer,
+    flags: c_int,
+    owner: &PyAny,
+    data: *const f64,
+    shape: &[usize],
+) -> PyResult<()> {
+    if view.is_null() {
+        return Err(PyBufferError::new_err("View is null"));
+    }
+
+    if (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+        return Err(PyBufferError::new_err("Object is not writable"));
+    }
+
+    let itemsize = std::mem::size_of::<f64>();
+    let count: usize = shape.iter().product();
+
+    let mut strides = vec![itemsize as ffi::Py_ssize_t; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1] as ffi::Py_ssize_t;
+    }
+
+    let layout = Box::new(Layout {
+        shape: shape.iter().map(|&size| size as ffi::Py_ssize_t).collect(),
+        strides,
+    });
+
+    (*view).obj = ffi::_Py_NewRef(owner.as_ptr());
+    (*view).buf = data as *mut c_void;
+    (*view).len = (count * itemsize) as ffi::Py_ssize_t;
+    (*view).readonly = 1;
+    (*view).itemsize = itemsize as ffi::Py_ssize_t;
+
+    (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+        c"d".as_ptr() as *mut c_char
+    } else {
+        std::ptr::null_mut()
+    };
+
+    (*view).ndim = shape.len() as c_int;
+    (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+        layout.shape.as_ptr() as *mut _
+    } else {
+        std::ptr::null_mut()
+    };
+    (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+        layout.strides.as_ptr() as *mut _
+    } else {
+        std::ptr::null_mut()
+    };
+
+    (*view).suboffsets = std::ptr::null_mut();
+    (*view).internal = Box::into_raw(layout) as *mut c_void;
+
+    Ok(())
+}
+
+/// Free the layout of a view created by export.
+///
+/// # Safety
+/// `view` must have been filled by export.
+pub unsafe fn release(view: *mut ffi::Py_buffer) {
+    if !(*view).internal.is_null() {
+        drop(Box::from_raw((*view).internal as *mut Layout));
+        (*view).internal = std::ptr::null_mut();
+    }
+}
+
+/// read rows of an (n, N) float64 buffer (any memory layout)
+pub fn rows<const N: usize>(py: Python, buffer: &PyBuffer<f64>) -> PyResult<Vec<[f64; N]>> {
+    let shape = buffer.shape();
+
+    if shape.len() != 2 || shape[1] != N {
+        return Err(PyValueError::new_err(format!(
+            "expected an array of shape (n, {}), got {:?}",
+            N, shape
+        )));
+    }
+
+    Ok(buffer
+        .to_vec(py)?
+        .chunks_exact(N)
+        .map(|row| row.try_into().unwrap())
+        .collect())
+}
+
+/// a single parameter or many of them (a sequence or a one-dimensional float64 buffer)
+pub enum Parameters {
+    Scalar(f64),
+    Many(Vec<f64>),
+}
+
+impl Parameters {
+    pub fn into_vec(self) -> Vec<f64> {
+        match self {
+            Parameters::Scalar(value) => vec![value],
+            Parameters::Many(values) => values,
+        }
+    }
+}
+
+impl<'source> FromPyObject<'source> for Parameters {
+    fn extract(obj: &'source PyAny) -> PyResult<Self> {
+        if let Ok(buffer) = PyBuffer::<f64>::get(obj) {
+            let values = buffer.to_vec(obj.py())?;
+
+            return match buffer.dimensions() {
+                0 => Ok(Parameters::Scalar(values[0])),
+                1 => Ok(Parameters::Many(values)),
+                _ => Err(PyValueError::new_err(format!(
+                    "expected a one-dimensional array, got shape {:?}",
+                    buffer.shape()
+                ))),
+            };
+        }
+
+        if let Ok(value) = obj.extract::<f64>() {
+            return Ok(Parameters::Scalar(value));
+        }
+
+        Ok(Parameters::Many(obj.extract()?))
+    }
+}
@@ -3,17 +3,26 @@ use pyo3::types::PyDict;
 use pyo3::wrap_pymodule;
 
 pub mod _vector;
+pub mod buffer;
+pub mod quaternion;
 pub mod transform;
+pub mod transform_2d;
 
-pub use _vector::{Vector2D, Vector3D};
+pub use _vector::{CutResult, Vector2D, Vector3D};
+pub use quaternion::Quaternion;
 pub use transform::Transformation;
+pub use transform_2d::{Rotation2D, Transformation2D};
 
 pub fn register(_py: Python, m: &PyModule) -> PyResult<()> {
     #[pymodule]
     fn vector(_py: Python, m: &PyModule) -> PyResult<()> {
         m.add_class::<Vector2D>()?;
         m.add_class::<Vector3D>()?;
+        m.add_class::<Quaternion>()?;
         m.add_class::<Transformation>()?;
+        m.add_class::<Transformation2D>()?;
+        m.add_class::<Rotation2D>()?;
+        m.add_class::<CutResult>()?;
         Ok(())
     }
 
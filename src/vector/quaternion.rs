@@ -0,0 +1,442 @@
+use nalgebra as na;
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+
+use crate::io::json::{json_methods, JsonData};
+use crate::vector::_vector;
+use crate::vector::Transformation;
+
+/// Rotation in 3D, represented by a unit quaternion
+#[pyclass(module = "euklid_rs.vector")]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "QuaternionData", into = "QuaternionData")
+)]
+pub struct Quaternion {
+    pub q: na::UnitQuaternion<f64>,
+}
+
+fn axis_rotation(axis: usize, angle: f64) -> na::UnitQuaternion<f64> {
+    let axis = match axis {
+        0 => na::Vector3::x_axis(),
+        1 => na::Vector3::y_axis(),
+        _ => na::Vector3::z_axis(),
+    };
+
+    na::UnitQuaternion::from_axis_angle(&axis, angle)
+}
+
+// parse an axis order like "xyz" into axis indices and a flag for intrinsic rotations
+fn parse_order(order: &str) -> PyResult<([usize; 3], bool)> {
+    let axes: Vec<char> = order.chars().collect();
+
+    if axes.len() != 3
+        || !(axes.iter().all(|c| "xyz".contains(*c)) || axes.iter().all(|c| "XYZ".contains(*c)))
+    {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "order must be three axes, lowercase (extrinsic) or uppercase (intrinsic)",
+        ));
+    }
+
+    let indices: Vec<usize> = axes
+        .iter()
+        .map(|c| match c.to_ascii_lowercase() {
+            'x' => 0,
+            'y' => 1,
+            _ => 2,
+        })
+        .collect();
+
+    if indices[0] == indices[1] || indices[1] == indices[2] {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "consecutive axes must differ",
+        ));
+    }
+
+    Ok((
+        [indices[0], indices[1], indices[2]],
+        axes[0].is_ascii_uppercase(),
+    ))
+}
+
+fn normalize_angle(angle: f64) -> f64 {
+    let mut angle = angle % (2. * std::f64::consts::PI);
+
+    if angle > std::f64::consts::PI {
+        angle -= 2. * std::f64::consts::PI;
+    } else if angle <= -std::f64::consts::PI {
+        angle += 2. * std::f64::consts::PI;
+    }
+
+    angle
+}
+
+impl Quaternion {
+    pub fn new(q: na::UnitQuaternion<f64>) -> Self {
+        Self { q }
+    }
+}
+
+#[pymethods]
+impl Quaternion {
+    pub const SMALL_N: f64 = 1e-8;
+
+    #[new]
+    pub fn __new__(w: f64, x: f64, y: f64, z: f64) -> PyResult<Self> {
+        let q = na::Quaternion::new(w, x, y, z);
+
+        match na::UnitQuaternion::try_new(q, Self::SMALL_N) {
+            Some(q) => Ok(Self { q }),
+            None => Err(pyo3::exceptions::PyValueError::new_err(
+                "quaternion must not be zero",
+            )),
+        }
+    }
+
+    #[staticmethod]
+    pub fn identity() -> Self {
+        Self::new(na::UnitQuaternion::identity())
+    }
+
+    /// from_axis_angle(axis, angle)
+    /// --
+    ///
+    /// from_axis_angle(axis: euklid_rs.vector.Vector3D, angle: float) -> euklid_rs.vector.Quaternion
+    /// create a rotation about an axis
+    #[staticmethod]
+    pub fn from_axis_angle(axis: &_vector::Vector3D, angle: f64) -> PyResult<Self> {
+        match na::Unit::try_new(axis.v, Self::SMALL_N) {
+            Some(axis) => Ok(Self::new(na::UnitQuaternion::from_axis_angle(&axis, angle))),
+            None => Err(pyo3::exceptions::PyValueError::new_err(
+                "rotation axis must not be zero",
+            )),
+        }
+    }
+
+    /// from_euler(angles, order="xyz")
+    /// --
+    ///
+    /// from_euler(angles: List[float], order: str = "xyz") -> euklid_rs.vector.Quaternion
+    /// create a rotation from euler angles. lowercase axes are extrinsic (fixed axes),
+    /// uppercase axes intrinsic (rotated axes), the angles are applied in the given order
+    #[staticmethod]
+    #[pyo3(signature = (angles, order="xyz"))]
+    pub fn from_euler(angles: [f64; 3], order: &str) -> PyResult<Self> {
+        let (axes, intrinsic) = parse_order(order)?;
+        let mut q = na::UnitQuaternion::identity();
+
+        for (axis, angle) in axes.iter().zip(angles) {
+            let rotation = axis_rotation(*axis, angle);
+            q = if intrinsic {
+                q * rotation
+            } else {
+                rotation * q
+            };
+        }
+
+        Ok(Self::new(q))
+    }
+
+    /// from_matrix(matrix)
+    /// --
+    ///
+    /// from_matrix(matrix: List[List[float]]) -> euklid_rs.vector.Quaternion
+    /// create a rotation from an orthonormal 3x3 rotation matrix (list of rows)
+    #[staticmethod]
+    pub fn from_matrix(matrix: [[f64; 3]; 3]) -> PyResult<Self> {
+        let m = na::Matrix3::from_fn(|i, j| matrix[i][j]);
+
+        if (m.transpose() * m - na::Matrix3::identity()).amax() > 1e-6 || m.determinant() < 0. {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "matrix is not a rotation matrix",
+            ));
+        }
+
+        let rotation = na::Rotation3::from_matrix(&m);
+
+        Ok(Self::new(na::UnitQuaternion::from_rotation_matrix(
+            &rotation,
+        )))
+    }
+
+    /// from_vectors(source, target)
+    /// --
+    ///
+    /// from_vectors(source: euklid_rs.vector.Vector3D, target: euklid_rs.vector.Vector3D) -> euklid_rs.vector.Quaternion
+    /// get the shortest rotation that aligns source with target
+    #[staticmethod]
+    pub fn from_vectors(source: &_vector::Vector3D, target: &_vector::Vector3D) -> PyResult<Self> {
+        if source.length() < Self::SMALL_N || target.length() < Self::SMALL_N {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "vectors must not be zero",
+            ));
+        }
+
+        match na::UnitQuaternion::rotation_between(&source.v, &target.v) {
+            Some(q) => Ok(Self::new(q)),
+            None => {
+                // opposite vectors: rotate by 180° about any perpendicular axis
+                let mut axis = source.v.cross(&na::Vector3::x());
+                if axis.norm() < Self::SMALL_N * source.length() {
+                    axis = source.v.cross(&na::Vector3::y());
+                }
+
+                Ok(Self::new(na::UnitQuaternion::from_axis_angle(
+                    &na::Unit::new_normalize(axis),
+                    std::f64::consts::PI,
+                )))
+            }
+        }
+    }
+
+    #[getter]
+    pub fn w(&self) -> f64 {
+        self.q.w
+    }
+
+    #[getter]
+    pub fn x(&self) -> f64 {
+        self.q.i
+    }
+
+    #[getter]
+    pub fn y(&self) -> f64 {
+        self.q.j
+    }
+
+    #[getter]
+    pub fn z(&self) -> f64 {
+        self.q.k
+    }
+
+    pub fn tolist(&self) -> [f64; 4] {
+        [self.q.w, self.q.i, self.q.j, self.q.k]
+    }
+
+    pub fn angle(&self) -> f64 {
+        self.q.angle()
+    }
+
+    /// axis($self)
+    /// --
+    ///
+    /// axis(self: euklid_rs.vector.Quaternion) -> euklid_rs.vector.Vector3D
+    /// get the rotation axis (x-axis for the identity)
+    pub fn axis(&self) -> _vector::Vector3D {
+        match self.q.axis() {
+            Some(axis) => _vector::Vector3D {
+                v: axis.into_inner(),
+            },
+            None => _vector::Vector3D::__new__([1., 0., 0.]),
+        }
+    }
+
+    /// to_axis_angle($self)
+    /// --
+    ///
+    /// to_axis_angle(self: euklid_rs.vector.Quaternion) -> Tuple[euklid_rs.vector.Vector3D, float]
+    /// get the rotation axis and the angle (0 <= angle <= pi)
+    pub fn to_axis_angle(&self) -> (_vector::Vector3D, f64) {
+        (self.axis(), self.angle())
+    }
+
+    /// to_euler($self, order="xyz")
+    /// --
+    ///
+    /// to_euler(self: euklid_rs.vector.Quaternion, order: str = "xyz") -> List[float]
+    /// get euler angles for the given axis order (see from_euler). angles are in (-pi, pi],
+    /// the middle angle in [-pi/2, pi/2] (tait-bryan) or [0, pi] (proper euler).
+    /// in gimbal lock the last angle (extrinsic) is set to zero
+    #[pyo3(signature = (order="xyz"))]
+    pub fn to_euler(&self, order: &str) -> PyResult<[f64; 3]> {
+        let (axes, intrinsic) = parse_order(order)?;
+
+        // intrinsic rotations equal extrinsic rotations in reversed order
+        let axes = if intrinsic {
+            [axes[2], axes[1], axes[0]]
+        } else {
+            axes
+        };
+
+        let [i, j, mut k] = axes;
+
+        // Bernardes & Viollet: quaternion to euler angles for arbitrary sequences
+        let proper = i == k;
+        if proper {
+            k = 3 - i - j;
+        }
+        let sign = ((i as f64 - j as f64) * (j as f64 - k as f64) * (k as f64 - i as f64)) / 2.;
+
+        let v = self.q.vector();
+        let w = self.q.w;
+        let (a, b, c, d) = if proper {
+            (w, v[i], v[j], v[k] * sign)
+        } else {
+            (w - v[j], v[i] + v[k] * sign, v[j] + w, v[k] * sign - v[i])
+        };
+
+        let mut angle2 = 2. * f64::atan2(c.hypot(d), a.hypot(b));
+        let half_sum = f64::atan2(b, a);
+        let half_diff = f64::atan2(d, c);
+
+        let (angle1, mut angle3) = if angle2.abs() < Self::SMALL_N {
+            (2. * half_sum, 0.)
+        } else if (angle2 - std::f64::consts::PI).abs() < Self::SMALL_N {
+            (-2. * half_diff, 0.)
+        } else {
+            (half_sum - half_diff, half_sum + half_diff)
+        };
+
+        if !proper {
+            angle3 *= sign;
+            angle2 -= std::f64::consts::PI / 2.;
+        }
+
+        let angles = [
+            normalize_angle(angle1),
+            normalize_angle(angle2),
+            normalize_angle(angle3),
+        ];
+
+        if intrinsic {
+            Ok([angles[2], angles[1], angles[0]])
+        } else {
+            Ok(angles)
+        }
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self::new(self.q.inverse())
+    }
+
+    /// apply($self, vec)
+    /// --
+    ///
+    /// apply(self: euklid_rs.vector.Quaternion, vec: euklid_rs.vector.Vector3D) -> euklid_rs.vector.Vector3D
+    /// rotate a Vector3D
+    pub fn apply(&self, vec: &_vector::Vector3D) -> _vector::Vector3D {
+        _vector::Vector3D { v: self.q * vec.v }
+    }
+
+    /// slerp($self, other, t)
+    /// --
+    ///
+    /// slerp(self: euklid_rs.vector.Quaternion, other: euklid_rs.vector.Quaternion, t: float) -> euklid_rs.vector.Quaternion
+    /// spherical linear interpolation along the shortest path
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Self {
+        Self::new(self.q.try_slerp(&other.q, t, 1e-12).unwrap_or(self.q))
+    }
+
+    /// to_matrix($self)
+    /// --
+    ///
+    /// to_matrix(self: euklid_rs.vector.Quaternion) -> List[List[float]]
+    /// get the 3x3 rotation matrix as list of rows
+    pub fn to_matrix(&self) -> [[f64; 3]; 3] {
+        let m = self.q.to_rotation_matrix();
+        let mut result = [[0.; 3]; 3];
+
+        for (i, row) in result.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = m[(i, j)];
+            }
+        }
+
+        result
+    }
+
+    pub fn to_transformation(&self) -> Transformation {
+        Transformation::new(self.q.to_homogeneous())
+    }
+
+    fn __mul__(&self, other: &Quaternion) -> Self {
+        Self::new(self.q * other.q)
+    }
+
+    fn __richcmp__(
+        &self,
+        py: Python,
+        other: PyRef<Quaternion>,
+        op: pyo3::basic::CompareOp,
+    ) -> PyObject {
+        // q and -q describe the same rotation
+        let equal = self.q.angle_to(&other.q) < Self::SMALL_N;
+
+        match op {
+            pyo3::basic::CompareOp::Eq => equal.into_py(py),
+            pyo3::basic::CompareOp::Ne => (!equal).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Quaternion({:.4} {:.4} {:.4} {:.4})",
+            self.q.w, self.q.i, self.q.j, self.q.k
+        )
+    }
+}
+
+// json data: {"w": w, "x": x, "y": y, "z": z}
+impl JsonData for Quaternion {
+    fn json_data(&self, py: Python) -> PyObject {
+        [
+            ("w", self.q.w),
+            ("x", self.q.i),
+            ("y", self.q.j),
+            ("z", self.q.k),
+        ]
+        .into_py_dict(py)
+        .into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Self::__new__(
+            data.get_item("w")?.extract()?,
+            data.get_item("x")?.extract()?,
+            data.get_item("y")?.extract()?,
+            data.get_item("z")?.extract()?,
+        )
+    }
+}
+
+json_methods!(Quaternion);
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct QuaternionData {
+    w: f64,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<Quaternion> for QuaternionData {
+    fn from(quaternion: Quaternion) -> Self {
+        let q = quaternion.q;
+
+        Self {
+            w: q.w,
+            x: q.i,
+            y: q.j,
+            z: q.k,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<QuaternionData> for Quaternion {
+    type Error = &'static str;
+
+    fn try_from(data: QuaternionData) -> Result<Self, Self::Error> {
+        let q = na::Quaternion::new(data.w, data.x, data.y, data.z);
+
+        match na::UnitQuaternion::try_new(q, Self::SMALL_N) {
+            Some(q) => Ok(Self::new(q)),
+            None => Err("quaternion must not be zero"),
+        }
+    }
+}
@@ -1,10 +1,43 @@
 use nalgebra as na;
 use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
 
+use crate::io::json::{json_methods, JsonData};
+use crate::plane::Plane;
+use crate::polyline::PolyLine3D;
+use crate::spline::{BSplineCurve3_3D, BezierCurve3D};
 use crate::vector::_vector;
+use crate::vector::Quaternion;
 
-#[pyclass]
+#[derive(FromPyObject)]
+pub enum ScaleArgs {
+    Scalar(f64),
+    Vec(_vector::Vector3D),
+}
+
+#[derive(FromPyObject)]
+pub enum ApplyArgs<'a> {
+    Vec(_vector::Vector3D),
+    PolyLine(PolyLine3D),
+    VecList(Vec<_vector::Vector3D>),
+    Plane(PyRef<'a, Plane>),
+    BezierCurve(PyRef<'a, BezierCurve3D>),
+    BSplineCurve(PyRef<'a, BSplineCurve3_3D>),
+}
+
+#[derive(FromPyObject)]
+pub enum OperatorArgs<'a> {
+    Transformation(PyRef<'a, Transformation>),
+    Apply(ApplyArgs<'a>),
+}
+
+#[pyclass(module = "euklid_rs.vector")]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "TransformationData", into = "TransformationData")
+)]
 pub struct Transformation {
     matrix: na::base::Matrix4<f64>,
     inverse: Option<na::base::Matrix4<f64>>,
@@ -16,19 +49,352 @@ impl Transformation {
 
         Self { matrix, inverse }
     }
+
+    pub fn apply_vec(&self, vec: &_vector::Vector3D) -> _vector::Vector3D {
+        let p = self.matrix.transform_point(&vec.v.into());
+
+        _vector::Vector3D { v: p.coords }
+    }
+
+    pub fn apply_polyline(&self, line: &PolyLine3D) -> PolyLine3D {
+        let nodes = line.nodes.iter().map(|node| self.apply_vec(node)).collect();
+
+        PolyLine3D { nodes }
+    }
+
+    // wrap a transformation so that it is applied relative to origin
+    fn around(origin: Option<_vector::Vector3D>, transformation: Self) -> Self {
+        match origin {
+            Some(origin) => Self::translation(&origin)
+                .chain(&transformation)
+                .chain(&Self::translation(&(origin * -1.))),
+            None => transformation,
+        }
+    }
+
+    pub fn apply_plane(&self, plane: &Plane) -> Plane {
+        let p0 = self.apply_vec(&plane.p0);
+        let x_vector = self.apply_vec(&(plane.p0 + plane.x_vector)) - p0;
+        let y_vector = self.apply_vec(&(plane.p0 + plane.y_vector)) - p0;
+
+        Plane::new(p0, x_vector, y_vector)
+    }
+
+    /// split the affine part into translation * rotation * shear * scale.
+    /// shear is returned as the (xy, xz, yz) entries of an upper unit triangular matrix,
+    /// a reflection is carried by a negative z-scale.
+    pub fn decompose_affine(
+        &self,
+    ) -> (
+        na::Vector3<f64>,
+        na::UnitQuaternion<f64>,
+        na::Vector3<f64>,
+        na::Vector3<f64>,
+    ) {
+        let translation = self.matrix.fixed_view::<3, 1>(0, 3).into_owned();
+        let linear = self.matrix.fixed_view::<3, 3>(0, 0).into_owned();
+
+        // gram-schmidt on the columns: linear = q * k with k upper triangular
+        let mut q = na::Matrix3::zeros();
+        let mut k = na::Matrix3::zeros();
+        for j in 0..3 {
+            let mut column = linear.column(j).into_owned();
+            for i in 0..j {
+                k[(i, j)] = q.column(i).dot(&linear.column(j));
+                column -= q.column(i) * k[(i, j)];
+            }
+            k[(j, j)] = column.norm();
+            if k[(j, j)] > Self::SMALL_N {
+                q.set_column(j, &(column / k[(j, j)]));
+            } else {
+                // degenerate axis: complete the frame
+                let fallback = match j {
+                    0 => na::Vector3::x(),
+                    1 => q.column(0).cross(&na::Vector3::z()),
+                    _ => q.column(0).cross(&q.column(1)),
+                };
+                let fallback = if fallback.norm() > Self::SMALL_N {
+                    fallback
+                } else {
+                    q.column(0).cross(&na::Vector3::y())
+                };
+                q.set_column(j, &fallback.normalize());
+            }
+        }
+
+        if q.determinant() < 0. {
+            q.set_column(2, &(q.column(2) * -1.));
+            k.set_row(2, &(k.row(2) * -1.));
+        }
+
+        let scale = k.diagonal();
+        let shear_value = |i: usize, j: usize| {
+            if scale[j].abs() > Self::SMALL_N {
+                k[(i, j)] / scale[j]
+            } else {
+                0.
+            }
+        };
+        let shear = na::Vector3::new(shear_value(0, 1), shear_value(0, 2), shear_value(1, 2));
+        let rotation =
+            na::UnitQuaternion::from_rotation_matrix(&na::Rotation3::from_matrix_unchecked(q));
+
+        (translation, rotation, scale, shear)
+    }
+
+    pub fn compose(
+        translation: &na::Vector3<f64>,
+        rotation: &na::UnitQuaternion<f64>,
+        scale: &na::Vector3<f64>,
+        shear: &na::Vector3<f64>,
+    ) -> Self {
+        let shear_matrix = na::Matrix3::new(1., shear[0], shear[1], 0., 1., shear[2], 0., 0., 1.);
+        let linear = rotation.to_rotation_matrix().into_inner()
+            * shear_matrix
+            * na::Matrix3::from_diagonal(scale);
+
+        let mut matrix = na::Matrix4::identity();
+        matrix.fixed_view_mut::<3, 3>(0, 0).copy_from(&linear);
+        matrix.fixed_view_mut::<3, 1>(0, 3).copy_from(translation);
+
+        Self::new(matrix)
+    }
+}
+
+// homogeneous, affine 4x4 matrix as list of rows
+impl TryFrom<[[f64; 4]; 4]> for Transformation {
+    type Error = &'static str;
+
+    fn try_from(matrix: [[f64; 4]; 4]) -> Result<Self, Self::Error> {
+        if matrix.iter().flatten().any(|value| !value.is_finite()) {
+            return Err("matrix values must be finite");
+        }
+
+        let last_row = [0., 0., 0., 1.];
+        if (0..4).any(|i| (matrix[3][i] - last_row[i]).abs() > Self::SMALL_N) {
+            return Err(
+                "only affine transformations are supported (last row must be [0, 0, 0, 1])",
+            );
+        }
+
+        Ok(Self::new(na::Matrix4::from_fn(|i, j| matrix[i][j])))
+    }
 }
 
 #[pymethods]
 impl Transformation {
+    pub const SMALL_N: f64 = 1e-8;
+
+    /// from_matrix(matrix)
+    /// --
+    ///
+    /// from_matrix(matrix: List[List[float]]) -> euklid_rs.vector.Transformation
+    /// create a transformation from a homogeneous, affine 4x4 matrix (list of rows or numpy array)
+    #[staticmethod]
+    pub fn from_matrix(matrix: Vec<Vec<f64>>) -> PyResult<Self> {
+        if matrix.len() != 4 || matrix.iter().any(|row| row.len() != 4) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "matrix must be of shape 4x4",
+            ));
+        }
+
+        let rows = std::array::from_fn(|i| std::array::from_fn(|j| matrix[i][j]));
+
+        Self::try_from(rows).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// homogeneous 4x4 matrix as list of rows
+    #[getter]
+    pub fn matrix(&self) -> [[f64; 4]; 4] {
+        let mut result = [[0.; 4]; 4];
+
+        for (i, row) in result.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.matrix[(i, j)];
+            }
+        }
+
+        result
+    }
+
+    /// inverse($self)
+    /// --
+    ///
+    /// inverse(self: euklid_rs.vector.Transformation) -> euklid_rs.vector.Transformation
+    /// get the inverse transformation, raises ValueError for singular transformations
+    pub fn inverse(&self) -> PyResult<Transformation> {
+        match self.inverse {
+            Some(inverse) => Ok(Transformation::new(inverse)),
+            None => Err(pyo3::exceptions::PyValueError::new_err(
+                "transformation is not invertible",
+            )),
+        }
+    }
+
+    /// almost_equal($self, other, tolerance=1e-8)
+    /// --
+    ///
+    /// almost_equal(self: euklid_rs.vector.Transformation, other: euklid_rs.vector.Transformation, tolerance: float = 1e-8) -> bool
+    /// compare all matrix entries within an absolute tolerance
+    #[pyo3(signature = (other, tolerance=Self::SMALL_N))]
+    pub fn almost_equal(&self, other: &Transformation, tolerance: f64) -> bool {
+        (self.matrix - other.matrix).amax() <= tolerance
+    }
+
+    /// decompose($self)
+    /// --
+    ///
+    /// decompose(self: euklid_rs.vector.Transformation) -> Tuple[euklid_rs.vector.Vector3D, euklid_rs.vector.Quaternion, euklid_rs.vector.Vector3D, List[float]]
+    /// split into translation, rotation, scale and shear (xy, xz, yz), such that
+    /// T = translation @ rotation @ shear @ scale. reflections result in a negative z-scale
+    pub fn decompose(&self) -> (_vector::Vector3D, Quaternion, _vector::Vector3D, [f64; 3]) {
+        let (translation, rotation, scale, shear) = self.decompose_affine();
+
+        (
+            _vector::Vector3D { v: translation },
+            Quaternion::new(rotation),
+            _vector::Vector3D { v: scale },
+            [shear[0], shear[1], shear[2]],
+        )
+    }
+
+    pub fn get_translation(&self) -> _vector::Vector3D {
+        _vector::Vector3D {
+            v: self.matrix.fixed_view::<3, 1>(0, 3).into_owned(),
+        }
+    }
+
+    pub fn get_rotation(&self) -> Quaternion {
+        self.decompose().1
+    }
+
+    pub fn get_scale(&self) -> _vector::Vector3D {
+        self.decompose().2
+    }
+
+    pub fn get_shear(&self) -> [f64; 3] {
+        self.decompose().3
+    }
+
+    /// is_affine($self, tolerance=1e-8)
+    /// --
+    ///
+    /// is_affine(self: euklid_rs.vector.Transformation, tolerance: float = 1e-8) -> bool
+    /// check for a last row of [0, 0, 0, 1], singular matrices included (see handedness)
+    #[pyo3(signature = (tolerance=Self::SMALL_N))]
+    pub fn is_affine(&self, tolerance: f64) -> bool {
+        let last_row = na::RowVector4::new(0., 0., 0., 1.);
+
+        (self.matrix.row(3) - last_row).amax() <= tolerance
+    }
+
+    /// is_orthonormal($self, tolerance=1e-8)
+    /// --
+    ///
+    /// is_orthonormal(self: euklid_rs.vector.Transformation, tolerance: float = 1e-8) -> bool
+    /// check if the linear part has orthogonal unit columns (rotations and reflections)
+    #[pyo3(signature = (tolerance=Self::SMALL_N))]
+    pub fn is_orthonormal(&self, tolerance: f64) -> bool {
+        let linear = self.matrix.fixed_view::<3, 3>(0, 0);
+
+        (linear.transpose() * linear - na::Matrix3::identity()).amax() <= tolerance
+    }
+
+    /// is_rigid($self, tolerance=1e-8)
+    /// --
+    ///
+    /// is_rigid(self: euklid_rs.vector.Transformation, tolerance: float = 1e-8) -> bool
+    /// check if the transformation only consists of a rotation and a translation
+    #[pyo3(signature = (tolerance=Self::SMALL_N))]
+    pub fn is_rigid(&self, tolerance: f64) -> bool {
+        self.is_affine(tolerance)
+            && self.is_orthonormal(tolerance)
+            && self.handedness(tolerance) > 0
+    }
+
+    /// handedness($self, tolerance=1e-8)
+    /// --
+    ///
+    /// handedness(self: euklid_rs.vector.Transformation, tolerance: float = 1e-8) -> int
+    /// 1 if the orientation is preserved, -1 for reflections and 0 for singular transformations
+    #[pyo3(signature = (tolerance=Self::SMALL_N))]
+    pub fn handedness(&self, tolerance: f64) -> i32 {
+        let determinant = self.matrix.fixed_view::<3, 3>(0, 0).determinant();
+
+        if determinant.abs() <= tolerance {
+            0
+        } else if determinant > 0. {
+            1
+        } else {
+            -1
+        }
+    }
+
+    fn __richcmp__(
+        &self,
+        py: Python,
+        other: PyRef<Transformation>,
+        op: pyo3::basic::CompareOp,
+    ) -> PyObject {
+        match op {
+            pyo3::basic::CompareOp::Eq => self.almost_equal(&other, Self::SMALL_N).into_py(py),
+            pyo3::basic::CompareOp::Ne => (!self.almost_equal(&other, Self::SMALL_N)).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        let rows: Vec<String> = self
+            .matrix()
+            .iter()
+            .map(|row| {
+                format!(
+                    "[{:.4}, {:.4}, {:.4}, {:.4}]",
+                    row[0], row[1], row[2], row[3]
+                )
+            })
+            .collect();
+
+        format!("Transformation([{}])", rows.join(", "))
+    }
+
     /// apply($self, other)
     /// --
     ///
-    /// apply(self: euklid_rs.vector.Transformation, other: euklid_rs.vector.Vector3D) -> euklid_rs.vector.Vector3D
-    /// apply the transformation to a Vector3D
-    pub fn apply(&self, vec: &_vector::Vector3D) -> _vector::Vector3D {
-        let p = self.matrix.transform_point(&vec.v.into());
+    /// apply(self: euklid_rs.vector.Transformation, other: Union[Vector3D, List[Vector3D], PolyLine3D, Plane, BezierCurve3D, BSplineCurve3_3D]) -> Union[Vector3D, List[Vector3D], PolyLine3D, Plane, BezierCurve3D, BSplineCurve3_3D]
+    /// apply the transformation to a Vector3D, a list of vectors, a PolyLine3D, a Plane or the controlpoints of a curve
+    pub fn apply(&self, py: Python, other: ApplyArgs) -> PyObject {
+        match other {
+            ApplyArgs::Vec(vec) => self.apply_vec(&vec).into_py(py),
+            ApplyArgs::PolyLine(line) => self.apply_polyline(&line).into_py(py),
+            ApplyArgs::VecList(vectors) => vectors
+                .iter()
+                .map(|vec| self.apply_vec(vec))
+                .collect::<Vec<_>>()
+                .into_py(py),
+            ApplyArgs::Plane(plane) => self.apply_plane(&plane).into_py(py),
+            ApplyArgs::BezierCurve(curve) => {
+                BezierCurve3D::new(self.apply_polyline(&curve.controlpoints)).into_py(py)
+            }
+            ApplyArgs::BSplineCurve(curve) => {
+                BSplineCurve3_3D::new(self.apply_polyline(&curve.controlpoints)).into_py(py)
+            }
+        }
+    }
 
-        _vector::Vector3D { v: p.coords }
+    /// __matmul__($self, other)
+    /// --
+    ///
+    /// chain with another transformation or apply to other
+    fn __matmul__(&self, py: Python, other: OperatorArgs) -> PyObject {
+        match other {
+            OperatorArgs::Transformation(transformation) => self.chain(&transformation).into_py(py),
+            OperatorArgs::Apply(args) => self.apply(py, args),
+        }
+    }
+
+    fn __mul__(&self, py: Python, other: OperatorArgs) -> PyObject {
+        self.__matmul__(py, other)
     }
 
     pub fn apply_inverse(&self, vec: &_vector::Vector3D) -> Option<_vector::Vector3D> {
@@ -65,29 +431,196 @@ impl Transformation {
         Transformation::new(matrix)
     }
 
-    #[staticmethod]
-    /// rotation(angle, axis)
+    /// rotation(angle, axis, origin=None)
     /// --
     ///
-    /// rotation(angle: float, axis: euklid_rs.vector.Vector3D) -> euklid_rs.vector.Transformation
-    pub fn rotation(angle: f64, axis: &_vector::Vector3D) -> Self {
+    /// rotation(angle: float, axis: euklid_rs.vector.Vector3D, origin: Optional[euklid_rs.vector.Vector3D] = None) -> euklid_rs.vector.Transformation
+    /// create a rotation about an axis through origin (defaults to [0, 0, 0])
+    #[staticmethod]
+    #[pyo3(signature = (angle, axis, origin=None))]
+    pub fn rotation(
+        angle: f64,
+        axis: &_vector::Vector3D,
+        origin: Option<_vector::Vector3D>,
+    ) -> Self {
         let scaled_axis = axis.normalized().v * angle;
         let rotation = na::Rotation3::from_scaled_axis(scaled_axis);
         let matrix = rotation.to_homogeneous();
 
-        Transformation::new(matrix)
+        Self::around(origin, Transformation::new(matrix))
     }
 
-    #[staticmethod]
-    /// scale(scale)
+    /// scale(scale, origin=None)
     /// --
     ///
-    /// scale(scale: float) -> euklid_rs.vector.Transformation
-    /// create a scaling transformation
-    pub fn scale(scale: f64) -> Self {
-        let scale3 = na::Scale3::new(scale, scale, scale);
+    /// scale(scale: Union[float, euklid_rs.vector.Vector3D], origin: Optional[euklid_rs.vector.Vector3D] = None) -> euklid_rs.vector.Transformation
+    /// create a (non-uniform) scaling transformation relative to origin
+    #[staticmethod]
+    #[pyo3(signature = (scale, origin=None))]
+    pub fn scale(scale: ScaleArgs, origin: Option<_vector::Vector3D>) -> Self {
+        let scale3 = match scale {
+            ScaleArgs::Scalar(value) => na::Scale3::new(value, value, value),
+            ScaleArgs::Vec(vec) => na::Scale3::new(vec.v[0], vec.v[1], vec.v[2]),
+        };
         let matrix = scale3.to_homogeneous();
 
+        Self::around(origin, Transformation::new(matrix))
+    }
+
+    /// shear(xy=0., xz=0., yx=0., yz=0., zx=0., zy=0.)
+    /// --
+    ///
+    /// shear(xy: float = 0., xz: float = 0., yx: float = 0., yz: float = 0., zx: float = 0., zy: float = 0.) -> euklid_rs.vector.Transformation
+    /// create a shear transformation: x' = x + xy*y + xz*z, y' = y + yx*x + yz*z, z' = z + zx*x + zy*y
+    #[staticmethod]
+    #[pyo3(signature = (xy=0., xz=0., yx=0., yz=0., zx=0., zy=0.))]
+    pub fn shear(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Self {
+        let matrix = na::Matrix4::new(
+            1., xy, xz, 0., yx, 1., yz, 0., zx, zy, 1., 0., 0., 0., 0., 1.,
+        );
+
         Transformation::new(matrix)
     }
+
+    /// mirror(plane)
+    /// --
+    ///
+    /// mirror(plane: euklid_rs.plane.Plane) -> euklid_rs.vector.Transformation
+    /// create a reflection across a plane, raises ValueError for a degenerate plane
+    #[staticmethod]
+    pub fn mirror(plane: &Plane) -> PyResult<Self> {
+        let normal = plane.unit_normal()?;
+        let reflection = na::Matrix3::identity() - normal * normal.transpose() * 2.;
+
+        Ok(Self::around(
+            Some(plane.p0),
+            Transformation::new(reflection.to_homogeneous()),
+        ))
+    }
+
+    /// from_frame(origin, x_vector, y_vector)
+    /// --
+    ///
+    /// from_frame(origin: euklid_rs.vector.Vector3D, x_vector: euklid_rs.vector.Vector3D, y_vector: euklid_rs.vector.Vector3D) -> euklid_rs.vector.Transformation
+    /// create a rigid transformation from local coordinates into an orthonormal frame.
+    /// x_vector defines the local x-axis, y_vector is orthogonalized against it
+    #[staticmethod]
+    pub fn from_frame(
+        origin: _vector::Vector3D,
+        x_vector: _vector::Vector3D,
+        y_vector: _vector::Vector3D,
+    ) -> PyResult<Self> {
+        let x_axis = x_vector.normalized();
+        let z_axis = x_axis.cross(&y_vector);
+        let length = z_axis.length();
+
+        if length.is_nan() || length <= _vector::Vector3D::SMALL_N {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "frame vectors must not be parallel",
+            ));
+        }
+
+        let z_axis = z_axis.normalized();
+        let y_axis = z_axis.cross(&x_axis);
+
+        let mut matrix = na::Matrix4::identity();
+        for i in 0..3 {
+            matrix[(i, 0)] = x_axis.v[i];
+            matrix[(i, 1)] = y_axis.v[i];
+            matrix[(i, 2)] = z_axis.v[i];
+            matrix[(i, 3)] = origin.v[i];
+        }
+
+        Ok(Transformation::new(matrix))
+    }
+
+    /// look_at(eye, target, up)
+    /// --
+    ///
+    /// look_at(eye: euklid_rs.vector.Vector3D, target: euklid_rs.vector.Vector3D, up: euklid_rs.vector.Vector3D) -> euklid_rs.vector.Transformation
+    /// create a rigid transformation from local coordinates into a frame at eye,
+    /// with the local z-axis pointing at target and the local y-axis towards up
+    #[staticmethod]
+    pub fn look_at(
+        eye: _vector::Vector3D,
+        target: _vector::Vector3D,
+        up: _vector::Vector3D,
+    ) -> PyResult<Self> {
+        let z_axis = target - eye;
+        let x_axis = up.cross(&z_axis);
+
+        Self::from_frame(eye, x_axis, z_axis.cross(&x_axis))
+    }
+
+    /// change_of_basis(source, target)
+    /// --
+    ///
+    /// change_of_basis(source: euklid_rs.plane.Plane, target: euklid_rs.plane.Plane) -> euklid_rs.vector.Transformation
+    /// create a transformation that maps points relative to the source plane
+    /// to the same local coordinates relative to the target plane
+    #[staticmethod]
+    pub fn change_of_basis(source: &Plane, target: &Plane) -> PyResult<Self> {
+        let inverse = source.transformation.inverse()?;
+
+        Ok(target.transformation.chain(&inverse))
+    }
+
+    /// interpolate($self, other, t)
+    /// --
+    ///
+    /// interpolate(self: euklid_rs.vector.Transformation, other: euklid_rs.vector.Transformation, t: float) -> euklid_rs.vector.Transformation
+    /// interpolate between two transformations: translation, scale and shear are
+    /// interpolated linearly, the rotation is interpolated along the shortest arc
+    pub fn interpolate(&self, other: &Transformation, t: f64) -> Self {
+        let (translation1, rotation1, scale1, shear1) = self.decompose_affine();
+        let (translation2, rotation2, scale2, shear2) = other.decompose_affine();
+
+        let rotation = rotation1
+            .try_slerp(&rotation2, t, 1e-12)
+            .unwrap_or(rotation1);
+
+        Self::compose(
+            &translation1.lerp(&translation2, t),
+            &rotation,
+            &scale1.lerp(&scale2, t),
+            &shear1.lerp(&shear2, t),
+        )
+    }
+}
+
+// json data: {"matrix": [[...], ...]} (list of rows)
+impl JsonData for Transformation {
+    fn json_data(&self, py: Python) -> PyObject {
+        [("matrix", self.matrix())].into_py_dict(py).into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Self::from_matrix(data.get_item("matrix")?.extract()?)
+    }
+}
+
+json_methods!(Transformation);
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TransformationData {
+    matrix: [[f64; 4]; 4],
+}
+
+#[cfg(feature = "serde")]
+impl From<Transformation> for TransformationData {
+    fn from(transformation: Transformation) -> Self {
+        Self {
+            matrix: transformation.matrix(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TransformationData> for Transformation {
+    type Error = &'static str;
+
+    fn try_from(data: TransformationData) -> Result<Self, Self::Error> {
+        Self::try_from(data.matrix)
+    }
 }
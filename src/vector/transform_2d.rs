@@ -0,0 +1,345 @@
+use nalgebra as na;
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+
+use crate::io::json::{json_methods, JsonData};
+use crate::polyline::PolyLine2D;
+use crate::spline::{BSplineCurve3, BezierCurve};
+use crate::vector::_vector;
+use crate::vector::Transformation;
+
+#[derive(FromPyObject)]
+pub enum ScaleArgs2D {
+    Scalar(f64),
+    Vec(_vector::Vector2D),
+}
+
+#[derive(FromPyObject)]
+pub enum ApplyArgs2D<'a> {
+    Vec(_vector::Vector2D),
+    PolyLine(PolyLine2D),
+    BezierCurve(PyRef<'a, BezierCurve>),
+    BSplineCurve3(PyRef<'a, BSplineCurve3>),
+}
+
+/// Affine transformation in the plane (homogeneous 3x3 matrix)
+#[pyclass(module = "euklid_rs.vector")]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Transformation2DData", into = "Transformation2DData")
+)]
+pub struct Transformation2D {
+    matrix: na::base::Matrix3<f64>,
+    inverse: Option<na::base::Matrix3<f64>>,
+}
+
+impl Transformation2D {
+    pub fn new(matrix: na::base::Matrix3<f64>) -> Self {
+        let inverse = matrix.try_inverse();
+
+        Self { matrix, inverse }
+    }
+
+    pub fn apply_vec(&self, vec: &_vector::Vector2D) -> _vector::Vector2D {
+        let p = self.matrix.transform_point(&vec.v.into());
+
+        _vector::Vector2D { v: p.coords }
+    }
+
+    pub fn apply_polyline(&self, line: &PolyLine2D) -> PolyLine2D {
+        let nodes = line.nodes.iter().map(|node| self.apply_vec(node)).collect();
+
+        PolyLine2D { nodes }
+    }
+
+    // wrap a transformation so that it is applied relative to origin
+    fn around(origin: Option<_vector::Vector2D>, transformation: Self) -> Self {
+        match origin {
+            Some(origin) => Self::translation(&origin)
+                .chain(&transformation)
+                .chain(&Self::translation(&(origin * -1.))),
+            None => transformation,
+        }
+    }
+}
+
+// homogeneous, affine 3x3 matrix as list of rows
+impl TryFrom<[[f64; 3]; 3]> for Transformation2D {
+    type Error = &'static str;
+
+    fn try_from(matrix: [[f64; 3]; 3]) -> Result<Self, Self::Error> {
+        if matrix.iter().flatten().any(|value| !value.is_finite()) {
+            return Err("matrix values must be finite");
+        }
+
+        let last_row = [0., 0., 1.];
+        if (0..3).any(|i| (matrix[2][i] - last_row[i]).abs() > Transformation::SMALL_N) {
+            return Err("only affine transformations are supported (last row must be [0, 0, 1])");
+        }
+
+        Ok(Self::new(na::Matrix3::from_fn(|i, j| matrix[i][j])))
+    }
+}
+
+#[pymethods]
+impl Transformation2D {
+    /// homogeneous 3x3 matrix as list of rows
+    #[getter]
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        let mut result = [[0.; 3]; 3];
+
+        for (i, row) in result.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.matrix[(i, j)];
+            }
+        }
+
+        result
+    }
+
+    /// apply($self, other)
+    /// --
+    ///
+    /// apply(self: euklid_rs.vector.Transformation2D, other: Union[Vector2D, PolyLine2D, BezierCurve, BSplineCurve3]) -> Union[Vector2D, PolyLine2D, BezierCurve, BSplineCurve3]
+    /// apply the transformation to a Vector2D, a PolyLine2D or the controlpoints of a curve
+    pub fn apply(&self, py: Python, other: ApplyArgs2D) -> PyObject {
+        match other {
+            ApplyArgs2D::Vec(vec) => self.apply_vec(&vec).into_py(py),
+            ApplyArgs2D::PolyLine(line) => self.apply_polyline(&line).into_py(py),
+            ApplyArgs2D::BezierCurve(curve) => {
+                BezierCurve::new(self.apply_polyline(&curve.controlpoints)).into_py(py)
+            }
+            ApplyArgs2D::BSplineCurve3(curve) => {
+                BSplineCurve3::new(self.apply_polyline(&curve.controlpoints)).into_py(py)
+            }
+        }
+    }
+
+    pub fn apply_inverse(&self, vec: &_vector::Vector2D) -> Option<_vector::Vector2D> {
+        self.inverse.map(|inverse| {
+            let p = inverse.transform_point(&vec.v.into());
+            _vector::Vector2D { v: p.coords }
+        })
+    }
+
+    /// chain($self, other)
+    /// --
+    ///
+    /// chain(self: euklid_rs.vector.Transformation2D, other: euklid_rs.vector.Transformation2D) -> euklid_rs.vector.Transformation2D
+    /// get a chained transformation (other is applied first)
+    pub fn chain(&self, other: &Transformation2D) -> Transformation2D {
+        Transformation2D::new(self.matrix * other.matrix)
+    }
+
+    /// inverse($self)
+    /// --
+    ///
+    /// inverse(self: euklid_rs.vector.Transformation2D) -> euklid_rs.vector.Transformation2D
+    /// get the inverse transformation, raises ValueError for singular transformations
+    pub fn inverse(&self) -> PyResult<Transformation2D> {
+        match self.inverse {
+            Some(inverse) => Ok(Transformation2D::new(inverse)),
+            None => Err(pyo3::exceptions::PyValueError::new_err(
+                "transformation is not invertible",
+            )),
+        }
+    }
+
+    #[staticmethod]
+    pub fn identity() -> Self {
+        Transformation2D::new(na::Matrix3::identity())
+    }
+
+    /// translation(vec)
+    /// --
+    ///
+    /// translation(vec: euklid_rs.vector.Vector2D) -> euklid_rs.vector.Transformation2D
+    /// create a translation
+    #[staticmethod]
+    pub fn translation(vec: &_vector::Vector2D) -> Self {
+        let translation = na::geometry::Translation2::new(vec.v[0], vec.v[1]);
+
+        Transformation2D::new(translation.to_homogeneous())
+    }
+
+    /// rotation(angle, origin=None)
+    /// --
+    ///
+    /// rotation(angle: float, origin: Optional[euklid_rs.vector.Vector2D] = None) -> euklid_rs.vector.Transformation2D
+    /// create a counter-clockwise rotation about origin
+    #[staticmethod]
+    #[pyo3(signature = (angle, origin=None))]
+    pub fn rotation(angle: f64, origin: Option<_vector::Vector2D>) -> Self {
+        let rotation = na::Rotation2::new(angle);
+
+        Self::around(origin, Transformation2D::new(rotation.to_homogeneous()))
+    }
+
+    /// scale(scale, origin=None)
+    /// --
+    ///
+    /// scale(scale: Union[float, euklid_rs.vector.Vector2D], origin: Optional[euklid_rs.vector.Vector2D] = None) -> euklid_rs.vector.Transformation2D
+    /// create a (non-uniform) scaling relative to origin
+    #[staticmethod]
+    #[pyo3(signature = (scale, origin=None))]
+    pub fn scale(scale: ScaleArgs2D, origin: Option<_vector::Vector2D>) -> Self {
+        let scale2 = match scale {
+            ScaleArgs2D::Scalar(value) => na::Scale2::new(value, value),
+            ScaleArgs2D::Vec(vec) => na::Scale2::new(vec.v[0], vec.v[1]),
+        };
+
+        Self::around(origin, Transformation2D::new(scale2.to_homogeneous()))
+    }
+
+    /// mirror(p1, p2)
+    /// --
+    ///
+    /// mirror(p1: euklid_rs.vector.Vector2D, p2: euklid_rs.vector.Vector2D) -> euklid_rs.vector.Transformation2D
+    /// create a reflection across the line through p1 and p2, raises ValueError for p1 == p2
+    #[staticmethod]
+    pub fn mirror(p1: _vector::Vector2D, p2: _vector::Vector2D) -> PyResult<Self> {
+        let diff = p2 - p1;
+        if diff.length() < Transformation::SMALL_N {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "mirror axis points must not be equal",
+            ));
+        }
+
+        let normal = _vector::Vector2D::__new__([-diff.v[1], diff.v[0]]).normalized();
+
+        let reflection = na::Matrix2::identity() - normal.v * normal.v.transpose() * 2.;
+        let mut matrix = na::Matrix3::identity();
+        matrix.fixed_view_mut::<2, 2>(0, 0).copy_from(&reflection);
+
+        Ok(Self::around(Some(p1), Transformation2D::new(matrix)))
+    }
+
+    /// shear(x, y)
+    /// --
+    ///
+    /// shear(x: float, y: float) -> euklid_rs.vector.Transformation2D
+    /// create a shear transformation: x' = x + x_factor*y, y' = y + y_factor*x
+    #[staticmethod]
+    pub fn shear(x: f64, y: f64) -> Self {
+        let matrix = na::Matrix3::new(1., x, 0., y, 1., 0., 0., 0., 1.);
+
+        Transformation2D::new(matrix)
+    }
+}
+
+/// Rotation about the origin
+#[pyclass(module = "euklid_rs.vector")]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Rotation2DData", into = "Rotation2DData")
+)]
+pub struct Rotation2D {
+    #[pyo3(get)]
+    pub angle: f64,
+
+    rotation: na::Rotation2<f64>,
+}
+
+#[pymethods]
+impl Rotation2D {
+    #[new]
+    pub fn new(angle: f64) -> Self {
+        Self {
+            angle,
+            rotation: na::Rotation2::new(angle),
+        }
+    }
+
+    /// apply($self, vec)
+    /// --
+    ///
+    /// apply(self: euklid_rs.vector.Rotation2D, vec: euklid_rs.vector.Vector2D) -> euklid_rs.vector.Vector2D
+    /// rotate a Vector2D
+    pub fn apply(&self, vec: &_vector::Vector2D) -> _vector::Vector2D {
+        _vector::Vector2D {
+            v: self.rotation * vec.v,
+        }
+    }
+
+    pub fn transformation(&self) -> Transformation2D {
+        Transformation2D::new(self.rotation.to_homogeneous())
+    }
+}
+
+// json data: {"matrix": [[...], ...]} (list of rows)
+impl JsonData for Transformation2D {
+    fn json_data(&self, py: Python) -> PyObject {
+        [("matrix", self.matrix())].into_py_dict(py).into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        let matrix: [[f64; 3]; 3] = data.get_item("matrix")?.extract()?;
+
+        Self::try_from(matrix).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+}
+
+json_methods!(Transformation2D);
+
+// json data: {"angle": angle}
+impl JsonData for Rotation2D {
+    fn json_data(&self, py: Python) -> PyObject {
+        [("angle", self.angle)].into_py_dict(py).into()
+    }
+
+    fn from_json_data(data: &PyAny) -> PyResult<Self> {
+        Ok(Self::new(data.get_item("angle")?.extract()?))
+    }
+}
+
+json_methods!(Rotation2D);
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Transformation2DData {
+    matrix: [[f64; 3]; 3],
+}
+
+#[cfg(feature = "serde")]
+impl From<Transformation2D> for Transformation2DData {
+    fn from(transformation: Transformation2D) -> Self {
+        Self {
+            matrix: transformation.matrix(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Transformation2DData> for Transformation2D {
+    type Error = &'static str;
+
+    fn try_from(data: Transformation2DData) -> Result<Self, Self::Error> {
+        Self::try_from(data.matrix)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Rotation2DData {
+    angle: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<Rotation2D> for Rotation2DData {
+    fn from(rotation: Rotation2D) -> Self {
+        Self {
+            angle: rotation.angle,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Rotation2DData> for Rotation2D {
+    fn from(data: Rotation2DData) -> Self {
+        Self::new(data.angle)
+    }
+}
@@ -0,0 +1,20 @@
+G21
+G90
+M5
+G0 X0.000 Y4.000
+M3
+G1 X0.000 Y5.000 F1000.000
+G2 X5.000 Y10.000 I5.000 J0.000
+G1 X10.000 Y10.000
+G1 X10.500 Y10.000
+M5
+G0 X19.000 Y0.000
+M3
+G1 X20.000 Y0.000 F1000.000
+G1 X30.000 Y0.000
+G1 X30.000 Y10.000
+G1 X20.000 Y10.000
+G1 X20.000 Y0.000
+G1 X20.000 Y-0.500
+M5
+M2
@@ -0,0 +1,264 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for circles and arcs"""
+
+import math
+from euklid_rs.arc import Arc2D, Circle2D, Path2D
+from euklid_rs.line import Line2D, Segment2D
+from euklid_rs.polyline import PolyLine2D
+from euklid_rs.spline import BezierCurve, BSplineCurve3
+from euklid_rs.vector import Vector2D
+
+from .base import TestCase
+
+
+class TestCircle2D(TestCase):
+    """Test euklid_rs.arc.Circle2D"""
+
+    def setUp(self) -> None:
+        self.circle = Circle2D(Vector2D([1, 1]), 2)
+
+    def test_from_points(self):
+        """circle through three points"""
+        circle = Circle2D.from_points(Vector2D([3, 1]), Vector2D([1, 3]), Vector2D([-1, 1]))
+        self.assert_almost_equal_vec(circle.center, [1, 1])
+        self.assertAlmostEqual(circle.radius, 2)
+
+        with self.assertRaises(ValueError):
+            Circle2D.from_points(Vector2D([0, 0]), Vector2D([1, 1]), Vector2D([2, 2]))
+        with self.assertRaises(ValueError):
+            Circle2D(Vector2D([0, 0]), -1)
+
+    def test_fit(self):
+        """least-squares circle"""
+        points = [self.circle.get(i * 0.5) for i in range(10)]
+        circle, rms = Circle2D.fit(points)
+        self.assert_almost_equal_vec(circle.center, [1, 1])
+        self.assertAlmostEqual(circle.radius, 2)
+        self.assertAlmostEqual(rms, 0)
+
+        noisy = [point + Vector2D([0.01 * (-1) ** i, 0]) for i, point in enumerate(points)]
+        circle, rms = Circle2D.fit(PolyLine2D(noisy))
+        self.assertAlmostEqual(circle.radius, 2, 2)
+        self.assertLess(rms, 0.01)
+
+        with self.assertRaises(ValueError):
+            Circle2D.fit([Vector2D([0, 0]), Vector2D([1, 1]), Vector2D([2, 2])])
+
+    def test_polyline(self):
+        """discretization with a chord tolerance"""
+        for tolerance in [0.1, 0.01, 0.001]:
+            line = self.circle.get_polyline(tolerance)
+            self.assert_almost_equal_vec(line.nodes[0], line.nodes[-1])
+            for i in range(len(line) - 1):
+                midpoint = (line.nodes[i] + line.nodes[i + 1]) * 0.5
+                self.assertLessEqual(-self.circle.distance(midpoint), tolerance + 1e-12)
+
+    def test_polyline_tolerance(self):
+        """invalid tolerances raise, tiny tolerances are capped"""
+        arc = Arc2D(Vector2D([0, 0]), 1, 0, math.pi)
+        for tolerance in (0, -1, math.nan, math.inf):
+            with self.assertRaises(ValueError):
+                self.circle.get_polyline(tolerance)
+            with self.assertRaises(ValueError):
+                arc.get_polyline(tolerance)
+
+        line = self.circle.get_polyline(1e-300)
+        self.assertLess(len(line), 100_000)
+        self.assert_almost_equal_vec(line.nodes[0], line.nodes[-1])
+
+    def test_intersection(self):
+        """circle-line and circle-polyline intersections"""
+        line = Line2D(Vector2D([0, 1]), Vector2D([1, 1]))
+        cuts = self.circle.intersect_line(line)
+        self.assertEqual(len(cuts), 2)
+        self.assert_almost_equal_vec(cuts[0][1], [-1, 1])
+        self.assert_almost_equal_vec(cuts[1][1], [3, 1])
+
+        segment = Segment2D(Vector2D([1, 1]), Vector2D([5, 1]))
+        cuts = self.circle.intersect_line(segment)
+        self.assertEqual(len(cuts), 1)
+        self.assertAlmostEqual(cuts[0][0], 0.5)
+
+        tangent = Line2D(Vector2D([0, 3]), Vector2D([1, 3]))
+        self.assertEqual(len(self.circle.intersect_line(tangent)), 1)
+
+        polyline = PolyLine2D([[-2, 1], [1, 1], [1, 4]])
+        cuts = self.circle.intersect_polyline(polyline)
+        self.assertEqual(len(cuts), 2)
+        self.assertAlmostEqual(cuts[0][0], 1 / 3)
+        self.assertAlmostEqual(cuts[1][0], 1 + 2 / 3)
+
+
+class TestArc2D(TestCase):
+    """Test euklid_rs.arc.Arc2D"""
+
+    def test_from_points(self):
+        """arc through three points in both directions"""
+        p1, p2, p3 = Vector2D([1, 0]), Vector2D([0, 1]), Vector2D([-1, 0])
+        arc = Arc2D.from_points(p1, p2, p3)
+        self.assertAlmostEqual(arc.sweep, math.pi)
+        self.assert_almost_equal_vec(arc.get(0.5), p2)
+
+        arc = Arc2D.from_points(p3, p2, p1)
+        self.assertAlmostEqual(arc.sweep, -math.pi)
+        self.assert_almost_equal_vec(arc.start(), p3)
+        self.assert_almost_equal_vec(arc.end(), p1)
+
+        arc = Arc2D.from_points(p1, Vector2D([0, -1]), p2)
+        self.assertAlmostEqual(arc.sweep, -1.5 * math.pi)
+
+    def test_bulge(self):
+        """arcs from start, end and bulge"""
+        p1, p2 = Vector2D([1, 0]), Vector2D([-1, 0])
+        arc = Arc2D.from_bulge(p1, p2, 1)
+        self.assert_almost_equal_vec(arc.center, [0, 0])
+        self.assert_almost_equal_vec(arc.get(0.5), [0, 1])
+        self.assertAlmostEqual(arc.bulge(), 1)
+
+        arc = Arc2D.from_bulge(p1, p2, -0.5)
+        self.assert_almost_equal_vec(arc.start(), p1)
+        self.assert_almost_equal_vec(arc.end(), p2)
+        self.assertLess(arc.get(0.5)[1], 0)
+        self.assertAlmostEqual(arc.bulge(), -0.5)
+
+        large = Arc2D.from_bulge(p1, p2, 2)
+        self.assertGreater(large.sweep, math.pi)
+        self.assert_almost_equal_vec(large.end(), p2)
+
+    def test_invalid(self):
+        """non-finite angles and sweeps beyond a full turn are rejected"""
+        center = Vector2D([0, 0])
+        for start, end in ((0, math.nan), (math.inf, 1), (0, 1e12), (1, 1 - 2.5 * math.pi)):
+            with self.assertRaises(ValueError):
+                Arc2D(center, 1, start, end)
+
+        full = Arc2D(center, 1, 1, 1 - 2 * math.pi)
+        self.assertAlmostEqual(full.get_length(), 2 * math.pi)
+
+    def test_geometry(self):
+        """length, tangent and discretization"""
+        arc = Arc2D(Vector2D([0, 0]), 2, 0, math.pi / 2)
+        self.assertAlmostEqual(arc.get_length(), math.pi)
+        self.assert_almost_equal_vec(arc.tangent(0), [0, 1])
+        self.assert_almost_equal_vec(arc.reverse().tangent(0), [1, 0])
+        self.assertAlmostEqual(arc.distance(Vector2D([3, 3])), math.sqrt(18) - 2)
+        self.assertAlmostEqual(arc.distance(Vector2D([3, -1])), math.sqrt(2))
+
+        line = arc.get_polyline(0.01)
+        self.assert_almost_equal_vec(line.nodes[0], [2, 0])
+        self.assert_almost_equal_vec(line.nodes[-1], [0, 2])
+
+    def test_intersection(self):
+        """only intersections within the arc are returned"""
+        arc = Arc2D(Vector2D([0, 0]), 1, 0, math.pi)
+        line = Line2D(Vector2D([-2, 0.5]), Vector2D([2, 0.5]))
+        self.assertEqual(len(arc.intersect_line(line)), 2)
+
+        line = Line2D(Vector2D([-2, -0.5]), Vector2D([2, -0.5]))
+        self.assertEqual(len(arc.intersect_line(line)), 0)
+
+        polyline = PolyLine2D([[0, 0], [0, 2], [2, 2]])
+        cuts = arc.intersect_polyline(polyline)
+        self.assertEqual(len(cuts), 1)
+        self.assert_almost_equal_vec(cuts[0][1], [0, 1])
+
+
+class TestPath2D(TestCase):
+    """Test euklid_rs.arc.Path2D"""
+
+    def test_from_polyline(self):
+        """arcs and segments are recovered from a discretized outline"""
+        arc = Arc2D(Vector2D([0, 0]), 1, -math.pi / 2, math.pi / 2)
+        # corners are not tangential
+        nodes = [Vector2D([-1, -2])] + arc.get_polyline(0.0001).nodes + [Vector2D([-1, 2])]
+        line = PolyLine2D(nodes)
+
+        path = Path2D.from_polyline(line, 0.001)
+        self.assertEqual(len(path), 3)
+        self.assertIsInstance(path[0], Segment2D)
+        self.assertIsInstance(path[1], Arc2D)
+        self.assertIsInstance(path[2], Segment2D)
+        self.assertAlmostEqual(path[1].radius, 1, 5)
+        self.assertAlmostEqual(path.get_length(), 2 * math.sqrt(2) + math.pi, 3)
+
+        # every input node is within the tolerance of the path
+        result = path.get_polyline(0.0001)
+        self.assert_almost_equal_vec(result.nodes[0], nodes[0])
+        self.assert_almost_equal_vec(result.nodes[-1], nodes[-1])
+        for node in nodes:
+            distance = min(
+                element.distance(node) for element in path.elements
+            )
+            self.assertLessEqual(distance, 0.001)
+
+    def test_tangent_continuity(self):
+        """biarcs of a smooth polyline join with a common tangent"""
+        line = PolyLine2D([[x / 20, math.sin(x / 20)] for x in range(200)])
+        path = Path2D.from_polyline(line, 1e-3)
+        self.assertGreater(len(path), 2)
+
+        def tangent(element, t):
+            if isinstance(element, Segment2D):
+                return element.direction
+            return element.tangent(t)
+
+        def ends(element):
+            if isinstance(element, Segment2D):
+                return element.p1, element.p2
+            return element.start(), element.end()
+
+        for first, second in zip(path.elements, path.elements[1:]):
+            self.assert_almost_equal_vec(ends(first)[1], ends(second)[0])
+            self.assert_almost_equal_vec(tangent(first, 1), tangent(second, 0), 6)
+
+        for node in line.nodes:
+            distance = min(element.distance(node) for element in path.elements)
+            self.assertLessEqual(distance, 1e-3)
+
+        # corners are kept, closed outlines are closed smoothly
+        square = Path2D.from_polyline(PolyLine2D([[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]]))
+        self.assertEqual(len(square), 4)
+        self.assertTrue(all(isinstance(element, Segment2D) for element in square.elements))
+
+        circle = Circle2D(Vector2D([0, 0]), 2).get_polyline(1e-4)
+        (arc,) = Path2D.from_polyline(circle, 1e-3).elements
+        self.assertAlmostEqual(abs(arc.sweep), 2 * math.pi)
+
+        with self.assertRaises(ValueError):
+            Path2D.from_polyline(line, 0)
+
+    def test_from_curve(self):
+        """curves are approximated within tolerance"""
+        curves = [
+            BezierCurve(PolyLine2D([[0, 0], [1, 2], [3, -1], [4, 1]])),
+            BezierCurve(PolyLine2D([[0, 0], [1, 2], [3, 2], [4, 0]])),
+            BSplineCurve3(PolyLine2D([[0, 0], [1, 2], [2, 0], [3, 2], [4, 0]])),
+        ]
+
+        for curve in curves:
+            path = Path2D.from_curve(curve, 0.001, 100)
+            self.assertLess(len(path), 50)
+            line = path.get_polyline()
+            self.assert_almost_equal_vec(line.nodes[0], curve.get(0))
+            self.assert_almost_equal_vec(line.nodes[-1], curve.get(1))
+
+            for i in range(501):
+                node = curve.get(i / 500)
+                distance = min(element.distance(node) for element in path.elements)
+                self.assertLessEqual(distance, 0.002)
+
+        with self.assertRaises(ValueError):
+            Path2D.from_curve(curves[0], 0.001, 1)
+
+    def test_elements(self):
+        """explicit construction"""
+        segment = Segment2D(Vector2D([0, 0]), Vector2D([1, 0]))
+        arc = Arc2D.from_bulge(Vector2D([1, 0]), Vector2D([1, 2]), 1)
+        path = Path2D([segment, arc])
+
+        self.assertEqual(len(path.elements), 2)
+        self.assertAlmostEqual(path.get_length(), 1 + math.pi)
+        line = path.get_polyline(0.01)
+        self.assert_almost_equal_vec(line.nodes[-1], [1, 2])
@@ -0,0 +1,94 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for bounding boxes"""
+import itertools
+import math
+import random
+
+from euklid_rs.polyline import PolyLine2D, PolyLine3D
+from euklid_rs.vector import Transformation, Vector2D, Vector3D
+
+from .base import TestCase
+
+
+class TestBoundingBox2D(TestCase):
+    """Test axis-aligned and oriented 2D boxes"""
+
+    def setUp(self) -> None:
+        # rectangle 4x1 rotated by 30°
+        rotation = Transformation.rotation(math.pi / 6, Vector3D([0, 0, 1]))
+        nodes = []
+        for x, y in [[0, 0], [4, 0], [4, 1], [0, 1], [2, 0.5], [1, 1]]:
+            p = rotation.apply(Vector3D([x + 1, y + 2, 0]))
+            nodes.append([p[0], p[1]])
+
+        self.line = PolyLine2D(nodes)
+
+    def test_boundary(self):
+        """boundary uses x and y values"""
+        line = PolyLine2D([[0, 1], [2, 5]])
+        self.assertEqual(line.boundary(), [0, 1, 2, 5])
+
+        bbox = line.bounding_box()
+        self.assertEqual(bbox.min, Vector2D([0, 1]))
+        self.assertEqual(bbox.max, Vector2D([2, 5]))
+        self.assertEqual(bbox.get_area(), 8)
+        self.assertTrue(bbox.contains(Vector2D([1, 1])))
+        self.assertFalse(bbox.contains(Vector2D([3, 1])))
+        self.assertEqual(len(bbox.corners()), 4)
+
+    def test_min_area(self):
+        """the minimum-area rectangle recovers the rotated rectangle"""
+        rectangle = self.line.min_area_box()
+
+        self.assertAlmostEqual(rectangle.get_area(), 4)
+        # the angle is only defined modulo 90°
+        self.assertAlmostEqual(math.sin(rectangle.angle * 4), math.sin(math.pi * 2 / 3))
+        self.assertLess(rectangle.get_area(), self.line.bounding_box().get_area())
+
+        for node in self.line:
+            local = rectangle.transformation.apply(node)
+            for i in range(2):
+                self.assertGreater(local[i], -1e-8)
+                self.assertLess(local[i], rectangle.size[i] + 1e-8)
+
+        corner = rectangle.corners[0]
+        self.assert_almost_equal_vec(rectangle.transformation.apply(corner), [0, 0])
+
+
+class TestBoundingBox3D(TestCase):
+    """Test axis-aligned and oriented 3D boxes"""
+
+    def setUp(self) -> None:
+        rotation = Transformation.rotation(0.4, Vector3D([1, 2, 3]))
+        self.nodes = [
+            rotation.apply(Vector3D([x * 3, y * 2, z]))
+            for x, y, z in itertools.product([0, 1], repeat=3)
+        ]
+        self.nodes += [rotation.apply(Vector3D([random.random() * 3, 1, 0.5])) for _ in range(10)]
+        self.line = PolyLine3D(self.nodes)
+
+    def test_bounding_box(self):
+        """axis aligned box"""
+        bbox = PolyLine3D([[0, 0, 0], [1, 2, 3]]).bounding_box()
+        self.assertEqual(bbox.get_volume(), 6)
+        self.assertEqual(bbox.center(), Vector3D([0.5, 1, 1.5]))
+        self.assertEqual(len(bbox.corners()), 8)
+
+    def test_pca_box(self):
+        """pca box of a rotated cuboid"""
+        box = self.line.pca_box()
+        self.assertAlmostEqual(box.get_volume(), 6, 4)
+
+    def test_min_volume_box(self):
+        """minimum volume box of a rotated cuboid"""
+        box = self.line.min_volume_box()
+        self.assertAlmostEqual(box.get_volume(), 6)
+        self.assertLess(box.get_volume(), self.line.bounding_box().get_volume())
+
+        for node in self.nodes:
+            local = box.transformation.apply(node)
+            for i in range(3):
+                self.assertGreater(local[i], -1e-8)
+                self.assertLess(local[i], box.size[i] + 1e-8)
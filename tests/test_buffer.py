@@ -0,0 +1,82 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for the buffer protocol (numpy interop)"""
+
+import array
+import math
+
+from euklid_rs.polyline import PolyLine2D, PolyLine3D
+from euklid_rs.vector import Vector2D, Vector3D
+
+from .base import TestCase
+
+
+def float_array(rows):
+    """a (n, len(row)) float64 memoryview, like a numpy array"""
+    flat = array.array("d", [value for row in rows for value in row])
+
+    return memoryview(flat).cast("B").cast("d", (len(rows), len(rows[0])))
+
+
+class TestBuffer(TestCase):
+    """Test buffer export and import"""
+
+    def test_vector(self):
+        """vectors export their coordinates"""
+        view = memoryview(Vector3D([1, 2, 3]))
+
+        self.assertEqual(view.format, "d")
+        self.assertEqual(view.shape, (3,))
+        self.assertTrue(view.readonly)
+        self.assertEqual(view.tolist(), [1, 2, 3])
+
+    def test_polyline(self):
+        """polylines export their nodes as an (n, dimensions) array"""
+        line = PolyLine2D([[0, 0], [1, 2], [3, 4]])
+        view = line.nodes_array
+
+        self.assertEqual(view.format, "d")
+        self.assertEqual(view.shape, (3, 2))
+        self.assertTrue(view.readonly)
+        self.assertEqual(view.tolist(), line.tolist())
+        self.assertEqual(memoryview(PolyLine3D([[0, 1, 2]])).shape, (1, 3))
+
+    def test_view(self):
+        """exported views share the nodes and stay valid for in-place changes"""
+        line = PolyLine2D([[1, 0], [2, 0]])
+        view = line.nodes_array
+
+        line.rotate_inplace(math.pi / 2)
+        line.move_inplace(Vector2D([1, 1]))
+
+        for node, row in zip(line.nodes, view.tolist()):
+            self.assert_almost_equal_vec(node, Vector2D(row))
+
+        with self.assertRaises(TypeError):
+            view[0, 0] = 1
+
+    def test_construction(self):
+        """polylines are built from (n, dimensions) float64 arrays"""
+        rows = [[0, 0, 0], [1, 2, 3], [4, 5, 6]]
+        line = PolyLine3D(float_array(rows))
+
+        self.assertEqual(line.tolist(), rows)
+        self.assertEqual(PolyLine3D(line).tolist(), rows)
+
+        with self.assertRaises(ValueError):
+            PolyLine2D(float_array(rows))
+
+    def test_get(self):
+        """get returns an array for many indices"""
+        line = PolyLine2D([[0, 0], [1, 0], [1, 2]])
+        iks = [0, 0.5, 1.5, 2]
+
+        result = line.get(iks)
+        self.assertEqual(result.shape, (4, 2))
+        self.assertEqual(result.tolist(), [line.get(ik).tolist() for ik in iks])
+
+        result = line.get(memoryview(array.array("d", iks)))
+        self.assertEqual(result.tolist(), [[0, 0], [0.5, 0], [1, 1], [1, 2]])
+
+        self.assertEqual(line.get(0.5), Vector2D([0.5, 0]))
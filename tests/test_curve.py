@@ -31,6 +31,46 @@ class CurveTestBase(TestCase):
         sequence = self.curve.get_sequence(50)
         self.assertEqual(len(sequence), 50)
 
+    def long_curve(self):
+        """a curve with enough controlpoints for all curve types"""
+        points = [[0, 0], [1, 0], [1, 1], [2, 1], [2, 2]]
+        return self.CurveType(euklid_rs.polyline.PolyLine2D(points))
+
+    def test_many(self):
+        """Test batch evaluation"""
+        self.curve = self.long_curve()
+        xs = [0.1, 0.5, 0.9]
+
+        self.assertEqual(self.curve.get_many(xs).tolist(), [self.curve.get(x).tolist() for x in xs])
+        self.assertEqual(
+            self.curve.derivative_many(xs).tolist(),
+            [self.curve.derivative(x).tolist() for x in xs],
+        )
+
+    def test_derivative(self):
+        """Test derivative against finite differences"""
+        self.curve = self.long_curve()
+        h = 1e-6
+        for x in [0.1, 0.3, 0.5, 0.9]:
+            difference = (self.curve.get(x + h) - self.curve.get(x - h)) * (1 / (2 * h))
+            self.assert_almost_equal_vec(self.curve.derivative(x), difference, 4)
+
+    def test_walk_many(self):
+        """Test walking along the curve"""
+        self.curve = self.long_curve()
+        line = euklid_rs.polyline.PolyLine2D(self.curve.get_many([i / 1000 for i in range(1001)]))
+        length = line.get_length()
+        start, middle, end = self.curve.walk_many(0, [0, length / 2, length])
+
+        self.assertEqual(start, 0)
+        self.assertAlmostEqual(end, 1, 6)
+
+        first_half = euklid_rs.polyline.PolyLine2D(self.curve.get_many([i / 1000 * middle for i in range(1001)]))
+        self.assertAlmostEqual(first_half.get_length(), length / 2, 3)
+
+        with self.assertRaises(ValueError):
+            self.curve.walk_many(0, [1], segments=0)
+
 
 class BezierTest(CurveTestBase):
     """
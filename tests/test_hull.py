@@ -0,0 +1,108 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for convex hulls"""
+import itertools
+import math
+import random
+import unittest
+from euklid_rs.polyline import PolyLine2D, PolyLine3D
+from euklid_rs.vector import Vector2D
+
+
+class TestConvexHull2D(unittest.TestCase):
+    """Test PolyLine2D.convex_hull"""
+
+    def test_square(self):
+        """inner, duplicate and collinear points are dropped"""
+        line = PolyLine2D(
+            [[0, 0], [1, 0], [0.5, 0], [1, 1], [0.5, 0.5], [0, 1], [1, 1], [0, 0.5]]
+        )
+        hull = line.convex_hull()
+
+        self.assertEqual(len(hull), 5)
+        self.assertEqual(hull[0], hull[-1])
+        self.assertEqual(hull[0], Vector2D([0, 0]))
+        self.assertEqual(hull[1], Vector2D([1, 0]))
+
+    def test_collinear(self):
+        """collinear points result in a degenerate hull"""
+        hull = PolyLine2D([[0, 0], [1, 1], [2, 2], [3, 3]]).convex_hull()
+
+        self.assertEqual(len(hull), 3)
+        self.assertEqual(hull[1], Vector2D([3, 3]))
+
+    def test_random(self):
+        """every node is inside the (ccw) hull"""
+        line = PolyLine2D([[random.random(), random.random()] for _ in range(200)])
+        hull = line.convex_hull()
+
+        for i in range(len(hull) - 1):
+            segment = hull[i + 1] - hull[i]
+            for node in line:
+                self.assertGreaterEqual(segment.cross(node - hull[i]), -1e-12)
+
+    def test_not_finite(self):
+        """nan and inf nodes are rejected"""
+        for value in (math.nan, math.inf):
+            line = PolyLine2D([[0, 0], [1, 0], [value, 1], [0, 1]])
+            with self.assertRaises(ValueError):
+                line.convex_hull()
+            with self.assertRaises(ValueError):
+                line.min_area_box()
+
+
+class TestConvexHull3D(unittest.TestCase):
+    """Test PolyLine3D.convex_hull"""
+
+    def test_cube(self):
+        """cube corners with inner and duplicate points"""
+        corners = [list(p) for p in itertools.product([0, 1], repeat=3)]
+        line = PolyLine3D(corners + corners + [[0.5, 0.5, 0.5], [0.5, 0, 0]])
+        mesh = line.convex_hull()
+
+        self.assertEqual(len(mesh.vertices), 8)
+        self.assertEqual(len(mesh), 12)
+        self.assertAlmostEqual(mesh.get_volume(), 1)
+        self.assertAlmostEqual(mesh.get_area(), 6)
+
+    def test_random(self):
+        """points on a sphere are all hull vertices"""
+        nodes = []
+        for _ in range(100):
+            vec = [random.gauss(0, 1) for _ in range(3)]
+            length = sum(x**2 for x in vec) ** 0.5
+            nodes.append([x / length for x in vec])
+
+        mesh = PolyLine3D(nodes).convex_hull()
+
+        self.assertEqual(len(mesh.vertices), 100)
+        self.assertEqual(len(mesh), 2 * 100 - 4)
+        self.assertGreater(mesh.get_volume(), 0)
+
+    def test_coplanar(self):
+        """coplanar points result in a flat hull"""
+        mesh = PolyLine3D([[0, 0, 1], [1, 0, 1], [1, 1, 1], [0, 1, 1], [0.5, 0.5, 1]]).convex_hull()
+
+        self.assertEqual(len(mesh.vertices), 4)
+        self.assertAlmostEqual(mesh.get_area(), 1)
+
+    def test_collinear(self):
+        """collinear points have no hull"""
+        with self.assertRaises(ValueError):
+            PolyLine3D([[0, 0, 0], [1, 1, 1], [2, 2, 2], [2, 2, 2]]).convex_hull()
+
+    def test_not_finite(self):
+        """nan and inf nodes are rejected"""
+        for value in (math.nan, math.inf):
+            line = PolyLine3D([[0, 0, 0], [1, 0, 0], [0, value, 0], [0, 0, 1]])
+            with self.assertRaises(ValueError):
+                line.convex_hull()
+            with self.assertRaises(ValueError):
+                line.min_volume_box()
+            with self.assertRaises(ValueError):
+                line.pca_box()
+
+
+if __name__ == "__main__":
+    unittest.main(exit=False)
@@ -0,0 +1,456 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for file export and import"""
+
+import io
+import math
+import os
+from euklid_rs.arc import Arc2D, Circle2D, Path2D
+from euklid_rs.io import DXFDocument, OBJDocument, PLY, STL, PlotterWriter, SVGDocument
+from euklid_rs.line import Segment2D
+from euklid_rs.mesh import Mesh
+from euklid_rs.polyline import PolyLine2D, PolyLine3D
+from euklid_rs.spline import BezierCurve, BSplineCurve3, BSplineCurve3_3D
+from euklid_rs.vector import Vector2D, Vector3D
+
+from .base import TestCase
+
+DATA = os.path.join(os.path.dirname(__file__), "data")
+
+
+def golden(name):
+    """content of a reference file"""
+    with open(os.path.join(DATA, name), encoding="utf-8") as file:
+        return file.read()
+
+
+def polyline_distance(line, point):
+    """distance of a point to a polyline"""
+    return min(
+        Segment2D(p1, p2).distance(point)
+        for p1, p2 in zip(line.nodes[:-1], line.nodes[1:])
+        if (p2 - p1).length() > 0
+    )
+
+
+class TestPlotterWriter(TestCase):
+    """Test euklid_rs.io.PlotterWriter"""
+
+    def setUp(self) -> None:
+        self.writer = PlotterWriter(lead_in=1, lead_out=0.5)
+        self.writer.add(PolyLine2D([[20, 0], [30, 0], [30, 10], [20, 10], [20, 0]]))
+        self.writer.add(
+            Path2D(
+                [
+                    Segment2D(Vector2D([10, 10]), Vector2D([5, 10])),
+                    Arc2D(Vector2D([5, 5]), 5, math.pi / 2, math.pi),
+                ]
+            )
+        )
+
+    def test_gcode(self):
+        """g-code with arcs, lead-in and lead-out"""
+        self.assertEqual(self.writer.to_gcode(), golden("plotter.gcode"))
+
+    def test_hpgl(self):
+        """hpgl in plotter units"""
+        self.assertEqual(self.writer.to_hpgl(), golden("plotter.hpgl"))
+
+    def test_ordering(self):
+        """paths are reordered and reversed to reduce travel"""
+        writer = PlotterWriter(optimize=False)
+        for i in range(5):
+            writer.add(PolyLine2D([[0, i], [10, i]]))
+        writer.add(PolyLine2D([[100, 100], [100, 101], [100, 100]]))
+
+        self.assertEqual(len(writer), 6)
+        unordered = writer.get_travel_length()
+        writer.optimize = True
+        self.assertLess(writer.get_travel_length(), unordered)
+        self.assertAlmostEqual(writer.get_travel_length(), 4 + math.hypot(90, 96))
+
+    def test_settings(self):
+        """units, tool commands and split paths"""
+        writer = PlotterWriter(units="in", tool_up="G0 Z5", tool_down="G1 Z-1", precision=1)
+        writer.add(
+            Path2D(
+                [
+                    Segment2D(Vector2D([0, 0]), Vector2D([1, 0])),
+                    Segment2D(Vector2D([2, 0]), Vector2D([3, 0])),
+                ]
+            )
+        )
+        self.assertEqual(len(writer), 2)
+
+        gcode = writer.to_gcode().splitlines()
+        self.assertEqual(gcode[0], "G20")
+        self.assertEqual(gcode.count("G1 Z-1"), 2)
+        self.assertIn("G1 X3.0 Y0.0 F1000.0", gcode)
+        self.assertIn("PD3048,0;", writer.to_hpgl().splitlines())
+
+        with self.assertRaises(ValueError):
+            PlotterWriter(units="cm")
+
+
+class TestSVGDocument(TestCase):
+    """Test euklid_rs.io.SVGDocument"""
+
+    def setUp(self) -> None:
+        self.document = SVGDocument()
+        self.document.add_layer("cut", stroke="red", stroke_width=0.5, dash=[2, 1])
+        self.document.add(PolyLine2D([[0, 0], [10, 0], [10, 5], [0, 0]]), "cut", closed=True)
+        self.document.add(Circle2D(Vector2D([5, 5]), 2))
+        self.document.add(Arc2D(Vector2D([0, 0]), 3, 0, math.pi / 2))
+        self.document.add(BezierCurve(PolyLine2D([[0, 0], [1, 2], [3, -1], [4, 1]])), "curves")
+
+    def test_write(self):
+        """layers, styles, native arcs and curves"""
+        self.assertEqual(self.document.layers, ["cut", "default", "curves"])
+        self.assertEqual(self.document.to_svg(1, "mm"), golden("drawing.svg"))
+
+    def test_roundtrip(self):
+        """written documents are read back within tolerance"""
+        document = SVGDocument.from_svg(self.document.to_svg(), 1e-4)
+        self.assertEqual(document.layers, ["cut", "default", "curves"])
+
+        (polygon,) = document.get_layer("cut")
+        self.assertEqual(len(polygon), 4)
+        self.assert_almost_equal_vec(polygon.nodes[2], [10, 5])
+        self.assert_almost_equal_vec(polygon.nodes[-1], [0, 0])
+
+        circle, arc = document.get_layer("default")
+        for node in circle.nodes:
+            self.assertAlmostEqual((node - Vector2D([5, 5])).length(), 2, 3)
+        for node in arc.nodes:
+            self.assertAlmostEqual(node.length(), 3, 3)
+        self.assert_almost_equal_vec(arc.nodes[0], [3, 0], 3)
+        self.assert_almost_equal_vec(arc.nodes[-1], [0, 3], 3)
+
+        curve = BezierCurve(PolyLine2D([[0, 0], [1, 2], [3, -1], [4, 1]]))
+        (line,) = document.get_layer("curves")
+        for i in range(21):
+            self.assertLess(polyline_distance(line, curve.get(i / 20)), 1e-3)
+
+        with self.assertRaises(KeyError):
+            document.get_layer("missing")
+
+    def test_bspline(self):
+        """cubic b-splines are written as bezier segments"""
+        curve = BSplineCurve3(PolyLine2D([[0, 0], [1, 2], [3, -1], [4, 1], [6, 0], [7, 3]]))
+        document = SVGDocument(flip_y=False)
+        document.add(curve)
+        self.assertEqual(document.to_svg().count(" C "), 3)
+
+        (line,) = document.get_layer("default")
+        for i in range(51):
+            self.assertLess(polyline_distance(line, curve.get(i / 50)), 2e-3)
+
+    def test_parse_path(self):
+        """path commands, relative coordinates and subpaths"""
+        lines = SVGDocument.parse_path("M0 0 h10 v10 H0 z m1 1 l1-1.5.5.5", flip_y=False)
+        self.assertEqual(len(lines), 2)
+        self.assertEqual(len(lines[0]), 5)
+        self.assert_almost_equal_vec(lines[0].nodes[-1], [0, 0])
+        self.assert_almost_equal_vec(lines[1].nodes[0], [1, 1])
+        self.assert_almost_equal_vec(lines[1].nodes[1], [2, -0.5])
+        self.assert_almost_equal_vec(lines[1].nodes[2], [2.5, 0])
+
+        (line,) = SVGDocument.parse_path("M 1 0 A 1 1 0 0 1 -1 0", 1e-4)
+        self.assert_almost_equal_vec(line.get(len(line) / 2 - 0.5), [0, -1], 3)
+        for node in line.nodes:
+            self.assertAlmostEqual(node.length(), 1)
+
+        (line,) = SVGDocument.parse_path("M 0 0 Q 1 2 2 0 T 4 0", 1e-4, False)
+        self.assert_almost_equal_vec(line.nodes[-1], [4, 0])
+        self.assertLess(min(node[1] for node in line.nodes), -0.99)
+
+        (line,) = SVGDocument.parse_path("M 0 0 C 0 1 1 1 1 0 S 2 -1 2 0", 1e-4, False)
+        self.assertGreater(max(node[1] for node in line.nodes), 0.74)
+        self.assertLess(min(node[1] for node in line.nodes), -0.74)
+
+        with self.assertRaises(ValueError):
+            SVGDocument.parse_path("L 1 1")
+        with self.assertRaises(ValueError):
+            SVGDocument.parse_path("M 0 0 X 1 1")
+
+    def test_read_elements(self):
+        """polylines, polygons and lines in nested groups"""
+        text = """<svg xmlns="http://www.w3.org/2000/svg">
+          <g id="outer"><g id="inner">
+            <polygon points="0,0 1,0 1,1"/>
+          </g>
+          <line x1="0" y1="0" x2="2" y2="0"/></g>
+          <polyline points="0 0 1 1 2 0"/>
+        </svg>"""
+        document = SVGDocument.from_svg(text, flip_y=False)
+        self.assertEqual(document.layers, ["inner", "outer", "default"])
+        self.assertEqual(len(document.get_layer("inner")[0]), 4)
+        self.assert_almost_equal_vec(document.get_layer("outer")[0].nodes[1], [2, 0])
+        self.assertEqual(len(document.get_layer("default")[0]), 3)
+
+    def test_read_transform(self):
+        """group and element transforms are applied"""
+        text = """<svg xmlns="http://www.w3.org/2000/svg" viewBox="-10 -10 20 20">
+          <g id="moved" transform="translate(10, 5) scale(2)">
+            <line x1="0" y1="0" x2="1" y2="0" transform="rotate(90)"/>
+            <polyline points="1 1 2 1"/>
+          </g>
+          <path d="M 0 0 L 0 1" transform="matrix(1 0 0 1 3 4) skewX(45)"/>
+        </svg>"""
+        document = SVGDocument.from_svg(text, flip_y=False)
+
+        line, polyline = document.get_layer("moved")
+        self.assert_almost_equal_vec(line.nodes[0], [10, 5])
+        self.assert_almost_equal_vec(line.nodes[1], [10, 7])
+        self.assert_almost_equal_vec(polyline.nodes[0], [12, 7])
+        self.assert_almost_equal_vec(polyline.nodes[1], [14, 7])
+
+        (path,) = document.get_layer("default")
+        self.assert_almost_equal_vec(path.nodes[0], [3, 4])
+        self.assert_almost_equal_vec(path.nodes[1], [4, 5])
+
+        with self.assertRaises(ValueError):
+            SVGDocument.from_svg('<svg><path d="M 0 0 L 1 0" transform="spin(1)"/></svg>')
+
+    def test_tolerance(self):
+        """non-positive tolerances are rejected"""
+        with self.assertRaises(ValueError):
+            SVGDocument(tolerance=0)
+        with self.assertRaises(ValueError):
+            SVGDocument.from_svg("<svg/>", 0)
+        with self.assertRaises(ValueError):
+            SVGDocument.parse_path("M 0 0 L 1 1", -1)
+
+        self.document.tolerance = 1e-2
+        self.assertEqual(self.document.tolerance, 1e-2)
+        with self.assertRaises(ValueError):
+            self.document.tolerance = 0
+        self.assertEqual(self.document.tolerance, 1e-2)
+        self.document.to_svg()
+
+
+class TestDXFDocument(TestCase):
+    """Test euklid_rs.io.DXFDocument"""
+
+    def setUp(self) -> None:
+        document = DXFDocument()
+        document.add(PolyLine2D([[0, 0], [10, 0], [10, 5], [0, 0]]), "cut")
+        document.add(
+            Path2D(
+                [
+                    Segment2D(Vector2D([0, 0]), Vector2D([1, 0])),
+                    Arc2D.from_bulge(Vector2D([1, 0]), Vector2D([1, 2]), 1),
+                ]
+            ),
+            "cut",
+        )
+        document.add(PolyLine3D([[0, 0, 0], [1, 0, 1], [1, 1, 2]]), "rib")
+        document.add(Arc2D(Vector2D([0, 0]), 3, math.pi / 2, 0))
+        document.add(Circle2D(Vector2D([5, 5]), 2))
+        document.add(BSplineCurve3(PolyLine2D([[0, 0], [1, 2], [3, -1], [4, 1], [6, 0]])), "curves")
+        document.add(BSplineCurve3_3D(PolyLine3D([[0, 0, 0], [1, 2, 1], [3, -1, 1], [4, 1, 0]])), "curves")
+        self.document = document
+
+    def test_roundtrip(self):
+        """entities and layers survive writing and reading"""
+        result = DXFDocument.from_dxf(self.document.to_dxf())
+        self.assertEqual(result.layers, ["cut", "rib", "0", "curves"])
+
+        polygon, path = result.get_layer("cut")
+        self.assertEqual(len(polygon), 4)
+        self.assert_almost_equal_vec(polygon.nodes[-1], [0, 0])
+        self.assertEqual(len(path), 2)
+        self.assertAlmostEqual(path[1].bulge(), 1)
+        self.assertAlmostEqual(path.get_length(), 1 + math.pi)
+
+        (rib,) = result.get_layer("rib")
+        self.assertIsInstance(rib, PolyLine3D)
+        self.assert_almost_equal_vec(rib.nodes[2], [1, 1, 2])
+
+        arc, circle = result.get_layer("0")
+        self.assert_almost_equal_vec(arc.start(), [3, 0])
+        self.assert_almost_equal_vec(arc.end(), [0, 3])
+        self.assertAlmostEqual(circle.radius, 2)
+
+        spline_2d, spline_3d = result.get_layer("curves")
+        self.assertIsInstance(spline_2d, BSplineCurve3)
+        self.assertIsInstance(spline_3d, BSplineCurve3_3D)
+        self.assert_almost_equal_vec(spline_2d.controlpoints.nodes[3], [4, 1])
+        self.assert_almost_equal_vec(spline_3d.get(0.5), [2, 0.5, 0.75])
+
+    def test_write(self):
+        """r2000 output with handles, owners and subclass markers"""
+        text = self.document.to_dxf()
+        self.assertEqual(text, golden("document.dxf"))
+
+        lines = text.splitlines()
+        pairs = list(zip(lines[::2], lines[1::2]))
+        handles = [int(value, 16) for code, value in pairs[7:] if code.strip() in ("5", "105")]
+        self.assertEqual(len(handles), len(set(handles)))
+        self.assertEqual(pairs[5], ("  5", f"{max(handles) + 1:X}"))
+
+        start = pairs.index(("  2", "ENTITIES"))
+        entities = []
+        for code, value in pairs[start + 1 : pairs.index(("  0", "ENDSEC"), start)]:
+            if code == "  0":
+                entities.append([])
+            entities[-1].append((code.strip(), value))
+
+        self.assertEqual(len(entities), 11)
+        for entity in entities:
+            self.assertEqual([code for code, _ in entity[:4]], ["0", "5", "330", "100"])
+            self.assertEqual(entity[3][1], "AcDbEntity")
+
+        subclasses = {
+            "LWPOLYLINE": ["AcDbPolyline"],
+            "POLYLINE": ["AcDb3dPolyline"],
+            "VERTEX": ["AcDbVertex", "AcDb3dPolylineVertex"],
+            "SEQEND": [],
+            "ARC": ["AcDbCircle", "AcDbArc"],
+            "CIRCLE": ["AcDbCircle"],
+            "SPLINE": ["AcDbSpline"],
+        }
+        for entity in entities:
+            markers = [value for code, value in entity[4:] if code == "100"]
+            self.assertEqual(markers, subclasses[entity[0][1]])
+
+    def test_ezdxf(self):
+        """the golden output is accepted by an independent reader"""
+        try:
+            import ezdxf  # pylint: disable=import-outside-toplevel
+        except ImportError:
+            self.skipTest("ezdxf is not installed")
+
+        document = ezdxf.read(io.StringIO(golden("document.dxf")))
+        self.assertEqual(document.dxfversion, "AC1015")
+        auditor = document.audit()
+        self.assertEqual(len(auditor.errors), 0)
+
+        types = [entity.dxftype() for entity in document.modelspace()]
+        self.assertEqual(types, ["LWPOLYLINE", "LWPOLYLINE", "POLYLINE", "ARC", "CIRCLE", "SPLINE", "SPLINE"])
+        self.assertEqual(len(list(document.modelspace().query("POLYLINE")[0].vertices)), 3)
+
+    def test_read(self):
+        """r12 polylines with bulges, mirrored arcs and general splines"""
+        document = DXFDocument.from_dxf(golden("entities.dxf"), spline_samples=8)
+        self.assertEqual(document.layers, ["outline", "0"])
+
+        path, line = document.get_layer("outline")
+        self.assertIsInstance(path, Path2D)
+        self.assertEqual(len(path), 3)
+        self.assertAlmostEqual(path.get_length(), 2 + math.pi)
+        self.assert_almost_equal_vec(line.nodes[1], [2, 2, 1])
+
+        arc, spline = document.get_layer("0")
+        self.assert_almost_equal_vec(arc.center, [-1, 0])
+        self.assert_almost_equal_vec(arc.start(), [-1, 1])
+        self.assert_almost_equal_vec(arc.end(), [-2, 0])
+
+        # quadratic spline with a single bezier span
+        self.assertEqual(len(spline), 9)
+        self.assert_almost_equal_vec(spline.nodes[4], [1, 1])
+        self.assert_almost_equal_vec(spline.nodes[-1], [2, 0])
+
+        with self.assertRaises(ValueError):
+            DXFDocument.from_dxf("  0\nSECTION\nX\nENTITIES\n")
+        with self.assertRaises(ValueError):
+            DXFDocument.from_dxf(golden("entities.dxf"), spline_samples=0)
+
+    def test_short_splines(self):
+        """curves with less than four controlpoints are written with a lower degree"""
+        document = DXFDocument()
+        document.add(BSplineCurve3(PolyLine2D([[0, 0], [1, 1]])))
+        document.add(BSplineCurve3(PolyLine2D([[0, 0], [1, 2], [2, 0]])))
+        document.add(BSplineCurve3_3D(PolyLine3D([[0, 0, 0], [1, 1, 1]])))
+
+        text = document.to_dxf()
+        self.assertIn(" 71\n1\n", text)
+        self.assertIn(" 71\n2\n", text)
+
+        line, quadratic, line_3d = DXFDocument.from_dxf(text, spline_samples=4).get_layer("0")
+        self.assertEqual(len(line), 5)
+        self.assert_almost_equal_vec(line.nodes[0], [0, 0])
+        self.assert_almost_equal_vec(line.nodes[2], [0.5, 0.5])
+        self.assert_almost_equal_vec(line.nodes[-1], [1, 1])
+        self.assert_almost_equal_vec(quadratic.nodes[2], [1, 1])
+        self.assert_almost_equal_vec(quadratic.nodes[-1], [2, 0])
+        self.assert_almost_equal_vec(line_3d.nodes[-1], [1, 1, 1])
+
+
+class TestMeshIO(TestCase):
+    """Test euklid_rs.io mesh formats"""
+
+    def setUp(self) -> None:
+        vertices = [Vector3D(p) for p in [[0, 0, 0], [1, 0, 0], [0, 1, 0], [0, 0, 1]]]
+        self.mesh = Mesh(vertices, [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]])
+
+    def assert_tetrahedron(self, mesh):
+        """mesh equals the unit tetrahedron"""
+        self.assertEqual(len(mesh.vertices), 4)
+        self.assertEqual(len(mesh), 4)
+        self.assertAlmostEqual(mesh.get_volume(), 1 / 6)
+        self.assertAlmostEqual(mesh.get_area(), self.mesh.get_area())
+
+    def test_stl(self):
+        """binary and ascii stl, vertices are merged"""
+        data = STL.write(self.mesh)
+        self.assertEqual(len(data), 84 + 4 * 50)
+        self.assert_tetrahedron(STL.read(data))
+
+        data = STL.write(self.mesh, binary=False, name="tetrahedron")
+        self.assertTrue(data.startswith(b"solid tetrahedron\n  facet normal 0 0 -1"))
+        self.assert_tetrahedron(STL.read(data))
+
+        with self.assertRaises(ValueError):
+            STL.read(b"no stl")
+
+    def test_ply(self):
+        """binary and ascii ply, polygons are triangulated"""
+        self.assert_tetrahedron(PLY.read(PLY.write(self.mesh)))
+        self.assert_tetrahedron(PLY.read(PLY.write(self.mesh, binary=False)))
+
+        data = b"""ply
+format ascii 1.0
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+element face 1
+property list uchar uint vertex_index
+end_header
+0 0 0 255
+2 0 0 255
+2 1 0 255
+0 1 0 255
+4 0 1 2 3
+"""
+        mesh = PLY.read(data)
+        self.assertEqual(len(mesh), 2)
+        self.assertAlmostEqual(mesh.get_area(), 2)
+
+    def test_obj(self):
+        """groups with meshes and polylines"""
+        document = OBJDocument()
+        document.add(self.mesh, "body")
+        document.add(PolyLine3D([[0, 0, 0], [1, 1, 1], [2, 0, 0]]), "lines")
+
+        text = document.to_obj()
+        self.assertIn("l 5 6 7", text.splitlines())
+
+        result = OBJDocument.from_obj(text)
+        self.assertEqual(result.groups, ["body", "lines"])
+        (mesh,) = result.get_group("body")
+        self.assert_tetrahedron(mesh)
+        (line,) = result.get_group("lines")
+        self.assert_almost_equal_vec(line.nodes[1], [1, 1, 1])
+
+        text = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\no quad\nf 1/1/1 2/2/1 3//1 -1\nl 1 3\n"
+        mesh, line = OBJDocument.from_obj(text).get_group("quad")
+        self.assertEqual(len(mesh), 2)
+        self.assertAlmostEqual(mesh.get_area(), 1)
+        self.assertEqual(len(line), 2)
+
+        with self.assertRaises(ValueError):
+            OBJDocument.from_obj("v 0 0 0\nf 1 2 3\n")
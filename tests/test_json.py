@@ -0,0 +1,130 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for json serialization"""
+
+import json
+import math
+
+from euklid_rs.arc import Arc2D, Circle2D, Path2D
+from euklid_rs.io import JSON_VERSION
+from euklid_rs.line import Line3D, Ray2D, Segment2D
+from euklid_rs.mesh import Mesh
+from euklid_rs.nesting import Nesting
+from euklid_rs.plane import Plane
+from euklid_rs.polyline import PolyLine2D, PolyLine3D
+from euklid_rs.spline import BezierCurve, BezierCurve3D, BSplineCurve3, BSplineCurve3_3D
+from euklid_rs.vector import (
+    Quaternion,
+    Rotation2D,
+    Transformation,
+    Transformation2D,
+    Vector2D,
+    Vector3D,
+)
+
+from .base import TestCase
+
+
+def samples():
+    """one instance of every serializable type"""
+    line_2d = PolyLine2D([[0, 0], [1.5, 0.25], [3, 1], [2, 4]])
+    line_3d = PolyLine3D([[0, 0, 0], [1, 0.1, 0.3], [2, 1, 0], [1, 3, 2]])
+
+    nesting = Nesting(2)
+    nesting.add_panel(PolyLine2D([[0, 0], [1, 0], [1, 1], [0, 1]]))
+
+    return [
+        Vector2D([1 / 3, -2]),
+        Vector3D([0.1, 0.2, 0.3]),
+        line_2d,
+        line_3d,
+        Transformation.rotation(math.pi / 7, Vector3D([1, 2, 3])),
+        Transformation2D.rotation(0.3, Vector2D([1, 2])),
+        Rotation2D(math.pi / 5),
+        Quaternion.from_euler([0.1, 0.2, 0.3], "xyz"),
+        Plane(Vector3D([1, 2, 3]), Vector3D([1, 0, 0]), Vector3D([0, 0.6, 0.8])),
+        BezierCurve(line_2d),
+        BSplineCurve3(line_2d),
+        BezierCurve3D(line_3d),
+        BSplineCurve3_3D(line_3d),
+        line_2d.bounding_box(),
+        line_3d.bounding_box(),
+        line_2d.min_area_box(),
+        line_3d.pca_box(),
+        Segment2D(Vector2D([0, 0]), Vector2D([1, 2])),
+        Ray2D(Vector2D([0, 1]), Vector2D([1, 2])),
+        Line3D(Vector3D([0, 0, 0]), Vector3D([1, 2, 3])),
+        Circle2D(Vector2D([1, 1]), 2.5),
+        Arc2D(Vector2D([1, 1]), 2.5, 0.5, -1),
+        Path2D(
+            [
+                Segment2D(Vector2D([0, 0]), Vector2D([1, 0])),
+                Arc2D(Vector2D([1, 1]), 1, -math.pi / 2, 0),
+            ]
+        ),
+        Mesh([Vector3D([0, 0, 0]), Vector3D([1, 0, 0]), Vector3D([0, 1, 0])], [[0, 1, 2]]),
+        nesting.solve().placements[0],
+    ]
+
+
+class TestJson(TestCase):
+    """Test json round-trips"""
+
+    def test_round_trip(self):
+        """from_json restores every type without loss"""
+        for obj in samples():
+            with self.subTest(type(obj).__name__):
+                text = obj.to_json()
+                restored = type(obj).from_json(text)
+
+                self.assertIs(type(restored), type(obj))
+                self.assertEqual(restored.to_json(), text)
+
+    def test_envelope(self):
+        """to_json stores type and schema version"""
+        envelope = json.loads(Vector2D([1, 2]).to_json())
+
+        self.assertEqual(envelope, {"type": "Vector2D", "version": JSON_VERSION, "data": [1, 2]})
+
+    def test_dunder(self):
+        """__from_json__ accepts __json__ data positionally or as keyword arguments"""
+        for obj in samples():
+            with self.subTest(type(obj).__name__):
+                data = json.loads(json.dumps(obj.__json__()))
+                restored = type(obj).__from_json__(data)
+                self.assertEqual(restored.__json__(), obj.__json__())
+
+                if isinstance(data, dict):
+                    restored = type(obj).__from_json__(**data)
+                    self.assertEqual(restored.__json__(), obj.__json__())
+
+    def test_schema(self):
+        """the data of vectors and polylines is unchanged"""
+        self.assertEqual(Vector3D([1, 2, 3]).__json__(), [1, 2, 3])
+        self.assertEqual(PolyLine2D([[0, 1], [2, 3]]).__json__(), {"nodes": [[0, 1], [2, 3]]})
+
+        plane = Plane(Vector3D([0, 0, 0]), Vector3D([1, 0, 0]), Vector3D([0, 1, 0]))
+        restored = Plane.from_json(plane.to_json())
+        self.assert_almost_equal_vec(restored.normvector, Vector3D([0, 0, 1]))
+
+        curve = BSplineCurve3_3D(PolyLine3D([[0, 0, 0], [1, 1, 0], [2, 0, 1], [3, 1, 1]]))
+        restored = BSplineCurve3_3D.from_json(curve.to_json())
+        self.assert_almost_equal_vec(restored.get(0.3), curve.get(0.3))
+
+    def test_invalid(self):
+        """wrong types, newer versions and invalid data are rejected"""
+        text = Vector2D([1, 2]).to_json()
+
+        with self.assertRaises(ValueError):
+            Vector3D.from_json(text)
+
+        future = json.dumps({"type": "Vector2D", "version": JSON_VERSION + 1, "data": [1, 2]})
+        with self.assertRaises(ValueError):
+            Vector2D.from_json(future)
+
+        with self.assertRaises(ValueError):
+            Circle2D.__from_json__({"center": [0, 0], "radius": -1})
+
+        with self.assertRaises(KeyError):
+            Segment2D.__from_json__({"p1": [0, 0]})
@@ -0,0 +1,120 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for lines, rays and segments"""
+
+import math
+from euklid_rs.line import Line2D, Line3D, Ray2D, Segment2D, Segment3D
+from euklid_rs.vector import Vector2D, Vector3D
+
+from .base import TestCase
+
+
+class TestLinear2D(TestCase):
+    """Test euklid_rs.line 2D primitives"""
+
+    def test_evaluation(self):
+        """parametric evaluation and point distance"""
+        segment = Segment2D(Vector2D([0, 0]), Vector2D([2, 0]))
+        self.assert_almost_equal_vec(segment.get(0.25), [0.5, 0])
+        self.assert_almost_equal_vec(segment.direction, [1, 0])
+        self.assertAlmostEqual(segment.length(), 2)
+        self.assert_almost_equal_vec(segment.midpoint(), [1, 0])
+
+        point = Vector2D([-1, 1])
+        self.assertAlmostEqual(segment.closest_parameter(point), 0)
+        self.assertAlmostEqual(segment.distance(point), math.sqrt(2))
+        self.assertAlmostEqual(Line2D(segment.p1, segment.p2).distance(point), 1)
+        self.assertAlmostEqual(Ray2D(segment.p1, segment.p2).distance(point), math.sqrt(2))
+        self.assertTrue(segment.contains(Vector2D([1.5, 0])))
+        self.assertFalse(segment.contains(Vector2D([2.5, 0])))
+
+        with self.assertRaises(ValueError):
+            Segment2D(Vector2D([1, 1]), Vector2D([1, 1]))
+
+    def test_intersection_point(self):
+        """crossing and non-crossing segments"""
+        s1 = Segment2D(Vector2D([0, 0]), Vector2D([2, 2]))
+        s2 = Segment2D(Vector2D([0, 2]), Vector2D([2, 0]))
+
+        result = s1.intersect(s2)
+        self.assertEqual(result.kind, "point")
+        self.assertTrue(result)
+        self.assert_almost_equal_vec(result.point, [1, 1])
+        self.assertAlmostEqual(result.ik_1, 0.5)
+        self.assertAlmostEqual(result.ik_2, 0.5)
+
+        s3 = Segment2D(Vector2D([3, 0]), Vector2D([4, -1]))
+        result = s1.intersect(s3)
+        self.assertEqual(result.kind, "disjoint")
+        self.assertFalse(result)
+        self.assertIsNone(result.point)
+
+        # the infinite line does hit
+        result = Line2D(s3.p1, s3.p2).intersect(s1)
+        self.assertEqual(result.kind, "point")
+        self.assert_almost_equal_vec(result.point, [1.5, 1.5])
+        self.assertAlmostEqual(result.ik_1, -1.5)
+
+    def test_intersection_parallel(self):
+        """parallel, collinear and overlapping segments"""
+        s1 = Segment2D(Vector2D([0, 0]), Vector2D([2, 0]))
+
+        parallel = Segment2D(Vector2D([0, 1]), Vector2D([2, 1]))
+        self.assertEqual(s1.intersect(parallel).kind, "parallel")
+
+        collinear = Segment2D(Vector2D([3, 0]), Vector2D([5, 0]))
+        self.assertEqual(s1.intersect(collinear).kind, "collinear")
+
+        overlap = Segment2D(Vector2D([3, 0]), Vector2D([1, 0]))
+        result = s1.intersect(overlap)
+        self.assertEqual(result.kind, "overlap")
+        self.assertAlmostEqual(result.overlap[0], 0.5)
+        self.assertAlmostEqual(result.overlap[1], 1)
+
+        touching = Segment2D(Vector2D([2, 0]), Vector2D([4, 0]))
+        result = s1.intersect(touching)
+        self.assertEqual(result.kind, "point")
+        self.assert_almost_equal_vec(result.point, [2, 0])
+
+        ray = Ray2D(Vector2D([5, 0]), Vector2D([4, 0]))
+        result = s1.intersect(ray)
+        self.assertEqual(result.kind, "overlap")
+        self.assertAlmostEqual(result.overlap[0], 0)
+        self.assertAlmostEqual(result.overlap[1], 1)
+
+
+class TestLinear3D(TestCase):
+    """Test euklid_rs.line 3D primitives"""
+
+    def test_closest_points(self):
+        """closest points of skew lines"""
+        l1 = Line3D(Vector3D([0, 0, 0]), Vector3D([1, 0, 0]))
+        l2 = Line3D(Vector3D([0, 0, 1]), Vector3D([0, 1, 1]))
+
+        t1, t2, p1, p2 = l1.closest_points(l2)
+        self.assertAlmostEqual(t1, 0)
+        self.assertAlmostEqual(t2, 0)
+        self.assert_almost_equal_vec(p1, [0, 0, 0])
+        self.assert_almost_equal_vec(p2, [0, 0, 1])
+        self.assertAlmostEqual(l1.distance_to(l2), 1)
+        self.assertEqual(l1.intersect(l2).kind, "disjoint")
+
+    def test_segments(self):
+        """clamped closest points and intersections of segments"""
+        s1 = Segment3D(Vector3D([0, 0, 0]), Vector3D([1, 0, 0]))
+        s2 = Segment3D(Vector3D([2, -1, 1]), Vector3D([2, 1, 1]))
+
+        t1, t2, p1, p2 = s1.closest_points(s2)
+        self.assertAlmostEqual(t1, 1)
+        self.assertAlmostEqual(t2, 0.5)
+        self.assertAlmostEqual(s1.distance_to(s2), math.sqrt(2))
+
+        s3 = Segment3D(Vector3D([0.5, -1, -1]), Vector3D([0.5, 1, 1]))
+        result = s1.intersect(s3)
+        self.assertEqual(result.kind, "point")
+        self.assert_almost_equal_vec(result.point, [0.5, 0, 0])
+
+        parallel = Segment3D(Vector3D([0, 0, 1]), Vector3D([1, 0, 1]))
+        self.assertEqual(s1.intersect(parallel).kind, "parallel")
+        self.assertAlmostEqual(s1.distance_to(parallel), 1)
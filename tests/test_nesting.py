@@ -0,0 +1,119 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for nesting panels onto a roll"""
+import math
+import unittest
+
+from euklid_rs.line import Segment2D
+from euklid_rs.nesting import Nesting
+from euklid_rs.polyline import PolyLine2D
+
+
+def outline_distance(line, other):
+    """smallest distance between two non-intersecting outlines"""
+    return min(
+        Segment2D(p1, p2).distance(node)
+        for first, second in ((line, other), (other, line))
+        for p1, p2 in zip(first.nodes, first.nodes[1:])
+        for node in second.nodes
+    )
+
+
+def rectangle(width, height):
+    """closed rectangle"""
+    return PolyLine2D([[0, 0], [width, 0], [width, height], [0, height], [0, 0]])
+
+
+class TestNesting(unittest.TestCase):
+    """Test euklid_rs.nesting.Nesting"""
+
+    def assert_valid(self, nesting, panels, result):
+        """all panels are on the roll and do not overlap"""
+        placed = [p.apply(panel) for p, panel in zip(result.placements, panels)]
+        boxes = [line.bounding_box() for line in placed]
+
+        for box in boxes:
+            self.assertGreater(box.min[0], -1e-6)
+            self.assertGreater(box.min[1], -1e-6)
+            self.assertLess(box.max[1], nesting.width + 1e-6)
+            self.assertLess(box.max[0], result.length + 1e-6)
+
+        for i, line in enumerate(placed):
+            for other in placed[i + 1 :]:
+                cuts = [
+                    cut
+                    for cut in line.cut_with_polyline(other)
+                    if 1e-6 < cut[0] % 1 < 1 - 1e-6 and 1e-6 < cut[1] % 1 < 1 - 1e-6
+                ]
+                self.assertEqual(cuts, [])
+
+    def test_rectangles(self):
+        """four squares fill a 2x2 area"""
+        nesting = Nesting(2)
+        panels = [rectangle(1, 1) for _ in range(4)]
+        for panel in panels:
+            nesting.add_panel(panel)
+
+        result = nesting.solve()
+
+        self.assertEqual(len(result.placements), 4)
+        self.assertAlmostEqual(result.length, 2, 4)
+        self.assert_valid(nesting, panels, result)
+
+    def test_rotation(self):
+        """a long panel only fits rotated"""
+        nesting = Nesting(1, rotations=[0, math.pi / 2])
+        panel = rectangle(0.5, 3)
+        nesting.add_panel(panel)
+
+        result = nesting.solve()
+        self.assertAlmostEqual(result.length, 3)
+        self.assertAlmostEqual(result.placements[0].angle, math.pi / 2)
+
+        transformed = result.placements[0].transformation().apply(panel)
+        for node, expected in zip(transformed, result.placements[0].apply(panel)):
+            self.assertAlmostEqual((node - expected).length(), 0)
+
+    def test_grain_direction(self):
+        """panel rotations override the default rotations"""
+        nesting = Nesting(1, rotations=[0, math.pi / 2])
+        nesting.add_panel(rectangle(0.5, 3), rotations=[0])
+
+        with self.assertRaises(ValueError):
+            nesting.solve()
+
+    def test_spacing_and_triangles(self):
+        """triangles interlock, spacing is respected"""
+        nesting = Nesting(1, spacing=0.05, rotations=[0, math.pi])
+        panels = [PolyLine2D([[0, 0], [2, 0], [1, 0.9], [0, 0]]) for _ in range(4)]
+        for panel in panels:
+            nesting.add_panel(panel)
+
+        result = nesting.solve()
+
+        self.assertLess(result.length, 8)
+        self.assert_valid(nesting, panels, result)
+
+        placed = [p.apply(panel) for p, panel in zip(result.placements, panels)]
+        for i, line in enumerate(placed):
+            for other in placed[i + 1 :]:
+                self.assertGreater(outline_distance(line, other), 0.05 - 1e-6)
+
+    def test_invalid_panels(self):
+        """non-finite coordinates and rotations are rejected"""
+        nesting = Nesting(1)
+        with self.assertRaises(ValueError):
+            nesting.add_panel(PolyLine2D([[0, 0], [1, math.nan], [0, 1], [0, 0]]))
+        with self.assertRaises(ValueError):
+            nesting.add_panel(rectangle(1, 1), rotations=[math.inf])
+        self.assertEqual(len(nesting), 0)
+
+        with self.assertRaises(ValueError):
+            Nesting(math.nan)
+        with self.assertRaises(ValueError):
+            Nesting(1, spacing=-1)
+
+
+if __name__ == "__main__":
+    unittest.main(exit=False)
@@ -0,0 +1,112 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for pickle and copy support"""
+
+import copy
+import math
+import pickle
+
+from euklid_rs.arc import Arc2D, Circle2D, Path2D
+from euklid_rs.io import DXFDocument, OBJDocument, PLY, STL, PlotterWriter, SVGDocument
+from euklid_rs.line import Segment2D
+from euklid_rs.mesh import Mesh
+from euklid_rs.nesting import Nesting
+from euklid_rs.polyline import PolyLine2D, PolyLine3D
+from euklid_rs.spline import BezierCurve, BSplineCurve3
+from euklid_rs.vector import Vector2D, Vector3D
+
+from .base import TestCase
+from .test_json import samples
+
+
+def documents():
+    """one instance of every non-geometry type"""
+    line = PolyLine2D([[0, 0], [2, 0], [2, 1]])
+    arc = Arc2D(Vector2D([0, 0]), 1, 0, math.pi / 2)
+
+    nesting = Nesting(3, spacing=0.1, rotations=[0, math.pi])
+    nesting.add_panel(PolyLine2D([[0, 0], [1, 0], [1, 1], [0, 1]]))
+    nesting.add_panel(PolyLine2D([[0, 0], [2, 0], [0, 1]]), rotations=[0])
+
+    dxf = DXFDocument()
+    dxf.add(line, "outline")
+    dxf.add(Path2D([arc]), "outline")
+    dxf.add(Circle2D(Vector2D([1, 1]), 0.5), "holes")
+    dxf.add(BSplineCurve3(line), "marks")
+    dxf.add(PolyLine3D([[0, 0, 0], [1, 1, 1]]))
+
+    obj = OBJDocument()
+    obj.add(Mesh([Vector3D([0, 0, 0]), Vector3D([1, 0, 0]), Vector3D([0, 1, 0])], [[0, 1, 2]]), "panel")
+    obj.add(PolyLine3D([[0, 0, 0], [1, 1, 1]]), "lines")
+
+    svg = SVGDocument(flip_y=False, precision=2)
+    svg.add_layer("cut", stroke="red", stroke_width=0.5, dash=[1, 2])
+    svg.add(line, "cut", closed=True)
+    svg.add(arc, "cut")
+    svg.add(BezierCurve(PolyLine2D([[0, 0], [1, 1], [2, 0]])))
+    svg.add(BezierCurve(PolyLine2D([[0, 0], [1, 1], [2, 1], [3, 0]])))
+
+    writer = PlotterWriter(units="in", feed_rate=20, lead_in=0.1)
+    writer.add(line)
+    writer.add(Path2D([arc]))
+
+    return [
+        line.cut_nearest(Vector2D([1, -1]), Vector2D([1, 1]), 0),
+        Segment2D(Vector2D([0, 0]), Vector2D([1, 0])).intersect(Segment2D(Vector2D([0.5, -1]), Vector2D([0.5, 1]))),
+        Segment2D(Vector2D([0, 0]), Vector2D([1, 0])).intersect(Segment2D(Vector2D([0.5, 0]), Vector2D([2, 0]))),
+        nesting,
+        nesting.solve(),
+        dxf,
+        obj,
+        svg,
+        writer,
+    ]
+
+
+class TestPickle(TestCase):
+    """Test pickle, copy and deepcopy for all types"""
+
+    def test_pickle(self):
+        """pickled objects restore type and state"""
+        for obj in samples() + documents():
+            with self.subTest(type(obj).__name__):
+                for protocol in range(2, pickle.HIGHEST_PROTOCOL + 1):
+                    restored = pickle.loads(pickle.dumps(obj, protocol))
+
+                    self.assertIs(type(restored), type(obj))
+                    self.assertEqual(restored.__json__(), obj.__json__())
+
+    def test_copy(self):
+        """copies are independent"""
+        for obj in samples() + documents():
+            with self.subTest(type(obj).__name__):
+                for copied in (copy.copy(obj), copy.deepcopy(obj)):
+                    self.assertIsNot(copied, obj)
+                    self.assertIs(type(copied), type(obj))
+                    self.assertEqual(copied.__json__(), obj.__json__())
+
+        vec = Vector2D([1, 2])
+        copied = copy.deepcopy(vec)
+        copied[0] = 5
+        self.assertEqual(vec[0], 1)
+
+    def test_classes(self):
+        """classes are pickled by reference"""
+        for cls in [type(obj) for obj in samples() + documents()] + [STL, PLY]:
+            with self.subTest(cls.__name__):
+                self.assertIs(pickle.loads(pickle.dumps(cls)), cls)
+
+    def test_documents(self):
+        """restored documents write the same output"""
+        svg, writer = documents()[7:9]
+
+        self.assertEqual(pickle.loads(pickle.dumps(svg)).to_svg(), svg.to_svg())
+        self.assertEqual(pickle.loads(pickle.dumps(writer)).to_gcode(), writer.to_gcode())
+
+    def test_nesting(self):
+        """a pickled nesting solves to the same result"""
+        nesting = documents()[3]
+        restored = pickle.loads(pickle.dumps(nesting))
+
+        self.assertEqual(restored.solve().__json__(), nesting.solve().__json__())
@@ -2,8 +2,10 @@
 # coding: utf-8
 
 """Unittest for vectors from the rust module euklid_rs"""
+import math
 from typing import List
 from euklid_rs.plane import Plane
+from euklid_rs.polyline import PolyLine2D, PolyLine3D
 from euklid_rs.vector import Vector2D, Vector3D
 
 from .base import TestCase
@@ -49,3 +51,208 @@ class TestPlaneFunctions(TestCase):
             self.assert_almost_equal_vec(self.plane_r.align(v_1), vec2)
 
         test_align_eq([0, 0], self.r_p3d_p0)
+
+
+class TestPlaneConstructors(TestCase):
+    """Test euklid_rs.plane.Plane constructors"""
+
+    def assert_orthonormal(self, plane: Plane) -> None:
+        """check unit length and orthogonality of the plane vectors"""
+        self.assertAlmostEqual(plane.x_vector.length(), 1)
+        self.assertAlmostEqual(plane.y_vector.length(), 1)
+        self.assertAlmostEqual(plane.normvector.length(), 1)
+        self.assertAlmostEqual(plane.x_vector.dot(plane.y_vector), 0)
+
+    def test_normalize(self) -> None:
+        """gram-schmidt normalized construction"""
+        plane = Plane(
+            Vector3D([2, 3, 4]), Vector3D([2, 0, 0]), Vector3D([1, 1, 0]), normalize=True
+        )
+        self.assert_orthonormal(plane)
+        self.assert_almost_equal_vec(plane.x_vector, [1, 0, 0])
+        self.assert_almost_equal_vec(plane.y_vector, [0, 1, 0])
+        self.assert_almost_equal_vec(plane.project(Vector3D([3, 5, 7])), [1, 2])
+
+        with self.assertRaises(ValueError):
+            Plane(Vector3D([0, 0, 0]), Vector3D([1, 0, 0]), Vector3D([2, 0, 0]), True)
+
+    def test_skewed_project(self) -> None:
+        """project inverts align for non-orthonormal planes"""
+        plane = Plane(Vector3D([1, 2, 3]), Vector3D([2, 0, 0]), Vector3D([1, 1, 1]))
+        for point in ([0.3, 0.7], [-2, 5], [0, 0]):
+            self.assert_almost_equal_vec(plane.project(plane.align(Vector2D(point))), point)
+
+        line = PolyLine2D([[0.3, 0.7], [-1, 2], [4, -3]])
+        projected = plane.project_polyline(plane.align_polyline(line))
+        for node, expected in zip(projected, line):
+            self.assert_almost_equal_vec(node, expected)
+
+        # points off the plane map to the closest point on the plane
+        offset = plane.align(Vector2D([0.3, 0.7])) + plane.normvector * 2
+        self.assert_almost_equal_vec(plane.project(offset), [0.3, 0.7])
+
+    def test_from_points(self) -> None:
+        """plane through three points"""
+        plane = Plane.from_points(
+            Vector3D([1, 1, 1]), Vector3D([1, 3, 1]), Vector3D([0, 2, 1])
+        )
+        self.assert_orthonormal(plane)
+        self.assert_almost_equal_vec(plane.p0, [1, 1, 1])
+        self.assert_almost_equal_vec(plane.x_vector, [0, 1, 0])
+        self.assert_almost_equal_vec(plane.normvector, [0, 0, 1])
+
+        with self.assertRaises(ValueError):
+            Plane.from_points(Vector3D([0, 0, 0]), Vector3D([1, 1, 1]), Vector3D([2, 2, 2]))
+
+    def test_from_normal(self) -> None:
+        """plane from origin and normal"""
+        for normal in [Vector3D([0, 0, 2]), Vector3D([1, 0, 0]), Vector3D([1, 2, 3])]:
+            plane = Plane.from_normal(Vector3D([1, 2, 3]), normal)
+            self.assert_orthonormal(plane)
+            self.assert_almost_equal_vec(plane.normvector, normal.normalized())
+
+    def test_best_fit(self) -> None:
+        """least-squares plane"""
+        points = [
+            Vector3D([0, 0, 1.1]),
+            Vector3D([4, 0, 0.9]),
+            Vector3D([4, 1, 1.1]),
+            Vector3D([0, 1, 0.9]),
+        ]
+        plane, rms = Plane.best_fit(points)
+        self.assert_orthonormal(plane)
+        self.assert_almost_equal_vec(plane.p0, [2, 0.5, 1])
+        self.assertAlmostEqual(abs(plane.normvector[2]), 1, 2)
+        self.assertAlmostEqual(abs(plane.x_vector[0]), 1, 2)
+        self.assertAlmostEqual(rms, 0.1)
+
+        plane, rms = Plane.best_fit(PolyLine3D([[0, 0, 0], [1, 0, 0], [0, 1, 0]]))
+        self.assertAlmostEqual(rms, 0)
+
+        with self.assertRaises(ValueError):
+            Plane.best_fit([Vector3D([0, 0, 0]), Vector3D([1, 1, 1]), Vector3D([2, 2, 2])])
+
+        for value in (math.nan, math.inf):
+            with self.assertRaises(ValueError):
+                Plane.best_fit(PolyLine3D([[0, 0, 0], [1, 0, value], [0, 1, 0], [1, 1, 1]]))
+
+
+class TestPlaneIntersections(TestCase):
+    """Test euklid_rs.plane.Plane intersections and distances"""
+
+    def setUp(self) -> None:
+        self.plane = Plane.from_normal(Vector3D([0, 0, 1]), Vector3D([0, 0, 2]))
+
+    def test_distance(self) -> None:
+        """signed distance and side"""
+        self.assertAlmostEqual(self.plane.signed_distance(Vector3D([5, 3, 4])), 3)
+        self.assertAlmostEqual(self.plane.signed_distance(Vector3D([5, 3, -1])), -2)
+        self.assertEqual(self.plane.side(Vector3D([5, 3, 4])), 1)
+        self.assertEqual(self.plane.side(Vector3D([5, 3, -1])), -1)
+        self.assertEqual(self.plane.side(Vector3D([5, 3, 1])), 0)
+        self.assertEqual(self.plane.side(Vector3D([5, 3, 1.01]), 0.1), 0)
+
+    def test_line(self) -> None:
+        """infinite lines and segments"""
+        p1 = Vector3D([1, 1, 2])
+        p2 = Vector3D([1, 3, 3])
+        ik, point = self.plane.intersect_line(p1, p2)
+        self.assertAlmostEqual(ik, -1)
+        self.assert_almost_equal_vec(point, [1, -1, 1])
+
+        self.assertIsNone(self.plane.intersect_segment(p1, p2))
+        ik, point = self.plane.intersect_segment(Vector3D([0, 0, 0]), Vector3D([0, 0, 4]))
+        self.assertAlmostEqual(ik, 0.25)
+
+        self.assertIsNone(self.plane.intersect_line(p1, Vector3D([2, 2, 2])))
+
+    def test_polyline(self) -> None:
+        """all crossings of a polyline"""
+        line = PolyLine3D([[0, 0, 0], [0, 0, 2], [1, 0, 1], [2, 0, 0], [3, 0, 3]])
+        cuts = self.plane.intersect_polyline(line)
+
+        self.assertEqual(len(cuts), 3)
+        for (ik, point), (expected_ik, expected_point) in zip(
+            cuts, [(0.5, [0, 0, 1]), (2, [1, 0, 1]), (3 + 1 / 3, [2 + 1 / 3, 0, 1])]
+        ):
+            self.assertAlmostEqual(ik, expected_ik)
+            self.assert_almost_equal_vec(point, expected_point)
+            self.assert_almost_equal_vec(line.get(ik), expected_point)
+
+    def test_plane(self) -> None:
+        """plane-plane intersection"""
+        other = Plane.from_normal(Vector3D([3, 0, 0]), Vector3D([1, 0, 0]))
+        point, direction = self.plane.intersect_plane(other)
+
+        self.assert_almost_equal_vec(point, [3, 0, 1])
+        self.assertAlmostEqual(abs(direction[1]), 1)
+
+        parallel = Plane.from_normal(Vector3D([0, 0, 5]), Vector3D([0, 0, -1]))
+        self.assertIsNone(self.plane.intersect_plane(parallel))
+
+
+class TestPlanePolylines(TestCase):
+    """Test batch operations of euklid_rs.plane.Plane"""
+
+    def setUp(self) -> None:
+        self.plane = Plane.from_points(
+            Vector3D([1, 0, 0]), Vector3D([1, 1, 0]), Vector3D([1, 0, 1])
+        )
+        self.line = PolyLine3D([[2, 0, 0], [3, 1, 2], [-1, 4, 3]])
+
+    def test_project_align(self) -> None:
+        """project to plane coordinates and back"""
+        projected = self.plane.project_polyline(self.line)
+        self.assertIsInstance(projected, PolyLine2D)
+        self.assertEqual(len(projected), 3)
+        for node, projected_node in zip(self.line, projected):
+            self.assert_almost_equal_vec(projected_node, self.plane.project(node))
+
+        aligned = self.plane.align_polyline(projected)
+        for node, expected in zip(aligned, [[1, 0, 0], [1, 1, 2], [1, 4, 3]]):
+            self.assert_almost_equal_vec(node, expected)
+
+    def test_project_3d(self) -> None:
+        """orthogonal projection onto the plane"""
+        self.assert_almost_equal_vec(
+            self.plane.project_point_3d(Vector3D([5, 2, 3])), [1, 2, 3]
+        )
+        projected = self.plane.project_polyline_3d(self.line)
+        for node in projected:
+            self.assertAlmostEqual(self.plane.signed_distance(node), 0)
+
+    def test_mirror(self) -> None:
+        """mirror points and polylines"""
+        self.assert_almost_equal_vec(self.plane.mirror_point(Vector3D([3, 2, 1])), [-1, 2, 1])
+
+        mirrored = self.plane.mirror_polyline(self.line)
+        for node, expected in zip(mirrored, [[0, 0, 0], [-1, 1, 2], [3, 4, 3]]):
+            self.assert_almost_equal_vec(node, expected)
+
+    def test_degenerate(self) -> None:
+        """planes with parallel x and y vectors have no normal"""
+        plane = Plane(Vector3D([0, 0, 0]), Vector3D([1, 0, 0]), Vector3D([2, 0, 0]))
+        point = Vector3D([1, 2, 3])
+
+        with self.assertRaises(ValueError):
+            plane.mirror_point(point)
+        with self.assertRaises(ValueError):
+            plane.mirror_polyline(self.line)
+        with self.assertRaises(ValueError):
+            plane.project_point_3d(point)
+        with self.assertRaises(ValueError):
+            plane.project_polyline_3d(self.line)
+        with self.assertRaises(ValueError):
+            plane.signed_distance(point)
+        with self.assertRaises(ValueError):
+            plane.side(point)
+        with self.assertRaises(ValueError):
+            plane.intersect_line(Vector3D([0, 0, 0]), point)
+        with self.assertRaises(ValueError):
+            plane.intersect_segment(Vector3D([0, 0, 0]), point)
+        with self.assertRaises(ValueError):
+            plane.intersect_polyline(self.line)
+        with self.assertRaises(ValueError):
+            plane.intersect_plane(self.plane)
+        with self.assertRaises(ValueError):
+            self.plane.intersect_plane(plane)
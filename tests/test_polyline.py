@@ -3,7 +3,12 @@
 
 """Unittest for vectors from the rust module euklid_rs"""
 import random
+import threading
+import time
 import unittest
+import math
+from concurrent.futures import ThreadPoolExecutor
+from euklid_rs.plane import Plane
 from euklid_rs.polyline import PolyLine2D, PolyLine3D
 from euklid_rs.vector import Vector2D, Vector3D
 
@@ -34,6 +39,25 @@ class TestPolyLine3D(unittest.TestCase):
         self.assertEqual(self.line.walk(0, -1), -1)
         self.assertAlmostEqual(self.line.walk(0, 0.1), 0.1)
 
+    def test_many(self):
+        """test batch evaluation"""
+        iks = [-1, 0, 1.5, 3.25, 4]
+
+        self.assertEqual(self.line.get_many(iks).tolist(), [self.line.get(ik).tolist() for ik in iks])
+        self.assertEqual(
+            self.line.derivative_many(iks).tolist(),
+            [self.line.derivative(ik).tolist() for ik in iks],
+        )
+        self.assertEqual(self.line.derivative(1.5).tolist()[:2], [1, 1])
+        self.assertEqual(self.line.derivative(4).tolist()[:2], [1, 2])
+        self.assertEqual(self.line.walk_many(0, [1, -1, 0.1]), [self.line.walk(0, d) for d in [1, -1, 0.1]])
+
+        for nodes in ([], [[0, 0, 0]]):
+            with self.assertRaises(ValueError):
+                PolyLine3D(nodes).derivative(0.5)
+            with self.assertRaises(ValueError):
+                PolyLine3D(nodes).derivative_many([0.5])
+
     def test_resample(self):
         """test resampling"""
         target_length = random.randint(20, 500)
@@ -43,6 +67,35 @@ class TestPolyLine3D(unittest.TestCase):
         self.assertEqual(len(resampled_line), target_length)
         self.assertAlmostEqual(resampled_line.get_length(), self.line.get_length(), 1)
 
+    def test_move(self):
+        """test moving (new line and inplace)"""
+        offset = self.line[1]
+        moved = self.line.move(offset)
+        self.assertEqual(moved[0], offset)
+        self.assertEqual(self.line[0] * 0, self.line[0])
+
+        self.line.move_inplace(offset)
+        self.assertEqual(self.line[0], offset)
+
+    def test_reverse(self):
+        """test reversing"""
+        first = self.line[0]
+        self.assertEqual(self.line.reverse()[-1], first)
+        self.line.reverse_inplace()
+        self.assertEqual(self.line[-1], first)
+
+    def test_scale_nodes(self):
+        """test scaling relative to an origin"""
+        origin = self.line[1]
+        scaled = self.line.scale_nodes(2, origin)
+        self.assertEqual(scaled[1], origin)
+        self.assertEqual(scaled[0], self.line[0] * 2 - origin)
+        self.assertAlmostEqual(scaled.get_length(), self.line.get_length() * 2)
+
+        length = self.line.get_length()
+        self.line.scale_nodes_inplace(0.5)
+        self.assertAlmostEqual(self.line.get_length(), length / 2)
+
 
 class TestPolyLine2D(TestPolyLine3D):
     """Test PolyLine2D"""
@@ -52,6 +105,24 @@ class TestPolyLine2D(TestPolyLine3D):
         list(line)
         self.line = PolyLine2D([[p[0], p[1]] for p in line])
 
+    def test_rotate(self):
+        """test rotation about a point"""
+        rotated = self.line.rotate(math.pi / 2, Vector2D([1, 0]))
+        self.assertAlmostEqual((rotated[0] - Vector2D([1, -1])).length(), 0)
+        self.assertEqual(rotated[1], self.line[1])
+
+        self.line.rotate_inplace(math.pi)
+        self.assertAlmostEqual((self.line[1] - Vector2D([-1, 0])).length(), 0)
+
+    def test_mirror(self):
+        """test mirroring across a line"""
+        mirrored = self.line.mirror(Vector2D([1, 0]), Vector2D([1, 1]))
+        self.assertAlmostEqual((mirrored[0] - Vector2D([2, 0])).length(), 0)
+        self.assertAlmostEqual((mirrored[2] - Vector2D([0, 1])).length(), 0)
+
+        self.line.mirror_inplace(Vector2D([0, 0]), Vector2D([1, 0]))
+        self.assertAlmostEqual((self.line[2] - Vector2D([2, -1])).length(), 0)
+
     def test_get(self):
         """Test getting a point"""
         self.assertEqual(self.line.get(0), Vector2D([0, 0]))
@@ -104,6 +175,57 @@ class TestPolyLine2D(TestPolyLine3D):
         self.assertEqual(len(cuts), 2)
         self.assertAlmostEqual(cuts[0][0], 1)
 
+    def test_cut_with_polylines(self):
+        """Test cuts with many lines at once"""
+        others = [PolyLine2D([[1, -1], [1, 1], [2, -1]]), PolyLine2D([[0, 5], [1, 5]]), self.line.copy()]
+
+        self.assertEqual(
+            self.line.cut_with_polylines(others),
+            [self.line.cut_with_polyline(other) for other in others],
+        )
+
+    def test_offset_many(self):
+        """Test offsetting many lines at once"""
+        lines = [self.line, PolyLine2D([[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]])]
+
+        for line, offset in zip(lines, PolyLine2D.offset_many(lines, 0.1)):
+            self.assertEqual(offset.tolist(), line.offset(0.1).tolist())
+
+    def test_threads(self):
+        """Test concurrent calls from python threads"""
+        lines = [self.line.resample(50 + i) for i in range(8)]
+
+        with ThreadPoolExecutor(4) as executor:
+            fixed = list(executor.map(lambda line: line.fix_errors(), lines))
+
+        self.assertEqual([line.tolist() for line in fixed], [line.fix_errors().tolist() for line in lines])
+
+    def test_release_gil(self):
+        """Test that other python threads run during a long resample"""
+        line = PolyLine2D([[i, math.sin(i)] for i in range(2000)])
+        ticks = []
+        running = threading.Event()
+        running.set()
+
+        def count():
+            while running.is_set():
+                ticks.append(time.perf_counter())
+
+        counter = threading.Thread(target=count)
+        counter.start()
+        try:
+            start = time.perf_counter()
+            line.resample(2000000)
+            end = time.perf_counter()
+        finally:
+            running.clear()
+            counter.join()
+
+        # with the gil held, the counter could only run shortly before and after the call
+        times = [start] + [tick for tick in ticks if start < tick < end] + [end]
+        largest_gap = max(b - a for a, b in zip(times, times[1:]))
+        self.assertLess(largest_gap, (end - start) / 2)
+
     def test_fix(self):
         """Test self intersecting line"""
         line = PolyLine2D([[0, 0], [1, 0], [1, 1], [0.5, -1]]).fix_errors()
@@ -133,5 +255,40 @@ class TestPolyLine2D(TestPolyLine3D):
         self.assertEqual(len(line_fixed), len(line) - 1)
 
 
+class TestPolyLine3DGeometry(unittest.TestCase):
+    """Test PolyLine3D rotation and mirroring"""
+
+    def setUp(self) -> None:
+        self.line = TestPolyLine3D.setUp(self)
+
+    def test_rotate(self):
+        """test rotation about an axis"""
+        rotated = self.line.rotate(math.pi / 2, Vector3D([0, 0, 1]), Vector3D([1, 0, 0]))
+        self.assertAlmostEqual((rotated[0] - Vector3D([1, -1, 0])).length(), 0)
+
+        self.line.rotate_inplace(math.pi, Vector3D([1, 0, 0]))
+        self.assertAlmostEqual((self.line[2] - Vector3D([2, -1, 0])).length(), 0)
+
+    def test_mirror(self):
+        """test mirroring across a plane"""
+        plane = Plane(Vector3D([0, 0, 1]), Vector3D([1, 0, 0]), Vector3D([0, 2, 0]))
+        mirrored = self.line.mirror(plane)
+        self.assertAlmostEqual((mirrored[2] - Vector3D([2, 1, 2])).length(), 0)
+
+        self.line.mirror_inplace(plane)
+        self.assertEqual(self.line[2], mirrored[2])
+
+    def test_mirror_degenerate(self):
+        """a plane with parallel x and y vectors is rejected"""
+        plane = Plane(Vector3D([0, 0, 0]), Vector3D([1, 0, 0]), Vector3D([2, 0, 0]))
+        nodes = self.line.tolist()
+
+        with self.assertRaises(ValueError):
+            self.line.mirror(plane)
+        with self.assertRaises(ValueError):
+            self.line.mirror_inplace(plane)
+        self.assertEqual(self.line.tolist(), nodes)
+
+
 if __name__ == "__main__":
     unittest.main(exit=False)
@@ -0,0 +1,202 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for quaternion rotations"""
+
+import math
+import pickle
+from euklid_rs.vector import Quaternion, Transformation, Vector3D
+
+from .base import TestCase
+
+
+class TestQuaternion(TestCase):
+    """Test euklid_rs.vector.Quaternion"""
+
+    def setUp(self) -> None:
+        self.vec = Vector3D([1, 2, 3])
+
+    def test_axis_angle(self):
+        """rotation about an axis"""
+        quaternion = Quaternion.from_axis_angle(Vector3D([0, 0, 1]), math.pi / 2)
+        self.assert_almost_equal_vec(quaternion.apply(self.vec), [-2, 1, 3])
+        self.assertAlmostEqual(quaternion.angle(), math.pi / 2)
+        self.assert_almost_equal_vec(quaternion.axis(), [0, 0, 1])
+
+        with self.assertRaises(ValueError):
+            Quaternion.from_axis_angle(Vector3D([0, 0, 0]), 1)
+
+    def test_euler(self):
+        """extrinsic and intrinsic euler angles"""
+        angles = [math.pi / 2, math.pi / 2, 0]
+        extrinsic = Quaternion.from_euler(angles, "xyz")
+        intrinsic = Quaternion.from_euler(angles, "XYZ")
+
+        # x first, then the fixed y-axis
+        self.assert_almost_equal_vec(extrinsic.apply(Vector3D([0, 1, 0])), [1, 0, 0])
+        # x first, then the rotated y-axis (which is the global z-axis)
+        self.assert_almost_equal_vec(intrinsic.apply(Vector3D([0, 1, 0])), [0, 0, 1])
+
+        self.assertEqual(
+            Quaternion.from_euler([0.1, 0.2, 0.3], "zyx"),
+            Quaternion.from_euler([0.3, 0.2, 0.1], "XYZ"),
+        )
+
+        with self.assertRaises(ValueError):
+            Quaternion.from_euler(angles, "xYz")
+
+    def test_matrix(self):
+        """conversion from and to rotation matrices"""
+        quaternion = Quaternion.from_euler([0.3, -0.5, 1.2])
+        self.assertEqual(Quaternion.from_matrix(quaternion.to_matrix()), quaternion)
+
+        with self.assertRaises(ValueError):
+            Quaternion.from_matrix([[2, 0, 0], [0, 1, 0], [0, 0, 1]])
+        with self.assertRaises(ValueError):
+            Quaternion.from_matrix([[-1, 0, 0], [0, 1, 0], [0, 0, 1]])
+
+    def test_vectors(self):
+        """alignment of two vectors"""
+        source = Vector3D([1, 0, 0])
+        for target in [Vector3D([0, 2, 0]), Vector3D([1, 1, 1]), Vector3D([-3, 0, 0])]:
+            quaternion = Quaternion.from_vectors(source, target)
+            self.assert_almost_equal_vec(quaternion.apply(source), target.normalized())
+
+    def test_slerp(self):
+        """interpolation along the shortest arc"""
+        axis = Vector3D([0, 0, 1])
+        q1 = Quaternion.from_axis_angle(axis, 0.2)
+        q2 = Quaternion.from_axis_angle(axis, 1.0)
+
+        self.assertEqual(q1.slerp(q2, 0.5), Quaternion.from_axis_angle(axis, 0.6))
+        self.assertEqual(q1.slerp(q2, 0), q1)
+        self.assertEqual(q1.slerp(q2, 1), q2)
+
+        # -q2 describes the same rotation
+        q3 = Quaternion(-q2.w, -q2.x, -q2.y, -q2.z)
+        self.assertEqual(q1.slerp(q3, 0.5), Quaternion.from_axis_angle(axis, 0.6))
+
+    def test_composition(self):
+        """multiplication, inverse and conversion to transformations"""
+        q1 = Quaternion.from_euler([0.3, 0.1, 0])
+        q2 = Quaternion.from_euler([0, -0.4, 0.7])
+
+        self.assert_almost_equal_vec(
+            (q1 * q2).apply(self.vec), q1.apply(q2.apply(self.vec))
+        )
+        self.assert_almost_equal_vec((q1 * q1.inverse()).apply(self.vec), self.vec)
+        self.assert_almost_equal_vec(
+            q1.to_transformation().apply(self.vec), q1.apply(self.vec)
+        )
+        self.assertEqual(pickle.loads(pickle.dumps(q1)), q1)
+
+
+class TestInterpolation(TestCase):
+    """Test interpolation between transformations"""
+
+    def test_rigid(self):
+        """translation and rotation"""
+        axis = Vector3D([0, 0, 1])
+        start = Transformation.translation(Vector3D([0, 0, 0]))
+        end = Transformation.translation(Vector3D([2, 0, 0])) @ Transformation.rotation(
+            math.pi / 2, axis
+        )
+
+        middle = start.interpolate(end, 0.5)
+        expected = Transformation.translation(
+            Vector3D([1, 0, 0])
+        ) @ Transformation.rotation(math.pi / 4, axis)
+
+        self.assertTrue(middle.almost_equal(expected, 1e-8))
+        self.assertTrue(start.interpolate(end, 0).almost_equal(start, 1e-8))
+        self.assertTrue(start.interpolate(end, 1).almost_equal(end, 1e-8))
+
+    def test_scale(self):
+        """scale is interpolated linearly"""
+        start = Transformation.scale(1)
+        end = Transformation.rotation(1, Vector3D([1, 1, 0])) @ Transformation.scale(
+            Vector3D([3, 2, 1])
+        )
+
+        self.assertTrue(start.interpolate(end, 1).almost_equal(end, 1e-8))
+        self.assert_almost_equal_vec(
+            start.interpolate(Transformation.scale(3), 0.5).apply(Vector3D([1, 1, 1])),
+            [2, 2, 2],
+        )
+
+    def test_mirror(self):
+        """reflections survive the decomposition"""
+        mirror = Transformation.scale(Vector3D([-1, 1, 1]))
+        self.assertTrue(mirror.interpolate(mirror, 0.3).almost_equal(mirror, 1e-8))
+
+
+class TestDecomposition(TestCase):
+    """Test decomposition of transformations"""
+
+    def test_euler_roundtrip(self):
+        """euler angles for all axis orders"""
+        for order in ["xyz", "xzy", "yxz", "yzx", "zxy", "zyx", "xyx", "zxz", "ZYX", "XYZ", "ZXZ"]:
+            # the middle angle of proper euler angles is in [0, pi]
+            angles = [0.3, 0.7 if order[0] == order[2] else -0.7, 1.1]
+            quaternion = Quaternion.from_euler(angles, order)
+            result = quaternion.to_euler(order)
+            for value, expected in zip(result, angles):
+                self.assertAlmostEqual(value, expected)
+
+        # gimbal lock: only the rotation is preserved
+        quaternion = Quaternion.from_euler([0.3, math.pi / 2, 0.2])
+        self.assertEqual(Quaternion.from_euler(quaternion.to_euler()), quaternion)
+
+        with self.assertRaises(ValueError):
+            quaternion.to_euler("xxy")
+
+    def test_axis_angle(self):
+        """axis-angle of the rotation part"""
+        axis, angle = Quaternion.from_axis_angle(Vector3D([0, 2, 0]), -0.5).to_axis_angle()
+        self.assert_almost_equal_vec(axis, [0, -1, 0])
+        self.assertAlmostEqual(angle, 0.5)
+
+    def test_decompose(self):
+        """translation, rotation, scale and shear"""
+        rotation = Quaternion.from_euler([0.2, 0.4, -0.3])
+        transformation = (
+            Transformation.translation(Vector3D([1, 2, 3]))
+            @ rotation.to_transformation()
+            @ Transformation.shear(0.5, 0, 0, 0, 0, 0)
+            @ Transformation.scale(Vector3D([2, 3, 4]))
+        )
+
+        translation, result_rotation, scale, shear = transformation.decompose()
+        self.assert_almost_equal_vec(translation, [1, 2, 3])
+        self.assertEqual(result_rotation, rotation)
+        self.assert_almost_equal_vec(scale, [2, 3, 4])
+        for value, expected in zip(shear, [0.5, 0, 0]):
+            self.assertAlmostEqual(value, expected)
+
+        self.assertEqual(transformation.get_rotation(), rotation)
+        self.assert_almost_equal_vec(transformation.get_translation(), [1, 2, 3])
+
+    def test_predicates(self):
+        """rigid, orthonormal and handedness checks"""
+        rigid = Transformation.translation(Vector3D([1, 0, 0])) @ Transformation.rotation(
+            0.4, Vector3D([1, 1, 1])
+        )
+        mirror = Transformation.scale(Vector3D([1, -1, 1]))
+        scale = Transformation.scale(2)
+        singular = Transformation.scale(Vector3D([1, 1, 0]))
+
+        self.assertTrue(rigid.is_rigid())
+        self.assertTrue(rigid.is_orthonormal())
+        self.assertEqual(rigid.handedness(), 1)
+
+        self.assertFalse(mirror.is_rigid())
+        self.assertTrue(mirror.is_orthonormal())
+        self.assertEqual(mirror.handedness(), -1)
+
+        self.assertFalse(scale.is_rigid())
+        self.assertFalse(scale.is_orthonormal())
+        self.assertTrue(scale.is_affine())
+
+        self.assertTrue(singular.is_affine())
+        self.assertEqual(singular.handedness(), 0)
+        self.assertEqual(mirror.get_scale()[2], -1)
@@ -4,7 +4,11 @@
 """Unittest for vectors from the rust module euklid_rs"""
 
 import math
-from euklid_rs.vector import Vector3D, Transformation
+import pickle
+from euklid_rs.plane import Plane
+from euklid_rs.polyline import PolyLine3D
+from euklid_rs.spline import BezierCurve3D
+from euklid_rs.vector import Vector2D, Vector3D, Transformation
 
 from .base import TestCase
 
@@ -84,3 +88,146 @@ class TestVectorTransFormFunctions(TestCase):
                 self.assert_almost_equal_vec(
                     transformation.apply_inverse(transformation.apply(vector)), vector
                 )
+
+    def test_apply_polyline(self):
+        """apply to a polyline and a list of vectors"""
+        line = PolyLine3D(self.vectors)
+        for transformation in self.get_transformations():
+            result = transformation.apply(line)
+            self.assertIsInstance(result, PolyLine3D)
+            vectors = transformation.apply(self.vectors)
+            self.assertIsInstance(vectors, list)
+
+            for i, vector in enumerate(self.vectors):
+                self.assert_almost_equal_vec(result[i], transformation.apply(vector))
+                self.assert_almost_equal_vec(vectors[i], transformation.apply(vector))
+
+    def test_apply_plane(self):
+        """apply to a plane"""
+        plane = Plane(Vector3D([1, 0, 0]), Vector3D([1, 0, 0]), Vector3D([0, 1, 0]))
+        rotation = Transformation.rotation(math.pi / 2, Vector3D([1, 0, 0]))
+        rotated = rotation.apply(plane)
+
+        self.assert_almost_equal_vec(rotated.p0, [1, 0, 0])
+        self.assert_almost_equal_vec(rotated.y_vector, [0, 0, 1])
+        self.assert_almost_equal_vec(rotated.normvector, [0, -1, 0])
+        self.assert_almost_equal_vec(
+            rotated.align(plane.project(Vector3D([2, 3, 0]))), [2, 0, 3]
+        )
+
+    def test_apply_curve(self):
+        """apply to the controlpoints of a 3d curve"""
+        curve = BezierCurve3D(PolyLine3D(self.vectors))
+        translation = Transformation.translation(self.vectors[1])
+        moved = translation.apply(curve)
+
+        self.assertIsInstance(moved, BezierCurve3D)
+        self.assert_almost_equal_vec(moved.get(0.5), curve.get(0.5) + self.vectors[1])
+
+    def test_operators(self):
+        """@ and * operators"""
+        translation, rotation = self.get_transformations()[:2]
+        chained = translation @ rotation
+
+        for vector in self.vectors:
+            self.assert_almost_equal_vec(chained @ vector, translation.chain(rotation).apply(vector))
+            self.assert_almost_equal_vec((translation * rotation) * vector, chained @ vector)
+
+        with self.assertRaises(TypeError):
+            _ = translation @ 1
+
+    def test_matrix(self):
+        """matrix roundtrip"""
+        for transformation in self.get_transformations():
+            matrix = transformation.matrix
+            self.assertEqual(len(matrix), 4)
+            self.assertEqual(matrix[3], [0, 0, 0, 1])
+            self.assertEqual(Transformation.from_matrix(matrix), transformation)
+
+        translation = Transformation.translation(Vector3D([1, 2, 3]))
+        self.assertEqual([row[3] for row in translation.matrix], [1, 2, 3, 1])
+
+    def test_from_matrix_validation(self):
+        """invalid matrices raise ValueError"""
+        with self.assertRaises(ValueError):
+            Transformation.from_matrix([[1, 0, 0], [0, 1, 0], [0, 0, 1]])
+        with self.assertRaises(ValueError):
+            Transformation.from_matrix([[1, 0, 0, 0]] * 3 + [[0, 0, 0, float("nan")]])
+        with self.assertRaises(ValueError):
+            Transformation.from_matrix([[1, 0, 0, 0]] * 3 + [[0, 0, 1, 1]])
+
+    def test_inverse_transformation(self):
+        """inverse as transformation"""
+        for transformation in self.get_transformations():
+            inverse = transformation.inverse()
+            self.assertEqual(inverse.chain(transformation), Transformation.scale(1))
+
+        with self.assertRaises(ValueError):
+            Transformation.scale(0).inverse()
+
+    def test_equality(self):
+        """approximate equality"""
+        rotation = Transformation.rotation(math.pi / 4, self.vectors[1])
+        self.assertEqual(rotation, Transformation.rotation(math.pi / 4 + 1e-12, self.vectors[1]))
+        self.assertNotEqual(rotation, Transformation.rotation(math.pi / 4 + 1e-3, self.vectors[1]))
+        self.assertTrue(rotation.almost_equal(Transformation.rotation(math.pi / 4 + 1e-3, self.vectors[1]), 1e-2))
+
+    def test_pickle(self):
+        """pickle and repr"""
+        for transformation in self.get_transformations():
+            self.assertEqual(pickle.loads(pickle.dumps(transformation)), transformation)
+            self.assertTrue(repr(transformation).startswith("Transformation("))
+
+    def test_rotation_about_point(self):
+        """rotation about an axis through a point"""
+        rotation = Transformation.rotation(math.pi / 2, Vector3D([0, 0, 1]), Vector3D([1, 0, 0]))
+        self.assert_almost_equal_vec(rotation.apply(self.vectors[0]), [1, -1, 0])
+        self.assert_almost_equal_vec(rotation.apply(Vector3D([1, 0, 5])), [1, 0, 5])
+
+    def test_non_uniform_scale(self):
+        """scale with a vector"""
+        scale = Transformation.scale(Vector3D([1, 2, 3]))
+        self.assert_almost_equal_vec(scale.apply(self.vectors[2]), [2, 6, 12])
+
+        scale = Transformation.scale(2, self.vectors[1])
+        self.assert_almost_equal_vec(scale.apply(self.vectors[1]), [1, 1, 1])
+        self.assert_almost_equal_vec(scale.apply(self.vectors[0]), [-1, -1, -1])
+
+    def test_shear(self):
+        """shear"""
+        shear = Transformation.shear(xy=1, zx=2)
+        self.assert_almost_equal_vec(shear.apply(self.vectors[2]), [5, 3, 8])
+
+    def test_mirror(self):
+        """mirror across a plane"""
+        plane = Plane(Vector3D([0, 0, 1]), Vector3D([1, 1, 0]), Vector3D([0, 1, 0]))
+        mirror = Transformation.mirror(plane)
+        self.assert_almost_equal_vec(mirror.apply(self.vectors[2]), [2, 3, -2])
+        self.assertEqual(mirror.chain(mirror), Transformation.scale(1))
+
+        with self.assertRaises(ValueError):
+            Transformation.mirror(Plane(Vector3D([0, 0, 0]), Vector3D([1, 0, 0]), Vector3D([2, 0, 0])))
+
+    def test_frame(self):
+        """frame from vectors and look_at"""
+        frame = Transformation.from_frame(self.vectors[1], Vector3D([0, 2, 0]), Vector3D([-1, 1, 0]))
+        self.assert_almost_equal_vec(frame.apply(Vector3D([1, 1, 1])), [0, 2, 2])
+
+        look_at = Transformation.look_at(self.vectors[0], Vector3D([0, 5, 0]), Vector3D([0, 0, 1]))
+        self.assert_almost_equal_vec(look_at.apply(Vector3D([0, 0, 2])), [0, 2, 0])
+        self.assert_almost_equal_vec(look_at.apply(Vector3D([0, 1, 0])), [0, 0, 1])
+
+        with self.assertRaises(ValueError):
+            Transformation.from_frame(self.vectors[0], self.vectors[1], self.vectors[1])
+
+    def test_change_of_basis(self):
+        """map between two planes"""
+        source = Plane(Vector3D([1, 0, 0]), Vector3D([1, 0, 0]), Vector3D([0, 1, 0]))
+        target = Plane(Vector3D([0, 0, 5]), Vector3D([0, 1, 0]), Vector3D([0, 0, 1]))
+        transformation = Transformation.change_of_basis(source, target)
+
+        for coords in ([0, 0], [1, 2], [-3, 1]):
+            point = source.align(Vector2D(coords))
+            self.assert_almost_equal_vec(
+                transformation.apply(point), target.align(Vector2D(coords))
+            )
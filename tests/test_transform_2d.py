@@ -0,0 +1,85 @@
+#!/usr/bin/env python3
+# coding: utf-8
+
+"""Unittest for 2D transformations"""
+
+import math
+from euklid_rs.polyline import PolyLine2D
+from euklid_rs.spline import BezierCurve
+from euklid_rs.vector import Rotation2D, Transformation2D, Vector2D
+
+from .base import TestCase
+
+
+class TestTransformation2D(TestCase):
+    """Test euklid_rs.vector.Transformation2D"""
+
+    def setUp(self) -> None:
+        self.vectors = [Vector2D([0, 0]), Vector2D([1, 1]), Vector2D([2, 3])]
+
+    def test_rotation(self):
+        """rotation about a point"""
+        rotation = Transformation2D.rotation(math.pi / 2, Vector2D([1, 0]))
+        self.assert_almost_equal_vec(rotation.apply(self.vectors[0]), [1, -1])
+        self.assert_almost_equal_vec(rotation.apply(self.vectors[1]), [0, 0])
+
+        rotation = Rotation2D(math.pi / 2)
+        self.assert_almost_equal_vec(rotation.apply(self.vectors[2]), [-3, 2])
+        self.assert_almost_equal_vec(
+            rotation.transformation().apply(self.vectors[2]), [-3, 2]
+        )
+
+    def test_scale(self):
+        """non-uniform scaling"""
+        scale = Transformation2D.scale(Vector2D([2, 3]), Vector2D([1, 1]))
+        self.assert_almost_equal_vec(scale.apply(self.vectors[1]), [1, 1])
+        self.assert_almost_equal_vec(scale.apply(self.vectors[2]), [3, 7])
+        self.assert_almost_equal_vec(
+            Transformation2D.scale(2).apply(self.vectors[2]), [4, 6]
+        )
+
+    def test_mirror(self):
+        """mirror across a line"""
+        mirror = Transformation2D.mirror(Vector2D([0, 1]), Vector2D([1, 2]))
+        self.assert_almost_equal_vec(mirror.apply(self.vectors[0]), [-1, 1])
+        self.assert_almost_equal_vec(mirror.apply(self.vectors[1]), [0, 2])
+
+        with self.assertRaises(ValueError):
+            Transformation2D.mirror(Vector2D([1, 2]), Vector2D([1, 2]))
+
+    def test_shear(self):
+        """shear"""
+        shear = Transformation2D.shear(0.5, 0)
+        self.assert_almost_equal_vec(shear.apply(self.vectors[2]), [3.5, 3])
+
+    def test_chain_and_inverse(self):
+        """chaining and inverting"""
+        transformation = Transformation2D.translation(Vector2D([1, 0])).chain(
+            Transformation2D.rotation(math.pi / 2)
+        )
+        self.assert_almost_equal_vec(transformation.apply(self.vectors[1]), [0, 1])
+
+        inverse = transformation.inverse()
+        for vector in self.vectors:
+            self.assert_almost_equal_vec(
+                inverse.apply(transformation.apply(vector)), vector
+            )
+            self.assert_almost_equal_vec(
+                transformation.apply_inverse(transformation.apply(vector)), vector
+            )
+
+        with self.assertRaises(ValueError):
+            Transformation2D.scale(Vector2D([1, 0])).inverse()
+
+    def test_apply_polyline_and_curve(self):
+        """apply to PolyLine2D and curves"""
+        translation = Transformation2D.translation(Vector2D([1, 2]))
+        line = PolyLine2D([[0, 0], [1, 0], [1, 1]])
+
+        moved = translation.apply(line)
+        self.assertIsInstance(moved, PolyLine2D)
+        self.assert_almost_equal_vec(moved[2], [2, 3])
+
+        curve = translation.apply(BezierCurve(line))
+        self.assertIsInstance(curve, BezierCurve)
+        self.assert_almost_equal_vec(curve.get(0), [1, 2])